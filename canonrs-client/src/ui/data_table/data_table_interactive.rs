@@ -136,7 +136,7 @@ where
     view! {
         <div
             data-rs-datatable=""
-            data-rs-density=move || density_signal.get()
+            data-density=move || density_signal.get()
             data-rs-zebra=move || zebra_signal.get().to_string()
             data-rs-row-hover=move || hover_signal.get().to_string()
             data-rs-sticky-header=move || sticky_signal.get().to_string()
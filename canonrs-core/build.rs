@@ -1,6 +1,6 @@
 //! build.rs — CanonRS SSOT Generator
 //! SOURCE: primitives/*.rs + blocks/*_block.rs + layouts/*_layout.rs + components.toml
-//! GENERATES: schema.json + SSOT_AUDIT.md + OUT_DIR/generated/*
+//! GENERATES: schema.json + schemas/*.schema.json + SSOT_AUDIT.md + OUT_DIR/generated/*
 
 mod build {
     pub(crate) mod types;
@@ -43,7 +43,7 @@ fn main() {
         .collect();
 
     generate_block_meta(&blocks_layouts, &out_dir);
-    generate_catalog(&semantic, &blocks_layouts, &out_dir, Path::new("../canonrs-server/src/blocks"), Path::new("../canonrs-server/src/layouts"));
+    generate_catalog(&semantic, &blocks_layouts, &out_dir, Path::new("../canonrs-server/src/ui"), Path::new("../canonrs-server/src/blocks"), Path::new("../canonrs-server/src/layouts"));
     generate_layout_definitions(&blocks_layouts, Path::new("../canonrs-server/src/layouts"), &out_dir);
     generate_block_definitions(&blocks_layouts, Path::new("../canonrs-server/src/blocks"), Path::new("../canonrs-server/src/layouts"), &out_dir);
     generate_api_files(Path::new("../canonrs-server/src/ui"));
@@ -53,6 +53,7 @@ fn main() {
     generate_api_files_layouts(Path::new("../canonrs-server/src/layouts"));
     generate_api_files_layout_primitives(Path::new("src/primitives/layout"));
     generate_llm_context(&semantic, &blocks_layouts, Path::new("../canonrs-server/src/blocks"), Path::new("../canonrs-server/src/layouts"), &out_dir);
+    generate_json_schemas(&semantic, &blocks_layouts, Path::new("../canonrs-server/src/ui"), Path::new("../canonrs-server/src/blocks"), Path::new("../canonrs-server/src/layouts"), Path::new("schemas"), &out_dir);
 
     // Canon Rules — parse + generate
     let rules_dir = Path::new("../canonrs-rules");
@@ -1,15 +1,15 @@
 //! gen_api — entry points públicos
 
-mod parser;
-mod resolver;
-mod renderer;
+pub(crate) mod parser;
+pub(crate) mod resolver;
+pub(crate) mod renderer;
 
 use std::fs;
 use std::path::Path;
 use parser::parse_components;
 use renderer::{render_api_with_boundary, render_api_no_header};
 
-fn write_if_changed(path: &Path, new_content: &str) {
+pub(crate) fn write_if_changed(path: &Path, new_content: &str) {
     if let Ok(existing) = fs::read_to_string(path) {
         if existing == new_content {
             return;
@@ -1,6 +1,6 @@
 //! Extrai ComponentDef a partir do conteúdo de *_ui.rs
 
-use super::resolver::resolve_type;
+use super::resolver::{pascal_to_kebab, resolve_type};
 
 #[derive(Debug)]
 pub(crate) struct PropDef {
@@ -97,7 +97,8 @@ fn process_arg(p: &str, attrs: &str, full_content: &str, props: &mut Vec<PropDef
     let optional = attrs.contains("optional") || arg_type.starts_with("Option<");
     let raw_default = extract_default(attrs);
     let ty          = resolve_type(&arg_type, &arg_name, full_content);
-    let default     = raw_default.map(|d| normalize_default(&d));
+    // `default = None` num Option<_> é ausência de default, não o valor "None"
+    let default     = raw_default.filter(|d| d != "None").map(|d| normalize_default(&d));
     props.push(PropDef {
         name: arg_name,
         ty,
@@ -185,24 +186,15 @@ fn normalize_default(d: &str) -> String {
         let inner = d.trim_end_matches(".to_string()").trim();
         return inner.trim_matches('"').to_string();
     }
+    // Mesmo kebab dos variants (`GridCols::Three` → "3") — o default precisa estar no Enum
     if d.contains("::") && !d.contains('(') {
         let variant = d.split("::").last().unwrap_or(d);
-        let mut result = String::new();
-        for (i, c) in variant.chars().enumerate() {
-            if c.is_uppercase() && i > 0 { result.push('-'); }
-            result.push(c.to_lowercase().next().unwrap());
-        }
-        return result;
+        return pascal_to_kebab(variant);
     }
     if d.contains("::") && d.contains('(') {
         let before_paren = d.split('(').next().unwrap_or(d);
         let variant = before_paren.split("::").last().unwrap_or(before_paren);
-        let mut result = String::new();
-        for (i, c) in variant.chars().enumerate() {
-            if c.is_uppercase() && i > 0 { result.push('-'); }
-            result.push(c.to_lowercase().next().unwrap());
-        }
-        return result;
+        return pascal_to_kebab(variant);
     }
     d.to_string()
 }
@@ -10,7 +10,8 @@ pub(crate) fn render_api_with_boundary(component_id: &str, components: &[Compone
     let mut out = String::new();
     out.push_str("// AUTO-GENERATED by build.rs — do not edit manually.\n");
     out.push_str("// Source: *_boundary.rs + builder.yaml\n");
-    out.push_str("use crate::catalog_types::{ComponentApi, PropDef, PropType};\n\n");
+    // ui/blocks/layouts vivem no canonrs-server — os tipos vêm do canonrs-core
+    out.push_str("use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};\n\n");
 
     // gera linha de imports para enums externos
     if !boundary_content.is_empty() {
@@ -70,7 +71,7 @@ fn render_prop(prop: &PropDef) -> String {
     )
 }
 
-fn prop_description(name: &str, _kind: &PropKind) -> &'static str {
+pub(crate) fn prop_description(name: &str, _kind: &PropKind) -> &'static str {
     match name {
         "children"        => "Child elements rendered inside the component",
         "class"           => "Additional CSS class names",
@@ -78,6 +79,10 @@ fn prop_description(name: &str, _kind: &PropKind) -> &'static str {
         "size"            => "Size variant of the component",
         "disabled"        => "Whether the component is disabled",
         "checked"         => "Whether the component is checked",
+        "readonly"        => "Whether the value is read-only",
+        "required"        => "Whether a value is required",
+        "invalid"         => "Whether the value failed validation",
+        "expanded"        => "Whether the item is expanded",
         "placeholder"     => "Placeholder text",
         "value"           => "Current value",
         "name"            => "Form field name",
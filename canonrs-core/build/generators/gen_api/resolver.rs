@@ -35,16 +35,16 @@ pub(crate) fn resolve_type(ty: &str, name: &str, full_content: &str) -> PropKind
     let variants = extract_enum_variants(full_content, clean);
     if !variants.is_empty() { return PropKind::Enum(variants); }
 
-    // 2. busca nos primitives do canonrs-core
+    // 2. busca nos primitives do canonrs-core (inclui subdiretórios: primitives/layout/*)
     let primitives_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src/primitives");
-    if let Ok(entries) = std::fs::read_dir(&primitives_dir) {
-        for entry in entries.flatten() {
-            if entry.path().extension().map_or(true, |e| e != "rs") { continue; }
-            if let Ok(prim_content) = std::fs::read_to_string(entry.path()) {
-                let variants = extract_enum_variants(&prim_content, clean);
-                if !variants.is_empty() { return PropKind::Enum(variants); }
-            }
-        }
+    let variants = find_enum_in_dir(&primitives_dir, clean);
+    if !variants.is_empty() { return PropKind::Enum(variants); }
+
+    // 3. alias (`pub type DataTableDensity = crate::infra::density::Density;`) → enum em qualquer lugar de src/
+    if let Some(target) = find_type_alias(&primitives_dir, clean) {
+        let src_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let variants = find_enum_in_dir(&src_dir, &target);
+        if !variants.is_empty() { return PropKind::Enum(variants); }
     }
 
     // Fallback semantico por nome
@@ -57,6 +57,45 @@ pub(crate) fn resolve_type(ty: &str, name: &str, full_content: &str) -> PropKind
     PropKind::StringT
 }
 
+fn find_enum_in_dir(dir: &std::path::Path, enum_name: &str) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return vec![] };
+    let mut paths: Vec<_> = entries.flatten().map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        let variants = if path.is_dir() {
+            find_enum_in_dir(&path, enum_name)
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            std::fs::read_to_string(&path).map(|c| extract_enum_variants(&c, enum_name)).unwrap_or_default()
+        } else {
+            continue;
+        };
+        if !variants.is_empty() { return variants; }
+    }
+    vec![]
+}
+
+fn find_type_alias(dir: &std::path::Path, alias: &str) -> Option<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return None };
+    let mut paths: Vec<_> = entries.flatten().map(|e| e.path()).collect();
+    paths.sort();
+    let needle = format!("pub type {} = ", alias);
+    for path in paths {
+        let target = if path.is_dir() {
+            find_type_alias(&path, alias)
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            std::fs::read_to_string(&path).ok().and_then(|c| {
+                let start = c.find(&needle)? + needle.len();
+                let end = c[start..].find(';')? + start;
+                Some(c[start..end].split("::").last()?.trim().to_string())
+            })
+        } else {
+            continue;
+        };
+        if target.is_some() { return target; }
+    }
+    None
+}
+
 pub(crate) fn extract_enum_variants(content: &str, enum_name: &str) -> Vec<String> {
     let mut variants = Vec::new();
     let needle  = format!("pub enum {} {{", enum_name);
@@ -69,8 +108,8 @@ pub(crate) fn extract_enum_variants(content: &str, enum_name: &str) -> Vec<Strin
     let bs = match content[start..].find('{') { Some(b) => start + b + 1, None => return variants };
     let be = match content[bs..].find('}')    { Some(e) => bs + e,        None => return variants };
     for line in content[bs..be].lines() {
-        let t = line.trim();
-        if t.is_empty() || t.starts_with("//") || t.starts_with('#') { continue; }
+        let t = strip_attributes(line.trim());
+        if t.is_empty() || t.starts_with("//") { continue; }
         for part in t.split(',') {
             let v = part.trim().trim_end_matches(',');
             if v.is_empty() || v.starts_with("//") { continue; }
@@ -83,6 +122,17 @@ pub(crate) fn extract_enum_variants(content: &str, enum_name: &str) -> Vec<Strin
     variants
 }
 
+/// `#[default] Default,` → `Default,` — o variant marcado com atributo também é um valor do enum
+fn strip_attributes(mut line: &str) -> &str {
+    while line.starts_with("#[") {
+        match line.find(']') {
+            Some(end) => line = line[end + 1..].trim_start(),
+            None      => return "",
+        }
+    }
+    line
+}
+
 pub(crate) fn pascal_to_kebab(s: &str) -> String {
     match s {
         "One"    => return "1".to_string(),
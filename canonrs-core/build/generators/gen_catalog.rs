@@ -6,8 +6,11 @@ use std::collections::HashMap;
 use super::types::*;
 use super::utils::*;
 use super::parsers::parse_slot_accepts;
+use super::gen_json_schema::{component_regions, block_regions};
 
-pub(crate) fn generate_catalog(semantic: &HashMap<String, SemanticEntry>, blocks: &[BlockInfo], out_dir: &Path, blocks_dir: &Path, layouts_dir: &Path) {
+/// `regions` de cada entry = chaves válidas em `PageNode::regions` — as mesmas do JSON Schema e do renderer
+/// Components: slots Children (`children` + props Children); blocks/layouts: regions declaradas + slots
+pub(crate) fn generate_catalog(semantic: &HashMap<String, SemanticEntry>, blocks: &[BlockInfo], out_dir: &Path, ui_dir: &Path, blocks_dir: &Path, layouts_dir: &Path) {
     let mut code = String::new();
     code.push_str("// AUTO-GENERATED\n");
     code.push_str("use crate::catalog_types::{CatalogEntry, CatalogCategory, CatalogKind, CatalogAcceptRule, CatalogRegionRule};\n\n");
@@ -23,10 +26,13 @@ pub(crate) fn generate_catalog(semantic: &HashMap<String, SemanticEntry>, blocks
         let parts = fmt_str_slice(&s.required_parts.iter()
             .chain(s.optional_parts.iter())
             .cloned().collect::<Vec<_>>());
+        let keywords = fmt_str_slice(&s.keywords);
+        let related  = fmt_str_slice(&s.related);
+        let regions  = fmt_region_names(&component_regions(id, ui_dir));
         code.push_str(&format!(
-            "    CatalogEntry {{ id: \"{id}\", label: \"{label}\", description: \"{desc}\", kind: CatalogKind::Component, category: {cat}, tags: &[{tags}], parts: &[{parts}], regions: &[], accepts: {accepts}, region_rules: &[] }},\n",
-            id = id, label = s.label, desc = s.description, cat = cat, tags = tags, parts = parts,
-            accepts = catalog_accepts_for_component()
+            "    CatalogEntry {{ id: \"{id}\", label: \"{label}\", description: \"{desc}\", kind: CatalogKind::Component, category: {cat}, tags: &[{tags}], parts: &[{parts}], regions: &[{regions}], accepts: {accepts}, region_rules: &[], keywords: &[{keywords}], related: &[{related}] }},\n",
+            id = id, label = s.label, desc = s.description, cat = cat, tags = tags, parts = parts, regions = regions,
+            accepts = catalog_accepts_for_component(), keywords = keywords, related = related
         ));
     }
 
@@ -35,15 +41,17 @@ pub(crate) fn generate_catalog(semantic: &HashMap<String, SemanticEntry>, blocks
         let label   = b.label.clone().unwrap_or_else(|| to_title_case(&b.id));
         let desc    = b.description.clone().unwrap_or_else(|| format!("{} block", label));
         let tags    = fmt_str_slice(&if b.tags.is_empty() { vec![b.id.clone()] } else { b.tags.clone() });
-        let regions = fmt_str_slice(&b.regions);
+        let regions = fmt_region_names(&block_regions(b, blocks_dir));
         let cat     = to_catalog_category(&b.category);
         let block_accepts      = catalog_accepts_for_block(&b.category);
         let block_file = blocks_dir.join(b.id.replace('-', "_")).join(format!("{}_block.rs", b.id.replace('-', "_")));
         let block_region_rules = catalog_region_rules_from_file(&block_file);
+        let keywords = fmt_str_slice(&b.keywords);
+        let related  = fmt_str_slice(&b.related);
         code.push_str(&format!(
-            "    CatalogEntry {{ id: \"block.{id}\", label: \"{label}\", description: \"{desc}\", kind: CatalogKind::Block, category: {cat}, tags: &[{tags}], parts: &[], regions: &[{regions}], accepts: {accepts}, region_rules: {rr} }},\n",
+            "    CatalogEntry {{ id: \"block.{id}\", label: \"{label}\", description: \"{desc}\", kind: CatalogKind::Block, category: {cat}, tags: &[{tags}], parts: &[], regions: &[{regions}], accepts: {accepts}, region_rules: {rr}, keywords: &[{keywords}], related: &[{related}] }},\n",
             id = b.id, label = label, desc = desc, cat = cat, tags = tags, regions = regions,
-            accepts = block_accepts, rr = block_region_rules
+            accepts = block_accepts, rr = block_region_rules, keywords = keywords, related = related
         ));
     }
 
@@ -52,17 +60,19 @@ pub(crate) fn generate_catalog(semantic: &HashMap<String, SemanticEntry>, blocks
         let label   = b.label.clone().unwrap_or_else(|| to_title_case(&b.id));
         let desc    = b.description.clone().unwrap_or_else(|| format!("{} layout", label));
         let tags    = fmt_str_slice(&if b.tags.is_empty() { vec![b.id.clone()] } else { b.tags.clone() });
-        let regions = fmt_str_slice(&b.regions);
+        let regions = fmt_region_names(&block_regions(b, layouts_dir));
         // Tenta com id snake_case, fallback para nome do diretório sem sufixo
         let layout_dir_name = b.id.replace('-', "_").replace("_layout", "");
         let layout_file1 = layouts_dir.join(&layout_dir_name).join(format!("{}_layout.rs", layout_dir_name));
         let layout_file2 = layouts_dir.join(b.id.replace('-', "_")).join(format!("{}_layout.rs", b.id.replace('-', "_")));
         let layout_file = if layout_file1.exists() { layout_file1 } else { layout_file2 };
         let layout_region_rules = catalog_region_rules_from_file(&layout_file);
+        let keywords = fmt_str_slice(&b.keywords);
+        let related  = fmt_str_slice(&b.related);
         code.push_str(&format!(
-            "    CatalogEntry {{ id: \"layout.{id}\", label: \"{label}\", description: \"{desc}\", kind: CatalogKind::Layout, category: CatalogCategory::Layout, tags: &[{tags}], parts: &[], regions: &[{regions}], accepts: {accepts}, region_rules: {rr} }},\n",
+            "    CatalogEntry {{ id: \"layout.{id}\", label: \"{label}\", description: \"{desc}\", kind: CatalogKind::Layout, category: CatalogCategory::Layout, tags: &[{tags}], parts: &[], regions: &[{regions}], accepts: {accepts}, region_rules: {rr}, keywords: &[{keywords}], related: &[{related}] }},\n",
             id = b.id, label = label, desc = desc, tags = tags, regions = regions,
-            accepts = catalog_accepts_for_layout(), rr = layout_region_rules
+            accepts = catalog_accepts_for_layout(), rr = layout_region_rules, keywords = keywords, related = related
         ));
     }
 
@@ -74,8 +84,12 @@ fn fmt_str_slice(items: &[String]) -> String {
     items.iter().map(|t| format!("\"{}\"", t)).collect::<Vec<_>>().join(", ")
 }
 
+fn fmt_region_names(regions: &[(String, bool)]) -> String {
+    regions.iter().map(|(name, _)| format!("\"{}\"", name)).collect::<Vec<_>>().join(", ")
+}
+
 fn catalog_accepts_for_component() -> &'static str {
-    // Componentes são leaf nodes — não aceitam filhos estruturais
+    // Componentes não têm regra de aceitação — qualquer node entra num slot (regions)
     "&[]"
 }
 
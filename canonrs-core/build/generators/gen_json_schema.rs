@@ -0,0 +1,351 @@
+//! Generator: JSON Schema (draft 2020-12)
+//! schemas/{components,blocks,layouts}/<id>.schema.json + schemas/canonrs.schema.json (bundle)
+//! Cada schema descreve uma instância no PageDocument: { id, catalog_id, version, props, regions }
+//! catalog_id = id do CatalogEntry (`button`, `block.card`, `layout.page-layout`) — um por schema
+//! regions = CatalogEntry::regions (slots Children / regions do contract) — o bundle é um oneOf disjunto
+//! Filhos de regions apontam para o oneOf do bundle — cada filho é validado pelo schema do seu catalog_id
+//! Props/enums/defaults: assinatura do #[component] raiz — blocks: @canon-prop sobrepõe
+//! Parts: builder.yaml required_parts/optional_parts (props de cada part em $defs)
+
+use std::collections::HashMap;
+use std::path::Path;
+use serde_json::{json, Map, Value};
+use super::types::*;
+use super::parsers::{parse_block_props, split_outside_parens};
+use super::gen_api::parser::{parse_components, ComponentDef, PropDef};
+use super::gen_api::resolver::{pascal_to_kebab, PropKind};
+use super::gen_api::renderer::prop_description;
+use super::gen_api::write_if_changed;
+
+const DRAFT: &str   = "https://json-schema.org/draft/2020-12/schema";
+const BASE_ID: &str = "https://canonrs.dev/schemas/";
+const BUNDLE: &str  = "canonrs.schema.json";
+
+pub(crate) fn generate_json_schemas(
+    semantic:    &HashMap<String, SemanticEntry>,
+    blocks:      &[BlockInfo],
+    ui_dir:      &Path,
+    blocks_dir:  &Path,
+    layouts_dir: &Path,
+    schemas_dir: &Path,
+    out_dir:     &Path,
+) {
+    let mut ids: Vec<&String> = semantic.keys().collect();
+    ids.sort();
+
+    let mut bundle = Map::new();
+    for id in ids {
+        let schema = component_schema(&semantic[id], ui_dir);
+        write_entry(schemas_dir, "components", id, &schema, &mut bundle);
+    }
+
+    let mut sorted: Vec<&BlockInfo> = blocks.iter().collect();
+    sorted.sort_by(|a, b| a.id.cmp(&b.id));
+    for b in sorted {
+        let (folder, dir) = if b.kind == "layout" { ("layouts", layouts_dir) } else { ("blocks", blocks_dir) };
+        let schema = block_schema(b, dir);
+        write_entry(schemas_dir, folder, &b.id, &schema, &mut bundle);
+    }
+
+    // catalog_id de cada schema é único — exatamente um branch casa com cada node
+    let one_of: Vec<Value> = bundle.values().map(|s| json!({ "$ref": s["$id"] })).collect();
+    let count = bundle.len();
+    let document = json!({
+        "$schema": DRAFT,
+        "$id": format!("{}{}", BASE_ID, BUNDLE),
+        "title": "CanonRS",
+        "description": "Any CanonRS component, block or layout instance in a PageDocument",
+        "oneOf": one_of,
+        "$defs": bundle,
+    });
+    let content = pretty(&document);
+    write_if_changed(&schemas_dir.join(BUNDLE), &content);
+    write_if_changed(&out_dir.join(BUNDLE), &content);
+    println!("cargo:warning=CanonRS JSON Schema: {} schemas", count);
+}
+
+fn write_entry(schemas_dir: &Path, folder: &str, id: &str, schema: &Value, bundle: &mut Map<String, Value>) {
+    let dir = schemas_dir.join(folder);
+    std::fs::create_dir_all(&dir).unwrap();
+    write_if_changed(&dir.join(format!("{}.schema.json", id)), &pretty(schema));
+    bundle.insert(format!("{}.{}", folder.trim_end_matches('s'), id), schema.clone());
+}
+
+fn pretty(value: &Value) -> String {
+    let mut s = serde_json::to_string_pretty(value).unwrap();
+    s.push('\n');
+    s
+}
+
+// ── Components ───────────────────────────────────────────────────────────────
+
+fn component_schema(entry: &SemanticEntry, ui_dir: &Path) -> Value {
+    let content = component_source(&entry.id, ui_dir);
+    let components = parse_components(&content);
+    let root = root_component(&components, &entry.id);
+    let regions = component_slots(root);
+
+    let mut defs = Map::new();
+    defs.insert("node".into(), node_ref_schema());
+    for part in entry.required_parts.iter().chain(&entry.optional_parts) {
+        if let Some(def) = components.iter().find(|c| &c.name == part) {
+            defs.insert(part.clone(), props_schema(&def.props.iter().collect::<Vec<_>>(), &[]));
+        }
+    }
+
+    let props: Vec<&PropDef> = root.map(|d| d.props.iter().collect()).unwrap_or_default();
+    let mut schema = node_schema(
+        &format!("components/{}", entry.id),
+        &entry.label,
+        &entry.description,
+        &[entry.id.as_str()],
+        props_schema(&props, &[]),
+        regions_schema(&regions),
+    );
+    let obj = schema.as_object_mut().unwrap();
+    obj.insert("x-canonrs-kind".into(), json!("component"));
+    obj.insert("x-canonrs-family".into(), json!(entry.family));
+    obj.insert("x-canonrs-parts".into(), json!({
+        "required": entry.required_parts,
+        "optional": entry.optional_parts,
+    }));
+    obj.insert("$defs".into(), Value::Object(defs));
+    schema
+}
+
+/// Regions de um component no PageDocument (mesmo modelo do renderer e do catalog)
+pub(crate) fn component_regions(id: &str, ui_dir: &Path) -> Vec<(String, bool)> {
+    let components = parse_components(&component_source(id, ui_dir));
+    component_slots(root_component(&components, id))
+}
+
+fn component_source(id: &str, ui_dir: &Path) -> String {
+    let dir_name = id.replace('-', "_");
+    std::fs::read_to_string(ui_dir.join(&dir_name).join(format!("{}_boundary.rs", dir_name)))
+        .unwrap_or_default()
+}
+
+/// Slots nomeados (Children) viram regions — `children` é a region default
+fn component_slots(root: Option<&ComponentDef>) -> Vec<(String, bool)> {
+    let mut regions: Vec<(String, bool)> = vec![];
+    if let Some(def) = root {
+        if def.children { regions.push(("children".into(), false)); }
+        for p in def.props.iter().filter(|p| matches!(p.ty, PropKind::Children)) {
+            regions.push((p.name.clone(), p.required));
+        }
+    }
+    regions
+}
+
+/// Componente cujo nome em kebab bate com o id — senão o primeiro do arquivo
+fn root_component<'a>(components: &'a [ComponentDef], id: &str) -> Option<&'a ComponentDef> {
+    components.iter()
+        .find(|c| pascal_to_kebab(&c.name) == id)
+        .or_else(|| components.first())
+}
+
+// ── Blocks / layouts ─────────────────────────────────────────────────────────
+
+fn block_schema(b: &BlockInfo, dir: &Path) -> Value {
+    let (content, yaml) = block_source(b, dir);
+    let components = parse_components(&content);
+    let root = root_component(&components, &b.id);
+    let regions = block_slots(b, root, &yaml);
+
+    // Props com nome de region são slots — já descritos em `regions`
+    let props: Vec<&PropDef> = root
+        .map(|d| d.props.iter().filter(|p| !regions.iter().any(|(r, _)| *r == p.name)).collect())
+        .unwrap_or_default();
+    let canon_props = parse_block_props(&content);
+
+    let label = b.label.clone().unwrap_or_else(|| b.id.clone());
+    let description = b.description.clone().unwrap_or_default();
+    let catalog_id = format!("{}.{}", b.kind, b.id);
+    let mut schema = node_schema(
+        &format!("{}s/{}", b.kind, b.id),
+        &label,
+        &description,
+        &[catalog_id.as_str()],
+        props_schema(&props, &canon_props),
+        regions_schema(&regions),
+    );
+    let obj = schema.as_object_mut().unwrap();
+    obj.insert("x-canonrs-kind".into(), json!(b.kind));
+    obj.insert("x-canonrs-category".into(), json!(b.category));
+    obj.insert("x-canonrs-container".into(), json!(b.container));
+    obj.insert("$defs".into(), json!({ "node": node_ref_schema() }));
+    schema
+}
+
+/// Regions de um block/layout no PageDocument (mesmo modelo do renderer e do catalog)
+pub(crate) fn block_regions(b: &BlockInfo, dir: &Path) -> Vec<(String, bool)> {
+    let (content, yaml) = block_source(b, dir);
+    let components = parse_components(&content);
+    block_slots(b, root_component(&components, &b.id), &yaml)
+}
+
+/// `<id>_block.rs`/`<id>_layout.rs` + builder.yaml — layouts aceitam o diretório sem `_layout`
+fn block_source(b: &BlockInfo, dir: &Path) -> (String, String) {
+    let dir_name = b.id.replace('-', "_");
+    let candidates = [dir_name.clone(), dir_name.trim_end_matches("_layout").to_string()];
+    candidates.iter()
+        .find_map(|d| {
+            let base = dir.join(d);
+            [format!("{}_block.rs", d), format!("{}_layout.rs", d)].iter()
+                .find_map(|f| std::fs::read_to_string(base.join(f)).ok())
+                .map(|c| (c, std::fs::read_to_string(base.join("builder.yaml")).unwrap_or_default()))
+        })
+        .unwrap_or_default()
+}
+
+/// Regions: BlockInfo + contract do builder.yaml + slots ChildrenFn da assinatura
+fn block_slots(b: &BlockInfo, root: Option<&ComponentDef>, yaml: &str) -> Vec<(String, bool)> {
+    let required = contract_list(yaml, "regions_required");
+    let mut names: Vec<String> = b.regions.clone();
+    names.extend(required.iter().cloned());
+    names.extend(contract_list(yaml, "regions_optional"));
+    if let Some(def) = root {
+        names.extend(def.props.iter().filter(|p| matches!(p.ty, PropKind::Children)).map(|p| p.name.clone()));
+    }
+    let mut regions: Vec<(String, bool)> = vec![];
+    for name in names {
+        if !regions.iter().any(|(r, _)| *r == name) {
+            let is_required = required.contains(&name);
+            regions.push((name, is_required));
+        }
+    }
+    regions
+}
+
+/// `regions_required: ["a", "b"]` do bloco `contract:` do builder.yaml
+fn contract_list(yaml: &str, field: &str) -> Vec<String> {
+    yaml.lines()
+        .map(str::trim)
+        .find_map(|l| l.strip_prefix(field)?.strip_prefix(':').map(str::to_string))
+        .map(|v| v.split(',')
+            .map(|s| s.trim().trim_matches(|c: char| c == '"' || c == '\'' || c == '[' || c == ']').to_string())
+            .filter(|s| !s.is_empty())
+            .collect())
+        .unwrap_or_default()
+}
+
+// ── Schema pieces ────────────────────────────────────────────────────────────
+
+fn node_schema(path: &str, title: &str, description: &str, catalog_ids: &[&str], props: Value, regions: Value) -> Value {
+    json!({
+        "$schema": DRAFT,
+        "$id": format!("{}{}.schema.json", BASE_ID, path),
+        "title": title,
+        "description": description,
+        "type": "object",
+        "properties": {
+            "id":         { "type": "integer", "minimum": 0 },
+            "catalog_id": { "enum": catalog_ids },
+            "version":    { "type": "integer", "minimum": 1, "default": 1 },
+            "props":      props,
+            "regions":    regions,
+        },
+        "required": ["id", "catalog_id"],
+        "additionalProperties": false,
+    })
+}
+
+/// Filhos de regions — oneOf do bundle: o catalog_id escolhe o schema, recursivamente até as folhas
+fn node_ref_schema() -> Value {
+    json!({ "$ref": format!("{}{}", BASE_ID, BUNDLE) })
+}
+
+fn regions_schema(regions: &[(String, bool)]) -> Value {
+    let mut properties = Map::new();
+    for (name, _) in regions {
+        properties.insert(name.clone(), json!({ "type": "array", "items": { "$ref": "#/$defs/node" } }));
+    }
+    let required: Vec<&String> = regions.iter().filter(|(_, r)| *r).map(|(n, _)| n).collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Props da assinatura Rust + @canon-prop (que vence em caso de mesma key)
+fn props_schema(props: &[&PropDef], canon: &[PropInfo]) -> Value {
+    let mut properties = Map::new();
+    let mut required = vec![];
+    for p in props.iter().filter(|p| !matches!(p.ty, PropKind::Children)) {
+        properties.insert(p.name.clone(), prop_schema(p));
+        if p.required { required.push(p.name.clone()); }
+    }
+    for p in canon {
+        properties.insert(p.key.clone(), canon_prop_schema(p));
+        required.retain(|r| r != &p.key);
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn prop_schema(p: &PropDef) -> Value {
+    let mut s = match &p.ty {
+        PropKind::StringT     => json!({ "type": "string" }),
+        PropKind::Bool        => json!({ "type": "boolean" }),
+        PropKind::Number      => json!({ "type": "number" }),
+        PropKind::Enum(vals)  => json!({ "type": "string", "enum": vals }),
+        PropKind::Children    => json!({}),
+    };
+    let description = prop_description(&p.name, &p.ty);
+    if description != "Prop value" {
+        s["description"] = json!(description);
+    }
+    if let Some(default) = p.default.as_deref().and_then(|d| typed_default(&p.ty, d)) {
+        s["default"] = default;
+    }
+    s
+}
+
+/// Defaults já normalizados pelo parser — descarta o que não é literal (vec![], expressões)
+fn typed_default(ty: &PropKind, raw: &str) -> Option<Value> {
+    match ty {
+        PropKind::Bool => raw.parse::<bool>().ok().map(Value::Bool),
+        PropKind::Number => {
+            let digits = raw.trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '_');
+            if let Ok(i) = digits.parse::<i64>() { return Some(json!(i)); }
+            digits.parse::<f64>().ok().map(|f| json!(f))
+        }
+        PropKind::Enum(vals) => vals.iter().any(|v| v == raw).then(|| json!(raw)),
+        PropKind::StringT => (!raw.contains('!') && !raw.contains('(')).then(|| json!(raw)),
+        PropKind::Children => None,
+    }
+}
+
+fn canon_prop_schema(p: &PropInfo) -> Value {
+    let mut s = if let Some(inner) = p.field.strip_prefix("Select(").and_then(|f| f.strip_suffix(')')) {
+        let values: Vec<&str> = split_outside_parens(inner).into_iter().map(|o| o.split(':').next().unwrap_or(o).trim()).collect();
+        json!({ "type": "string", "enum": values })
+    } else {
+        match p.field.as_str() {
+            "Number" => json!({ "type": "number" }),
+            "Toggle" => json!({ "type": "boolean" }),
+            "Color"  => json!({ "type": "string", "x-canonrs-field": "color" }),
+            _        => json!({ "type": "string" }),
+        }
+    };
+    s["title"] = json!(p.label);
+    s["x-canonrs-scope"] = json!(p.scope);
+    if let Some(d) = &p.default {
+        // parse_block_props já rebaixa Number com unidade para Text — default não numérico fica de fora
+        let default = match s["type"].as_str() {
+            Some("number")  => d.parse::<f64>().ok().map(|f| json!(f)),
+            Some("boolean") => Some(json!(d == "true")),
+            _               => Some(json!(d)),
+        };
+        if let Some(default) = default {
+            s["default"] = default;
+        }
+    }
+    s
+}
@@ -25,5 +25,8 @@ pub(crate) use gen_llm::generate_llm_context;
 pub(crate) mod gen_rules;
 pub(crate) use gen_rules::{parse_rules, generate_rules_json, generate_rules_seo, generate_rules_llm};
 
+pub(crate) mod gen_json_schema;
+pub(crate) use gen_json_schema::generate_json_schemas;
+
 pub(crate) mod gen_showcase;
 pub(crate) use gen_showcase::generate_showcase;
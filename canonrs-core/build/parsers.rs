@@ -157,7 +157,20 @@ fn parse_builder_yaml(content: &str, kind: &str) -> Option<BlockInfo> {
             .filter(|s| !s.is_empty())
             .collect())
         .unwrap_or_default();
-    Some(BlockInfo { id, kind: kind.to_string(), category, variant, container, regions, label, description, tags })
+    // Mesmo formato dos components — related já em ids do catalog (`block.*`, `layout.*` ou UI)
+    let keywords    = extract_builder_field(content, "keywords")
+        .map(|t| t.split(',')
+            .map(|s| s.trim().trim_matches(|c: char| c == '"' || c == '\'' || c == '[' || c == ']').to_string())
+            .filter(|s| !s.is_empty())
+            .collect())
+        .unwrap_or_default();
+    let related     = extract_builder_field(content, "related")
+        .map(|t| t.split(',')
+            .map(|s| s.trim().trim_matches(|c: char| c == '"' || c == '\'' || c == '[' || c == ']').replace('_', "-"))
+            .filter(|s| !s.is_empty())
+            .collect())
+        .unwrap_or_default();
+    Some(BlockInfo { id, kind: kind.to_string(), category, variant, container, regions, label, description, tags, keywords, related })
 }
 
 fn parse_canon_header(content: &str, kind: &str) -> Option<BlockInfo> {
@@ -173,7 +186,13 @@ fn parse_canon_header(content: &str, kind: &str) -> Option<BlockInfo> {
     let tags        = extract_canon_field(content, "canon-tags")
         .map(|t| t.split(',').map(|s| s.trim().to_string()).collect())
         .unwrap_or_default();
-    Some(BlockInfo { id, kind: kind.to_string(), category, variant, container, regions, label, description, tags })
+    let keywords    = extract_canon_field(content, "canon-keywords")
+        .map(|t| t.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    let related     = extract_canon_field(content, "canon-related")
+        .map(|t| t.split(',').map(|s| s.trim().replace('_', "-")).filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    Some(BlockInfo { id, kind: kind.to_string(), category, variant, container, regions, label, description, tags, keywords, related })
 }
 
 pub(crate) fn extract_canon_field(content: &str, field: &str) -> Option<String> {
@@ -191,10 +210,12 @@ pub(crate) fn parse_block_props(content: &str) -> Vec<PropInfo> {
         let parts: Vec<&str> = val.splitn(6, '|').map(|p| p.trim()).collect();
         if parts.len() < 4 { continue; }
         let key     = parts[0].to_string();
-        let field   = parts[1].to_string();
         let default = if parts[2].is_empty() { None } else { Some(parts[2].to_string()) };
         let scope   = parts[3].to_string();
         let css     = if parts.len() > 4 && !parts[4].is_empty() { Some(parts[4].to_string()) } else { None };
+        // Number com alvo CSS (gap, padding...) ou default com unidade é comprimento CSS — `gap: 12` não é CSS válido
+        let is_length = css.is_some() || default.as_deref().is_some_and(|d| d.parse::<f64>().is_err());
+        let field   = if parts[1] == "Number" && is_length { "Text".to_string() } else { parts[1].to_string() };
         let label   = key.replace('-', " ")
             .split_whitespace()
             .map(|w| { let mut c = w.chars(); c.next().map(|f| f.to_uppercase().collect::<String>() + c.as_str()).unwrap_or_default() })
@@ -315,6 +336,19 @@ pub(crate) fn parse_ui_components_semantic(ui_dir: &Path) -> HashMap<String, Sem
                 .filter(|s| !s.is_empty())
                 .collect())
             .unwrap_or_default();
+        // keywords: frases livres separadas por vírgula; related: ids em snake_case → ids do catalog
+        let keywords = extract_builder_field(&content, "keywords")
+            .map(|v| v.split(',')
+                .map(|s| s.trim().trim_matches(|c: char| c == '"' || c == '\'' || c == '[' || c == ']').to_string())
+                .filter(|s| !s.is_empty())
+                .collect())
+            .unwrap_or_default();
+        let related = extract_builder_field(&content, "related")
+            .map(|v| v.split(',')
+                .map(|s| s.trim().trim_matches(|c: char| c == '"' || c == '\'' || c == '[' || c == ']').replace('_', "-"))
+                .filter(|s| !s.is_empty())
+                .collect())
+            .unwrap_or_default();
         map.insert(id.clone(), SemanticEntry {
             id,
             label,
@@ -328,12 +362,14 @@ pub(crate) fn parse_ui_components_semantic(ui_dir: &Path) -> HashMap<String, Sem
             optional_parts,
             composable,
             requires_config: false,
+            keywords,
+            related,
         });
     }
     map
 }
 
-fn split_outside_parens(s: &str) -> Vec<&str> {
+pub(crate) fn split_outside_parens(s: &str) -> Vec<&str> {
     let mut result = Vec::new();
     let mut depth  = 0usize;
     let mut start  = 0usize;
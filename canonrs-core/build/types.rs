@@ -28,6 +28,8 @@ pub(crate) struct SemanticEntry {
     #[allow(dead_code)] #[serde(default)] pub optional_parts:  Vec<String>,
     #[allow(dead_code)] #[serde(default)] pub composable:      bool,
     #[allow(dead_code)] #[serde(default)] pub requires_config: bool,
+    #[serde(default)] pub keywords: Vec<String>,
+    #[serde(default)] pub related:  Vec<String>,
 }
 
 
@@ -42,6 +44,8 @@ pub(crate) struct BlockInfo {
     #[allow(dead_code)] pub label:       Option<String>,
     #[allow(dead_code)] pub description: Option<String>,
     #[allow(dead_code)] pub tags:        Vec<String>,
+    pub keywords:    Vec<String>,
+    pub related:     Vec<String>,
 }
 
 #[derive(Debug, Clone)]
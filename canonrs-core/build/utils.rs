@@ -36,7 +36,28 @@ pub(crate) fn to_family(family: &str) -> &'static str {
         "data_display" => "ComponentFamily::DataDisplay",
         "typography"   => "ComponentFamily::Typography",
         "interactive"  => "ComponentFamily::Interactive",
-        _              => "ComponentFamily::Utility",
+        "utility"      => "ComponentFamily::Utility",
+        _              => builder_family(family),
+    }
+}
+
+/// Taxonomia do builder.yaml (`family-<letra>-<nome>`) → ComponentFamily
+/// Sem isso todo component caía em Utility — AriaAudit::family_rules e CatalogQuery::family ficavam mudos
+/// forms → Input, selection/composite → Interactive (controles com estado), data → DataDisplay
+fn builder_family(family: &str) -> &'static str {
+    let name = family.strip_prefix("family-")
+        .and_then(|rest| rest.split_once('-'))
+        .map_or("", |(_, name)| name);
+    match name {
+        "overlay"                 => "ComponentFamily::Overlay",
+        "forms"                   => "ComponentFamily::Input",
+        "selection" | "composite" => "ComponentFamily::Interactive",
+        "navigation"              => "ComponentFamily::Navigation",
+        "feedback"                => "ComponentFamily::Feedback",
+        "data"                    => "ComponentFamily::DataDisplay",
+        "layout"                  => "ComponentFamily::Layout",
+        // animation e famílias novas — sem regras ARIA próprias
+        _                         => "ComponentFamily::Utility",
     }
 }
 
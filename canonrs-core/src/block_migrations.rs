@@ -0,0 +1,292 @@
+//! Block Migrations — upgrades stored block instances to the current schema
+//! Keyed by (block id, from version); each step upgrades exactly one version
+//! Runs on load: all or nothing — on failure the document is left untouched
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::block_types::BlockDefinition;
+use crate::page_document::{pointer_segment, NodeId, PageDocument, PageNode};
+
+/// Um passo de migração — recebe o node na versão `from` e deixa em `from + 1`
+pub type MigrateFn = fn(&mut PageNode) -> Result<(), String>;
+
+#[derive(Clone, Copy)]
+pub struct Migration {
+    pub block_id:    &'static str,
+    pub from:        u32,
+    pub description: &'static str,
+    pub migrate:     MigrateFn,
+}
+
+/// Uma migração aplicada a um node
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AppliedMigration {
+    pub path:        String,
+    pub node_id:     NodeId,
+    pub block_id:    String,
+    pub from:        u32,
+    pub to:          u32,
+    pub description: &'static str,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct MigrationReport {
+    pub applied: Vec<AppliedMigration>,
+}
+
+impl MigrationReport {
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MigrationError {
+    /// Nenhum passo registrado para sair de `from`
+    MissingStep { path: String, block_id: String, from: u32, to: u32 },
+    /// Node salvo por um schema mais novo que o atual
+    FromFuture { path: String, block_id: String, version: u32, current: u32 },
+    /// O passo recusou os dados
+    Failed { path: String, block_id: String, from: u32, reason: String },
+    Json(String),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingStep { path, block_id, from, to } =>
+                write!(f, "{}: no migration for '{}' from v{} (target v{})", path, block_id, from, to),
+            Self::FromFuture { path, block_id, version, current } =>
+                write!(f, "{}: '{}' v{} is newer than the current schema v{}", path, block_id, version, current),
+            Self::Failed { path, block_id, from, reason } =>
+                write!(f, "{}: migration of '{}' from v{} failed: {}", path, block_id, from, reason),
+            Self::Json(e) => write!(f, "invalid document: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+#[derive(Clone, Default)]
+pub struct MigrationRegistry {
+    steps: BTreeMap<&'static str, BTreeMap<u32, Migration>>,
+}
+
+impl MigrationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, migration: Migration) -> &mut Self {
+        self.steps.entry(migration.block_id).or_default().insert(migration.from, migration);
+        self
+    }
+
+    pub fn with(mut self, migration: Migration) -> Self {
+        self.register(migration);
+        self
+    }
+
+    pub fn get(&self, block_id: &str, from: u32) -> Option<&Migration> {
+        self.steps.get(block_id)?.get(&from)
+    }
+
+    /// Atualiza todos os blocks do documento até `BlockDefinition::version`
+    /// Todo block sai com `version` carimbada, mesmo quando nenhum passo rodou
+    pub fn migrate(&self, doc: &mut PageDocument) -> Result<MigrationReport, MigrationError> {
+        self.migrate_with(doc, current_version)
+    }
+
+    /// Deserializa e migra — entrada para páginas armazenadas
+    pub fn load(&self, json: &str) -> Result<(PageDocument, MigrationReport), MigrationError> {
+        let mut doc = PageDocument::from_json(json).map_err(|e| MigrationError::Json(e.to_string()))?;
+        let report = self.migrate(&mut doc)?;
+        Ok((doc, report))
+    }
+
+    /// `current` resolve catalog id → (block id canônico, versão atual)
+    fn migrate_with(
+        &self,
+        doc: &mut PageDocument,
+        current: impl Fn(&str) -> Option<(&'static str, u32)> + Copy,
+    ) -> Result<MigrationReport, MigrationError> {
+        let mut root = doc.root.clone();
+        let mut report = MigrationReport::default();
+        self.migrate_node(&mut root, "/root", current, &mut report)?;
+        doc.root = root;
+        Ok(report)
+    }
+
+    fn migrate_node(
+        &self,
+        node: &mut PageNode,
+        path: &str,
+        current: impl Fn(&str) -> Option<(&'static str, u32)> + Copy,
+        report: &mut MigrationReport,
+    ) -> Result<(), MigrationError> {
+        if let Some((block_id, target)) = current(&node.catalog_id) {
+            let mut version = node.version.unwrap_or(1);
+            if version > target {
+                return Err(MigrationError::FromFuture { path: path.into(), block_id: block_id.into(), version, current: target });
+            }
+            while version < target {
+                let Some(step) = self.get(block_id, version) else {
+                    return Err(MigrationError::MissingStep { path: path.into(), block_id: block_id.into(), from: version, to: target });
+                };
+                (step.migrate)(node).map_err(|reason| MigrationError::Failed {
+                    path: path.into(),
+                    block_id: block_id.into(),
+                    from: version,
+                    reason,
+                })?;
+                report.applied.push(AppliedMigration {
+                    path: path.into(),
+                    node_id: node.id,
+                    block_id: block_id.into(),
+                    from: version,
+                    to: version + 1,
+                    description: step.description,
+                });
+                version += 1;
+            }
+            node.version = Some(target);
+        }
+
+        // Migração pode ter renomeado regions — caminha depois
+        for (region, children) in node.regions.iter_mut() {
+            for (index, child) in children.iter_mut().enumerate() {
+                let child_path = format!("{}/regions/{}/{}", path, pointer_segment(region), index);
+                self.migrate_node(child, &child_path, current, report)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn current_version(catalog_id: &str) -> Option<(&'static str, u32)> {
+    BlockDefinition::for_catalog_id(catalog_id).map(|d| (d.id, d.version))
+}
+
+// ── Helpers para passos comuns ───────────────────────────────────────────────
+
+/// Renomeia uma prop — no-op quando ausente
+pub fn rename_prop(node: &mut PageNode, from: &str, to: &str) {
+    if let Some(value) = node.props.remove(from) {
+        node.props.insert(to.to_string(), value);
+    }
+}
+
+/// Remapeia valores de uma prop Select — valores fora do mapa são erro
+pub fn map_prop_values(node: &mut PageNode, key: &str, map: &[(&str, &str)]) -> Result<(), String> {
+    let Some(value) = node.props.get_mut(key) else { return Ok(()) };
+    let Some(old) = value.as_str() else {
+        return Err(format!("prop '{}' is not a string", key));
+    };
+    if map.iter().any(|(_, new)| *new == old) {
+        return Ok(());
+    }
+    match map.iter().find(|(prev, _)| *prev == old) {
+        Some((_, new)) => { *value = Value::String(new.to_string()); Ok(()) }
+        None           => Err(format!("prop '{}' has unmappable value '{}'", key, old)),
+    }
+}
+
+/// Move os filhos de uma region para outra (append) — usado em splits/renames de region
+pub fn move_region(node: &mut PageNode, from: &str, to: &str) {
+    if let Some(children) = node.regions.remove(from) {
+        node.regions.entry(to.to_string()).or_default().extend(children);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current(id: &str) -> Option<(&'static str, u32)> {
+        match id {
+            "block.hero" => Some(("hero", 3)),
+            "block.card" => Some(("card", 1)),
+            _            => None,
+        }
+    }
+
+    fn v1_to_v2(node: &mut PageNode) -> Result<(), String> {
+        rename_prop(node, "title", "heading");
+        Ok(())
+    }
+
+    fn v2_to_v3(node: &mut PageNode) -> Result<(), String> {
+        move_region(node, "body", "content");
+        map_prop_values(node, "align", &[("middle", "center")])
+    }
+
+    fn registry() -> MigrationRegistry {
+        MigrationRegistry::new()
+            .with(Migration { block_id: "hero", from: 1, description: "title → heading", migrate: v1_to_v2 })
+            .with(Migration { block_id: "hero", from: 2, description: "body → content", migrate: v2_to_v3 })
+    }
+
+    #[test]
+    fn test_upgrades_step_by_step() {
+        let mut doc = PageDocument::new(
+            PageNode::new(1, "form").with_child(
+                "children",
+                PageNode::new(2, "block.hero")
+                    .with_prop("title", "Hi")
+                    .with_prop("align", "middle")
+                    .with_child("body", PageNode::new(3, "button")),
+            ),
+        );
+        let report = registry().migrate_with(&mut doc, current).unwrap();
+        assert_eq!(report.applied.len(), 2);
+        assert_eq!(report.applied[1].path, "/root/regions/children/0");
+        let hero = doc.find(2).unwrap();
+        assert_eq!(hero.version, Some(3));
+        assert_eq!(hero.props["heading"], "Hi");
+        assert_eq!(hero.props["align"], "center");
+        assert_eq!(hero.regions["content"][0].id, 3);
+    }
+
+    #[test]
+    fn test_new_nodes_are_stamped_and_not_migrated() {
+        // Node vindo de um cliente, sem version — inserido depois do bump para v3
+        let mut fresh: PageNode = serde_json::from_str(r#"{"id": 2, "catalog_id": "block.hero", "props": {"heading": "Hi"}}"#).unwrap();
+        fresh.stamp_with(|id| current(id).map(|(_, v)| v));
+        assert_eq!(fresh.version, Some(3));
+
+        let legacy: PageNode = serde_json::from_str(r#"{"id": 3, "catalog_id": "block.card"}"#).unwrap();
+        let mut doc = PageDocument::new(PageNode::new(1, "form").with_child("children", fresh).with_child("children", legacy));
+        let report = registry().migrate_with(&mut doc, current).unwrap();
+        assert!(report.is_empty());
+        assert_eq!(doc.find(2).unwrap().props["heading"], "Hi");
+        // Sem passo para rodar, mas a versão fica gravada
+        assert_eq!(doc.find(3).unwrap().version, Some(1));
+
+        // PageNode::new e PageOp::Insert carimbam a versão do schema real
+        let def = crate::generated::block_definitions::BLOCK_DEFINITIONS_GENERATED.first().unwrap();
+        let catalog_id = format!("block.{}", def.id);
+        assert_eq!(PageNode::new(9, catalog_id.as_str()).version, Some(def.version));
+        let sent: PageNode = serde_json::from_value(serde_json::json!({ "id": 10, "catalog_id": catalog_id })).unwrap();
+        crate::page_ops::PageOp::Insert { parent: 1, region: "children".into(), index: 0, node: sent }
+            .apply(&mut doc)
+            .unwrap();
+        assert_eq!(doc.find(10).unwrap().version, Some(def.version));
+    }
+
+    #[test]
+    fn test_failure_leaves_document_untouched() {
+        let mut doc = PageDocument::new(PageNode::new(1, "block.hero").with_prop("align", "diagonal"));
+        let original = doc.clone();
+        let err = registry().migrate_with(&mut doc, current).unwrap_err();
+        assert!(matches!(err, MigrationError::Failed { from: 2, .. }));
+        assert_eq!(doc, original);
+
+        let mut future = PageDocument::new(PageNode::new(1, "block.hero").with_version(4));
+        assert!(matches!(registry().migrate_with(&mut future, current), Err(MigrationError::FromFuture { .. })));
+        let mut gap = PageDocument::new(PageNode::new(1, "block.hero"));
+        assert!(matches!(MigrationRegistry::new().migrate_with(&mut gap, current), Err(MigrationError::MissingStep { from: 1, .. })));
+    }
+}
@@ -0,0 +1,380 @@
+//! Block Props — validates and coerces concrete values against BlockPropDef
+//! Input: JSON map (PageNode props) or string map (query string, data-attrs)
+//! Output: typed values with defaults filled, split by PropScope
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde_json::Value;
+
+use crate::block_types::{BlockDefinition, BlockPreset, BlockPropDef, PropFieldType, PropScope};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropColor {
+    /// `#rgb`, `#rrggbb`, `#rrggbbaa` — normalizado em minúsculas
+    Hex(String),
+    /// `hsl(210 40% 50%)`, `hsl(210, 40%, 50%)` ou o formato de token `210 40% 50%`
+    Hsl { h: f32, s: f32, l: f32, alpha: Option<f32> },
+    /// Referência a token: `var(--primary)` ou `--primary` — guarda o nome sem `--`
+    Token(String),
+}
+
+impl PropColor {
+    pub fn parse(raw: &str) -> Option<Self> {
+        let s = raw.trim();
+        if let Some(hex) = s.strip_prefix('#') {
+            let valid = matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+            return valid.then(|| PropColor::Hex(format!("#{}", hex.to_ascii_lowercase())));
+        }
+        if let Some(name) = s.strip_prefix("var(").and_then(|v| v.strip_suffix(')')) {
+            return parse_token(name.trim());
+        }
+        if s.starts_with("--") {
+            return parse_token(s);
+        }
+        let body = s
+            .strip_prefix("hsla(")
+            .or_else(|| s.strip_prefix("hsl("))
+            .and_then(|v| v.strip_suffix(')'))
+            .unwrap_or(s);
+        parse_hsl(body)
+    }
+
+    pub fn to_css(&self) -> String {
+        match self {
+            PropColor::Hex(hex) => hex.clone(),
+            PropColor::Hsl { h, s, l, alpha: None } => format!("hsl({} {}% {}%)", h, s, l),
+            PropColor::Hsl { h, s, l, alpha: Some(a) } => format!("hsl({} {}% {}% / {})", h, s, l, a),
+            PropColor::Token(name) => format!("var(--{})", name),
+        }
+    }
+}
+
+fn parse_token(name: &str) -> Option<PropColor> {
+    let name = name.strip_prefix("--")?;
+    let valid = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    valid.then(|| PropColor::Token(name.to_string()))
+}
+
+fn parse_hsl(body: &str) -> Option<PropColor> {
+    let (channels, alpha) = match body.split_once('/') {
+        Some((c, a)) => (c, Some(a.trim())),
+        None         => (body, None),
+    };
+    let mut parts: Vec<&str> = channels.split([',', ' ']).map(str::trim).filter(|p| !p.is_empty()).collect();
+    // Sintaxe legada: hsla(h, s, l, a)
+    let alpha = match (alpha, parts.len()) {
+        (None, 4) => parts.pop(),
+        (a, _)    => a,
+    };
+    let [h, s, l] = parts.as_slice() else { return None };
+    let h: f32 = h.trim_end_matches("deg").parse().ok()?;
+    let s: f32 = s.strip_suffix('%')?.parse().ok()?;
+    let l: f32 = l.strip_suffix('%')?.parse().ok()?;
+    if !(0.0..=100.0).contains(&s) || !(0.0..=100.0).contains(&l) {
+        return None;
+    }
+    let alpha = match alpha {
+        None => None,
+        Some(a) => {
+            let value = match a.strip_suffix('%') {
+                Some(pct) => pct.parse::<f32>().ok()? / 100.0,
+                None      => a.parse().ok()?,
+            };
+            if !(0.0..=1.0).contains(&value) { return None; }
+            Some(value)
+        }
+    };
+    Some(PropColor::Hsl { h, s, l, alpha })
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockPropValue {
+    Text(String),
+    Number(f64),
+    /// Valor (não label) da opção escolhida
+    Select(&'static str),
+    Toggle(bool),
+    Color(PropColor),
+}
+
+impl BlockPropValue {
+    /// Valor pronto para CSS / data-attrs
+    pub fn to_css(&self) -> String {
+        match self {
+            BlockPropValue::Text(s)   => s.clone(),
+            BlockPropValue::Number(n) => n.to_string(),
+            BlockPropValue::Select(v) => v.to_string(),
+            BlockPropValue::Toggle(b) => b.to_string(),
+            BlockPropValue::Color(c)  => c.to_css(),
+        }
+    }
+
+    /// Forma guardada em `PageNode::props` — Number e Toggle tipados, o resto texto
+    pub fn to_json(&self) -> Value {
+        match self {
+            BlockPropValue::Number(n) => serde_json::Number::from_f64(*n).map_or(Value::Null, Value::Number),
+            BlockPropValue::Toggle(b) => Value::Bool(*b),
+            other                     => Value::String(other.to_css()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockPropError {
+    /// Key não declarada no schema
+    Unknown { key: String },
+    /// Valor fora das opções do Select
+    InvalidOption { key: &'static str, value: String, options: Vec<&'static str> },
+    InvalidNumber { key: &'static str, value: String },
+    InvalidColor  { key: &'static str, value: String },
+    InvalidToggle { key: &'static str, value: String },
+    /// Tipo JSON incompatível (array, object, null)
+    InvalidType   { key: &'static str, got: &'static str },
+}
+
+impl fmt::Display for BlockPropError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown { key } => write!(f, "unknown prop '{}'", key),
+            Self::InvalidOption { key, value, options } =>
+                write!(f, "prop '{}' expects one of {}, got '{}'", key, options.join("|"), value),
+            Self::InvalidNumber { key, value } => write!(f, "prop '{}' expects a number, got '{}'", key, value),
+            Self::InvalidColor { key, value } =>
+                write!(f, "prop '{}' expects a color (hex, hsl or token), got '{}'", key, value),
+            Self::InvalidToggle { key, value } => write!(f, "prop '{}' expects true|false, got '{}'", key, value),
+            Self::InvalidType { key, got } => write!(f, "prop '{}' cannot be {}", key, got),
+        }
+    }
+}
+
+/// Props coeridas e separadas por PropScope
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScopedProps {
+    pub structural: BTreeMap<&'static str, BlockPropValue>,
+    pub visual:     BTreeMap<&'static str, BlockPropValue>,
+    pub typography: BTreeMap<&'static str, BlockPropValue>,
+    pub capability: BTreeMap<&'static str, BlockPropValue>,
+}
+
+impl ScopedProps {
+    pub fn scope(&self, scope: PropScope) -> &BTreeMap<&'static str, BlockPropValue> {
+        match scope {
+            PropScope::Structural => &self.structural,
+            PropScope::Visual     => &self.visual,
+            PropScope::Typography => &self.typography,
+            PropScope::Capability => &self.capability,
+        }
+    }
+
+    fn scope_mut(&mut self, scope: PropScope) -> &mut BTreeMap<&'static str, BlockPropValue> {
+        match scope {
+            PropScope::Structural => &mut self.structural,
+            PropScope::Visual     => &mut self.visual,
+            PropScope::Typography => &mut self.typography,
+            PropScope::Capability => &mut self.capability,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&BlockPropValue> {
+        [&self.structural, &self.visual, &self.typography, &self.capability]
+            .into_iter()
+            .find_map(|m| m.get(key))
+    }
+
+    pub fn len(&self) -> usize {
+        self.structural.len() + self.visual.len() + self.typography.len() + self.capability.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn json_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null      => "null",
+        Value::Array(_)  => "an array",
+        Value::Object(_) => "an object",
+        _                => "a scalar",
+    }
+}
+
+/// Coage um valor de texto — forma comum a string maps e JSON escalar
+pub fn coerce_value(def: &BlockPropDef, raw: &str) -> Result<BlockPropValue, BlockPropError> {
+    let value = raw.to_string();
+    match def.field {
+        PropFieldType::Text => Ok(BlockPropValue::Text(value)),
+        PropFieldType::Number => match raw.trim().parse::<f64>() {
+            Ok(n) if n.is_finite() => Ok(BlockPropValue::Number(n)),
+            _ => Err(BlockPropError::InvalidNumber { key: def.key, value }),
+        },
+        PropFieldType::Select(options) => options
+            .iter()
+            .find(|(v, _)| *v == raw)
+            .map(|(v, _)| BlockPropValue::Select(v))
+            .ok_or_else(|| BlockPropError::InvalidOption {
+                key: def.key,
+                value,
+                options: options.iter().map(|(v, _)| *v).collect(),
+            }),
+        PropFieldType::Toggle => match raw.trim() {
+            "true"  => Ok(BlockPropValue::Toggle(true)),
+            "false" => Ok(BlockPropValue::Toggle(false)),
+            _       => Err(BlockPropError::InvalidToggle { key: def.key, value }),
+        },
+        PropFieldType::Color => PropColor::parse(raw)
+            .map(BlockPropValue::Color)
+            .ok_or(BlockPropError::InvalidColor { key: def.key, value }),
+    }
+}
+
+fn coerce_json(def: &BlockPropDef, value: &Value) -> Result<BlockPropValue, BlockPropError> {
+    match value {
+        Value::String(s) => coerce_value(def, s),
+        Value::Number(n) => coerce_value(def, &n.to_string()),
+        Value::Bool(b)   => coerce_value(def, if *b { "true" } else { "false" }),
+        other            => Err(BlockPropError::InvalidType { key: def.key, got: json_kind(other) }),
+    }
+}
+
+/// Keys podem vir em snake_case (`flex_direction`) — o schema usa kebab-case
+fn find_def<'a>(schema: &'a [BlockPropDef], key: &str) -> Option<&'a BlockPropDef> {
+    let kebab = key.replace('_', "-");
+    schema.iter().find(|d| d.key == key || d.key == kebab)
+}
+
+fn coerce_with<'a, V: 'a>(
+    schema: &[BlockPropDef],
+    raw: impl IntoIterator<Item = (&'a str, V)>,
+    coerce: impl Fn(&BlockPropDef, V) -> Result<BlockPropValue, BlockPropError>,
+) -> Result<ScopedProps, Vec<BlockPropError>> {
+    let mut out = ScopedProps::default();
+    let mut errors = vec![];
+
+    for (key, value) in raw {
+        match find_def(schema, key) {
+            None      => errors.push(BlockPropError::Unknown { key: key.to_string() }),
+            Some(def) => match coerce(def, value) {
+                Ok(v)  => { out.scope_mut(def.scope).insert(def.key, v); }
+                Err(e) => errors.push(e),
+            },
+        }
+    }
+
+    for def in schema {
+        if out.scope(def.scope).contains_key(def.key) { continue; }
+        if let Some(default) = def.default {
+            match coerce_value(def, default) {
+                Ok(v)  => { out.scope_mut(def.scope).insert(def.key, v); }
+                Err(e) => errors.push(e),
+            }
+        }
+    }
+
+    if errors.is_empty() { Ok(out) } else { Err(errors) }
+}
+
+/// Valida um map JSON (ex: `PageNode::props`) — coleta todos os erros
+pub fn coerce_props(schema: &[BlockPropDef], raw: &BTreeMap<String, Value>) -> Result<ScopedProps, Vec<BlockPropError>> {
+    coerce_with(schema, raw.iter().map(|(k, v)| (k.as_str(), v)), coerce_json)
+}
+
+/// Valida pares string → string (query string, data-attrs, CLI)
+pub fn coerce_string_props<'a>(
+    schema: &[BlockPropDef],
+    raw: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Result<ScopedProps, Vec<BlockPropError>> {
+    coerce_with(schema, raw, coerce_value)
+}
+
+/// Props de um preset prontas para `PageNode::props` — keys do schema, valores tipados
+/// Mesmo formato que o inspector grava; não preenche defaults (o preset só sobrescreve o que declara)
+pub fn coerce_preset(schema: &[BlockPropDef], preset: &BlockPreset) -> Result<BTreeMap<String, Value>, Vec<BlockPropError>> {
+    let mut out = BTreeMap::new();
+    let mut errors = vec![];
+    for (key, raw) in preset.props {
+        match find_def(schema, key).map(|def| (def, coerce_value(def, raw))) {
+            None              => errors.push(BlockPropError::Unknown { key: key.to_string() }),
+            Some((def, Ok(v))) => { out.insert(def.key.to_string(), v.to_json()); }
+            Some((_, Err(e)))  => errors.push(e),
+        }
+    }
+    if errors.is_empty() { Ok(out) } else { Err(errors) }
+}
+
+impl BlockDefinition {
+    /// Coage contra `final_props()` — schema declarado + props derivadas das capabilities
+    pub fn coerce_props(&self, raw: &BTreeMap<String, Value>) -> Result<ScopedProps, Vec<BlockPropError>> {
+        coerce_props(&self.final_props(), raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &[BlockPropDef] = &[
+        BlockPropDef { key: "flex-direction", label: "Direction", field: PropFieldType::Select(&[("column", "Vertical"), ("row", "Horizontal")]), default: Some("column"), scope: PropScope::Visual, css: Some("flex-direction") },
+        BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Number, default: None, scope: PropScope::Visual, css: Some("gap") },
+        BlockPropDef { key: "background", label: "Background", field: PropFieldType::Color, default: None, scope: PropScope::Visual, css: None },
+        BlockPropDef { key: "sticky", label: "Sticky", field: PropFieldType::Toggle, default: Some("false"), scope: PropScope::Structural, css: None },
+    ];
+
+    #[test]
+    fn test_coerces_json_and_fills_defaults() {
+        let raw: BTreeMap<String, Value> = serde_json::from_str(r##"{"gap": 12, "background": "#FFF", "sticky": true}"##).unwrap();
+        let props = coerce_props(SCHEMA, &raw).unwrap();
+        assert_eq!(props.visual["flex-direction"], BlockPropValue::Select("column"));
+        assert_eq!(props.visual["gap"], BlockPropValue::Number(12.0));
+        assert_eq!(props.visual["background"], BlockPropValue::Color(PropColor::Hex("#fff".into())));
+        assert_eq!(props.structural["sticky"], BlockPropValue::Toggle(true));
+    }
+
+    #[test]
+    fn test_string_map_collects_errors() {
+        let errors = coerce_string_props(SCHEMA, [("flex_direction", "diagonal"), ("gap", "wide"), ("nope", "1")]).unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors.contains(&BlockPropError::Unknown { key: "nope".into() }));
+        assert!(matches!(errors[0], BlockPropError::InvalidOption { key: "flex-direction", .. }));
+    }
+
+    #[test]
+    fn test_coerce_preset_writes_typed_values() {
+        let preset = BlockPreset { label: "Sticky row", props: &[("flex_direction", "row"), ("gap", "12"), ("sticky", "true"), ("background", "#FFF")] };
+        let props = coerce_preset(SCHEMA, &preset).unwrap();
+        assert_eq!(props["flex-direction"], Value::String("row".into()));
+        assert_eq!(props["gap"], serde_json::json!(12.0));
+        assert_eq!(props["sticky"], Value::Bool(true));
+        assert_eq!(props["background"], Value::String("#fff".into()));
+        // Preset não herda defaults
+        assert_eq!(props.len(), 4);
+
+        let bad = BlockPreset { label: "Bad", props: &[("gap", "wide"), ("nope", "1")] };
+        assert_eq!(coerce_preset(SCHEMA, &bad).unwrap_err().len(), 2);
+    }
+
+    #[test]
+    fn test_real_definitions_coerce_defaults_and_presets() {
+        use crate::generated::block_definitions::{BLOCK_DEFINITIONS_GENERATED, LAYOUT_DEFINITIONS_GENERATED};
+        for def in BLOCK_DEFINITIONS_GENERATED.iter().chain(LAYOUT_DEFINITIONS_GENERATED) {
+            if let Err(errors) = def.coerce_props(&BTreeMap::new()) {
+                panic!("{} defaults: {:?}", def.id, errors);
+            }
+            for preset in def.presets {
+                if let Err(errors) = coerce_preset(&def.final_props(), preset) {
+                    panic!("{} preset '{}': {:?}", def.id, preset.label, errors);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_formats() {
+        assert_eq!(PropColor::parse("hsl(210, 40%, 50%)"), Some(PropColor::Hsl { h: 210.0, s: 40.0, l: 50.0, alpha: None }));
+        assert_eq!(PropColor::parse("210 40% 50% / 0.5").map(|c| c.to_css()), Some("hsl(210 40% 50% / 0.5)".into()));
+        assert_eq!(PropColor::parse("var(--primary)"), Some(PropColor::Token("primary".into())));
+        assert_eq!(PropColor::parse("--color-muted").map(|c| c.to_css()), Some("var(--color-muted)".into()));
+        assert_eq!(PropColor::parse("#12345"), None);
+        assert_eq!(PropColor::parse("red"), None);
+    }
+}
@@ -127,11 +127,30 @@ impl PartialEq for BlockDefinition {
 }
 
 impl BlockDefinition {
+    /// Aceita também ids do catalog (`block.card`, `layout.dashboard-layout`)
     pub fn find(id: &str) -> Option<&'static BlockDefinition> {
         crate::generated::block_definitions::BLOCK_DEFINITIONS_GENERATED.iter().find(|d| d.id == id)
             .or_else(|| crate::generated::block_definitions::LAYOUT_DEFINITIONS_GENERATED.iter().find(|d| d.id == id))
     }
 
+    /// Definição pelo id do catalog — `block.<id>` ou `layout.<id>`; components não têm
+    pub fn for_catalog_id(catalog_id: &str) -> Option<&'static BlockDefinition> {
+        use crate::generated::block_definitions::{BLOCK_DEFINITIONS_GENERATED, LAYOUT_DEFINITIONS_GENERATED};
+        if let Some(id) = catalog_id.strip_prefix("block.") {
+            BLOCK_DEFINITIONS_GENERATED.iter().find(|d| d.id == id)
+        } else if let Some(id) = catalog_id.strip_prefix("layout.") {
+            LAYOUT_DEFINITIONS_GENERATED.iter().find(|d| d.id == id)
+        } else {
+            None
+        }
+    }
+
+    /// Inverso de `for_catalog_id` — `block.<id>` ou `layout.<id>`
+    pub fn catalog_id(&self) -> String {
+        let is_block = crate::generated::block_definitions::BLOCK_DEFINITIONS_GENERATED.iter().any(|d| d.id == self.id);
+        format!("{}.{}", if is_block { "block" } else { "layout" }, self.id)
+    }
+
     pub fn by_variant(v: BlockVariant) -> impl Iterator<Item = &'static BlockDefinition> {
         crate::generated::block_definitions::BLOCK_DEFINITIONS_GENERATED.iter().filter(move |d| d.variant == v)
     }
@@ -0,0 +1,257 @@
+//! CatalogQuery — filtered, ranked search over the generated catalog
+//! Shared by the builder "insert" palette and docs search
+//! Filters are AND-ed; within one filter the values are OR-ed (capabilities/tags: all required)
+//! Text: every query term must hit some field — fuzzy (1 typo) only on label/id/tags
+
+use crate::block_types::BlockDefinition;
+use crate::catalog_types::{CatalogCategory, CatalogEntry, CatalogKind};
+use crate::infra::constraint_engine::ConstraintEngine;
+use crate::meta_types::{Capability, ComponentFamily, ComponentMeta};
+
+/// Resultado ranqueado — `meta` ausente para entries sem ComponentMeta
+#[derive(Clone, Debug)]
+pub struct CatalogHit {
+    pub entry: &'static CatalogEntry,
+    pub meta:  Option<&'static ComponentMeta>,
+    pub score: u32,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CatalogQuery {
+    text:         Vec<String>,
+    kinds:        Vec<CatalogKind>,
+    categories:   Vec<CatalogCategory>,
+    families:     Vec<ComponentFamily>,
+    capabilities: Vec<Capability>,
+    tags:         Vec<String>,
+    related_to:   Option<String>,
+    limit:        Option<usize>,
+}
+
+/// Pesos por campo — match exato no id/label sempre vence
+const EXACT:       u32 = 100;
+const PREFIX:      u32 = 60;
+const TAG:         u32 = 40;
+const LABEL:       u32 = 30;
+const KEYWORD:     u32 = 20;
+const INTENT:      u32 = 15;
+const DESCRIPTION: u32 = 10;
+const FUZZY:       u32 = 8;
+
+impl CatalogQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(mut self, query: &str) -> Self {
+        self.text = query.split_whitespace().map(str::to_lowercase).collect();
+        self
+    }
+
+    pub fn kind(mut self, kind: CatalogKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    pub fn category(mut self, category: CatalogCategory) -> Self {
+        self.categories.push(category);
+        self
+    }
+
+    pub fn family(mut self, family: ComponentFamily) -> Self {
+        self.families.push(family);
+        self
+    }
+
+    pub fn capability(mut self, capability: Capability) -> Self {
+        self.capabilities.push(capability);
+        self
+    }
+
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_lowercase());
+        self
+    }
+
+    /// Entries relacionadas a `id` — em qualquer direção do `related`
+    pub fn related_to(mut self, id: &str) -> Self {
+        self.related_to = Some(id.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn run(&self) -> Vec<CatalogHit> {
+        self.run_over(crate::generated::catalog::CATALOG_GENERATED)
+    }
+
+    pub fn run_over(&self, entries: &'static [CatalogEntry]) -> Vec<CatalogHit> {
+        let mut hits: Vec<CatalogHit> = entries
+            .iter()
+            .filter_map(|entry| {
+                let meta = resolve_meta(entry);
+                if !self.passes_filters(entry, meta) { return None; }
+                let score = self.text_score(entry, meta)?;
+                Some(CatalogHit { entry, meta, score })
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.entry.label.cmp(b.entry.label)));
+        if let Some(limit) = self.limit {
+            hits.truncate(limit);
+        }
+        hits
+    }
+
+    fn passes_filters(&self, entry: &CatalogEntry, meta: Option<&ComponentMeta>) -> bool {
+        if !self.kinds.is_empty() && !self.kinds.contains(&entry.kind) { return false; }
+        if !self.categories.is_empty() && !self.categories.contains(&entry.category) { return false; }
+        if !self.families.is_empty() && !meta.is_some_and(|m| self.families.contains(&m.family)) { return false; }
+        if !self.capabilities.is_empty() {
+            let caps = meta.map_or(&[][..], |m| m.capabilities);
+            if !self.capabilities.iter().all(|c| caps.contains(c)) { return false; }
+        }
+        if !self.tags.iter().all(|t| entry.tags.iter().any(|e| e.eq_ignore_ascii_case(t))) { return false; }
+        if let Some(other) = &self.related_to {
+            let other_entry = CatalogEntry::find(other);
+            let forward = entry.related.contains(&other.as_str());
+            let backward = other_entry.is_some_and(|o| o.related.contains(&entry.id));
+            if entry.id == other || !(forward || backward) { return false; }
+        }
+        true
+    }
+
+    /// None = algum termo não casou com nenhum campo
+    fn text_score(&self, entry: &CatalogEntry, meta: Option<&ComponentMeta>) -> Option<u32> {
+        let id = entry.id.to_lowercase();
+        let label = entry.label.to_lowercase();
+        let description = entry.description.to_lowercase();
+        let intent = meta.map(|m| m.intent.to_lowercase()).unwrap_or_default();
+        let keywords: Vec<String> = entry.keywords.iter().map(|k| k.to_lowercase()).collect();
+        let words: Vec<&str> = label
+            .split(|c: char| !c.is_alphanumeric())
+            .chain(id.split(|c: char| !c.is_alphanumeric()))
+            .chain(entry.tags.iter().copied())
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let whole = self.text.join(" ");
+        let mut total = 0;
+        if !whole.is_empty() && (label == whole || id.trim_start_matches("block.").trim_start_matches("layout.") == whole.replace(' ', "-")) {
+            total += EXACT;
+        }
+
+        for term in &self.text {
+            let term = term.as_str();
+            let score = if label.starts_with(term) || id.starts_with(term) {
+                PREFIX
+            } else if entry.tags.iter().any(|t| t.eq_ignore_ascii_case(term)) {
+                TAG
+            } else if label.contains(term) {
+                LABEL
+            } else if keywords.iter().any(|k| k.contains(term)) {
+                KEYWORD
+            } else if intent.contains(term) {
+                INTENT
+            } else if description.contains(term) {
+                DESCRIPTION
+            } else if term.len() >= 4 && words.iter().any(|w| within_one_edit(w, term)) {
+                FUZZY
+            } else {
+                return None;
+            };
+            total += score;
+        }
+        Some(total)
+    }
+}
+
+fn resolve_meta(entry: &CatalogEntry) -> Option<&'static ComponentMeta> {
+    match entry.kind {
+        CatalogKind::Component => ConstraintEngine::resolve_meta(entry.id),
+        CatalogKind::Block | CatalogKind::Layout => BlockDefinition::for_catalog_id(entry.id).map(|d| d.meta),
+    }
+}
+
+/// Distância de edição ≤ 1 (inserção, remoção ou troca)
+fn within_one_edit(a: &str, b: &str) -> bool {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let (short, long) = if a.len() <= b.len() { (&a, &b) } else { (&b, &a) };
+    if long.len() - short.len() > 1 { return false; }
+    let prefix = short.iter().zip(long.iter()).take_while(|(x, y)| x == y).count();
+    if short.len() == long.len() {
+        short[prefix..].iter().skip(1).eq(long[prefix..].iter().skip(1))
+    } else {
+        short[prefix..] == long[prefix + 1..]
+    }
+}
+
+impl CatalogEntry {
+    /// Atalho para buscas simples — `CatalogQuery::new().text(q).run()`
+    pub fn search(query: &str) -> Vec<CatalogHit> {
+        CatalogQuery::new().text(query).run()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_edit_distance() {
+        assert!(within_one_edit("button", "buton"));
+        assert!(!within_one_edit("dialog", "dialgo"));
+        assert!(within_one_edit("select", "selekt"));
+        assert!(!within_one_edit("table", "cable-x"));
+    }
+
+    #[test]
+    fn test_exact_label_ranks_first() {
+        let hits = CatalogQuery::new().text("button").kind(CatalogKind::Component).run();
+        assert_eq!(hits[0].entry.id, "button");
+        assert!(hits.iter().all(|h| h.entry.kind == CatalogKind::Component));
+    }
+
+    #[test]
+    fn test_typo_and_filters() {
+        assert!(CatalogQuery::new().text("buton").run().iter().any(|h| h.entry.id == "button"));
+        let disabled = CatalogQuery::new().capability(Capability::Disabled).run();
+        assert!(disabled.iter().all(|h| h.meta.is_some_and(|m| m.capabilities.contains(&Capability::Disabled))));
+        assert!(CatalogQuery::new().text("zzzz-nothing").run().is_empty());
+    }
+
+    #[test]
+    fn test_blocks_and_layouts_resolve_meta() {
+        let hits = CatalogQuery::new().kind(CatalogKind::Block).run();
+        assert!(!hits.is_empty());
+        assert!(hits.iter().all(|h| h.meta.is_some()), "block entries must resolve BlockDefinition meta");
+        assert!(CatalogQuery::new().kind(CatalogKind::Layout).run().iter().all(|h| h.meta.is_some()));
+    }
+
+    #[test]
+    fn test_block_keywords_and_related() {
+        let hits = CatalogQuery::new().text("kpi").run();
+        assert!(hits.iter().any(|h| h.entry.id == "block.stat-group"));
+        let related = CatalogQuery::new().related_to("block.hero").run();
+        assert!(related.iter().any(|h| h.entry.id == "block.section"));
+        for kind in [CatalogKind::Block, CatalogKind::Layout] {
+            for hit in CatalogQuery::new().kind(kind).run() {
+                assert!(!hit.entry.keywords.is_empty(), "'{}' has no keywords", hit.entry.id);
+                for id in hit.entry.related {
+                    assert!(CatalogEntry::find(id).is_some(), "'{}' relates to unknown '{}'", hit.entry.id, id);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_family_filter_uses_builder_taxonomy() {
+        // builder.yaml `family-c-forms` → Input, `family-a-overlay` → Overlay
+        let inputs = CatalogQuery::new().family(ComponentFamily::Input).run();
+        assert!(inputs.iter().any(|h| h.entry.id == "input"));
+        assert!(inputs.iter().all(|h| h.meta.is_some_and(|m| m.family == ComponentFamily::Input)));
+        assert!(CatalogQuery::new().family(ComponentFamily::Overlay).run().iter().any(|h| h.entry.id == "dialog"));
+    }
+}
@@ -92,9 +92,14 @@ pub struct CatalogEntry {
     pub category:    CatalogCategory,
     pub tags:        &'static [&'static str],
     pub parts:       &'static [&'static str],
+    /// Chaves válidas em `PageNode::regions` — slots Children (components) ou regions (blocks/layouts)
     pub regions:     &'static [&'static str],
     pub accepts:     &'static [CatalogAcceptRule],
     pub region_rules: &'static [CatalogRegionRule],
+    /// Frases de busca do builder.yaml — usadas pelo CatalogQuery
+    pub keywords:    &'static [&'static str],
+    /// Ids do catalog relacionados (builder.yaml `related`)
+    pub related:     &'static [&'static str],
 }
 
 impl CatalogEntry {
@@ -109,6 +114,10 @@ impl CatalogEntry {
         crate::generated::catalog::CATALOG_GENERATED.iter().find(|e| e.id == id)
     }
 
+    pub fn has_region(&self, region: &str) -> bool {
+        self.regions.contains(&region)
+    }
+
     pub fn match_by_tags(tags: &[&str]) -> Vec<(&'static CatalogEntry, usize)> {
         let mut results: Vec<(&'static CatalogEntry, usize)> = crate::generated::catalog::CATALOG_GENERATED
             .iter()
@@ -5,20 +5,20 @@ use crate::meta::ComponentMeta;
 
 const CONTAINER_PROPS: &[BlockPropDef] = &[
 	BlockPropDef { key: "max-width", label: "Max Width", field: PropFieldType::Text, default: Some("1200px"), scope: PropScope::Visual, css: Some("max-width") },
-	BlockPropDef { key: "padding", label: "Padding", field: PropFieldType::Number, default: None, scope: PropScope::Visual, css: Some("padding") },
+	BlockPropDef { key: "padding", label: "Padding", field: PropFieldType::Text, default: None, scope: PropScope::Visual, css: Some("padding") },
 ];
 
 const STACK_PROPS: &[BlockPropDef] = &[
 	BlockPropDef { key: "flex-direction", label: "Flex Direction", field: PropFieldType::Select(&[("column", "Vertical"), ("row", "Horizontal")]), default: Some("column"), scope: PropScope::Visual, css: Some("flex-direction") },
-	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Number, default: Some("0.5rem"), scope: PropScope::Visual, css: Some("gap") },
+	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Text, default: Some("0.5rem"), scope: PropScope::Visual, css: Some("gap") },
 	BlockPropDef { key: "align-items", label: "Align Items", field: PropFieldType::Select(&[("stretch", "Stretch"), ("flex-start", "Start"), ("center", "Center"), ("flex-end", "End")]), default: Some("stretch"), scope: PropScope::Visual, css: Some("align-items") },
 ];
 
 const GRID_PROPS: &[BlockPropDef] = &[
 	BlockPropDef { key: "grid-columns", label: "Grid Columns", field: PropFieldType::Number, default: Some("3"), scope: PropScope::Structural, css: None },
-	BlockPropDef { key: "grid-template-columns", label: "Grid Template Columns", field: PropFieldType::Select(&[("repeat(1,1fr)", "1"), ("repeat(2,1fr)", "2"), ("repeat(3,1fr)", "3"), ("repeat(4,1fr)", "4"), ("repeat(5,1fr)", "5"), ("repeat(6,1fr)", "6")]), default: Some("repeat(3,1fr)"), scope: PropScope::Visual, css: Some("grid-template-columns") },
-	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Number, default: Some("1rem"), scope: PropScope::Visual, css: Some("gap") },
-	BlockPropDef { key: "row-gap", label: "Row Gap", field: PropFieldType::Number, default: None, scope: PropScope::Visual, css: Some("row-gap") },
+	BlockPropDef { key: "grid-template-columns", label: "Grid Template Columns", field: PropFieldType::Select(&[("repeat(1,1fr)", "1"), ("repeat(2,1fr)", "2"), ("repeat(3,1fr)", "3"), ("repeat(4,1fr)", "4"), ("repeat(5,1fr)", "5"), ("repeat(6,1fr)", "6"), ("240px 1fr", "Sidebar")]), default: Some("repeat(3,1fr)"), scope: PropScope::Visual, css: Some("grid-template-columns") },
+	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Text, default: Some("1rem"), scope: PropScope::Visual, css: Some("gap") },
+	BlockPropDef { key: "row-gap", label: "Row Gap", field: PropFieldType::Text, default: None, scope: PropScope::Visual, css: Some("row-gap") },
 ];
 
 const GRID_PRESETS: &[BlockPreset] = &[
@@ -29,7 +29,7 @@ const GRID_PRESETS: &[BlockPreset] = &[
 ];
 
 const COLUMNS_PROPS: &[BlockPropDef] = &[
-	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Number, default: Some("1rem"), scope: PropScope::Visual, css: Some("gap") },
+	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Text, default: Some("1rem"), scope: PropScope::Visual, css: Some("gap") },
 ];
 
 const COLUMNS_PRESETS: &[BlockPreset] = &[
@@ -38,19 +38,19 @@ const COLUMNS_PRESETS: &[BlockPreset] = &[
 ];
 
 const CARD_PROPS: &[BlockPropDef] = &[
-	BlockPropDef { key: "padding", label: "Padding", field: PropFieldType::Number, default: Some("1rem"), scope: PropScope::Visual, css: Some("padding") },
-	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Number, default: Some("0.5rem"), scope: PropScope::Visual, css: Some("gap") },
-	BlockPropDef { key: "border-radius", label: "Border Radius", field: PropFieldType::Number, default: Some("0.5rem"), scope: PropScope::Visual, css: Some("border-radius") },
+	BlockPropDef { key: "padding", label: "Padding", field: PropFieldType::Text, default: Some("1rem"), scope: PropScope::Visual, css: Some("padding") },
+	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Text, default: Some("0.5rem"), scope: PropScope::Visual, css: Some("gap") },
+	BlockPropDef { key: "border-radius", label: "Border Radius", field: PropFieldType::Text, default: Some("0.5rem"), scope: PropScope::Visual, css: Some("border-radius") },
 ];
 
 const FORM_PROPS: &[BlockPropDef] = &[
-	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Number, default: Some("1rem"), scope: PropScope::Visual, css: Some("gap") },
-	BlockPropDef { key: "padding", label: "Padding", field: PropFieldType::Number, default: None, scope: PropScope::Visual, css: Some("padding") },
+	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Text, default: Some("1rem"), scope: PropScope::Visual, css: Some("gap") },
+	BlockPropDef { key: "padding", label: "Padding", field: PropFieldType::Text, default: None, scope: PropScope::Visual, css: Some("padding") },
 ];
 
 const SECTION_PROPS: &[BlockPropDef] = &[
-	BlockPropDef { key: "padding", label: "Padding", field: PropFieldType::Number, default: Some("2rem"), scope: PropScope::Visual, css: Some("padding") },
-	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Number, default: Some("1rem"), scope: PropScope::Visual, css: Some("gap") },
+	BlockPropDef { key: "padding", label: "Padding", field: PropFieldType::Text, default: Some("2rem"), scope: PropScope::Visual, css: Some("padding") },
+	BlockPropDef { key: "gap", label: "Gap", field: PropFieldType::Text, default: Some("1rem"), scope: PropScope::Visual, css: Some("gap") },
 	BlockPropDef { key: "background", label: "Background", field: PropFieldType::Color, default: None, scope: PropScope::Visual, css: Some("background") },
 ];
 
@@ -1,3 +1,4 @@
+pub mod theme_presets;
 pub mod component_meta {
     #![allow(dead_code, unused_imports)]
     include!(concat!(env!("OUT_DIR"), "/generated/component_meta.rs"));
@@ -27,3 +28,9 @@ pub mod showcase {
     pub const SHOWCASE_JSON: &str = include_str!("showcase.json");
 }
 pub use showcase::SHOWCASE_JSON;
+
+/// JSON Schema (draft 2020-12) de todos os components/blocks/layouts — ver schemas/
+pub mod json_schema {
+    pub const JSON_SCHEMA_BUNDLE: &str = include_str!(concat!(env!("OUT_DIR"), "/generated/canonrs.schema.json"));
+}
+pub use json_schema::JSON_SCHEMA_BUNDLE;
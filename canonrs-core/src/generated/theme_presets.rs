@@ -0,0 +1,37 @@
+// AUTO-GENERATED by tokens-engine from canonrs-tokens/themes/ingest — DO NOT EDIT
+use crate::infra::theme::theme_registry::{ThemePreset, ThemeSwatches};
+
+pub const DEFAULT_THEME_PRESET: &str = "canonrs-theme";
+
+pub static THEME_PRESETS: &[ThemePreset] = &[
+    ThemePreset {
+        id: "amber-minimal",
+        label: "Amber Minimal",
+        description: "Warm amber accents with minimalist approach",
+        has_light: true,
+        has_dark: true,
+        dark_synthesized: false,
+        light: ThemeSwatches { background: "hsl(0 0% 100%)", foreground: "hsl(0 0% 14.902%)", primary: "hsl(37.6923 92.126% 50.1961%)", secondary: "hsl(220 14.2857% 95.8824%)", accent: "hsl(48 100% 96.0784%)" },
+        dark: ThemeSwatches { background: "hsl(0 0% 9.0196%)", foreground: "hsl(0 0% 89.8039%)", primary: "hsl(37.6923 92.126% 50.1961%)", secondary: "hsl(0 0% 14.902%)", accent: "hsl(22.7273 82.5% 31.3725%)" },
+    },
+    ThemePreset {
+        id: "canonrs-theme",
+        label: "CanonRS",
+        description: "Official CanonRS brand theme - Authority and precision",
+        has_light: true,
+        has_dark: true,
+        dark_synthesized: false,
+        light: ThemeSwatches { background: "hsl(220 18% 98%)", foreground: "hsl(222 25% 12%)", primary: "hsl(38 91% 50%)", secondary: "hsl(222 20% 92%)", accent: "hsl(38 95% 60%)" },
+        dark: ThemeSwatches { background: "hsl(222 18% 7%)", foreground: "hsl(220 18% 92%)", primary: "hsl(38 91% 50%)", secondary: "hsl(222 22% 23%)", accent: "hsl(38 95% 42%)" },
+    },
+    ThemePreset {
+        id: "clean-slate",
+        label: "Clean Slate",
+        description: "Minimal, clean design with neutral colors",
+        has_light: true,
+        has_dark: true,
+        dark_synthesized: false,
+        light: ThemeSwatches { background: "hsl(210 40% 98.0392%)", foreground: "hsl(217.2414 32.5843% 17.451%)", primary: "hsl(238.7324 83.5294% 66.6667%)", secondary: "hsl(220 13.0435% 90.9804%)", accent: "hsl(226.4516 100% 93.9216%)" },
+        dark: ThemeSwatches { background: "hsl(222.2222 47.3684% 11.1765%)", foreground: "hsl(214.2857 31.8182% 91.3725%)", primary: "hsl(234.4538 89.4737% 73.9216%)", secondary: "hsl(217.7778 23.0769% 22.9412%)", accent: "hsl(216.9231 19.1176% 26.6667%)" },
+    },
+];
@@ -0,0 +1,548 @@
+//! AriaAudit — confere a semântica ARIA do HTML final do SSR
+//! Regras globais (ids, referências, aria-expanded × data-rs-visibility, contexto de roles)
+//! Mais regras por ComponentFamily aplicadas dentro de cada root de componente
+//! Relatório serializável — `aria_audit(html).assert_valid()` em testes e CI
+
+use std::collections::HashMap;
+use std::fmt;
+use serde::Serialize;
+
+use crate::infra::constraint_engine::Severity;
+use crate::infra::dom_verify::component_roots;
+use crate::infra::html_tree::{HtmlNodeId, HtmlTree};
+use crate::meta_types::{Capability, ComponentFamily, ComponentMeta};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AriaRule {
+    /// `id` único no documento
+    UniqueIds,
+    /// aria-controls / labelledby / describedby / owns / activedescendant apontam para ids existentes
+    References,
+    /// aria-expanded bate com o `data-rs-visibility` do que o trigger controla
+    ExpandedState,
+    /// tab em tablist, option em listbox, row em grid...
+    RoleContext,
+    /// Filhos de tablist/listbox/menu/tree/grid/row têm os roles esperados
+    OwnedRoles,
+    /// <img> com alt, role="img" com nome
+    ImageName,
+    /// role="dialog"/"alertdialog" com título
+    DialogName,
+    /// Dialog de componente AriaModal declara aria-modal="true"
+    ModalFlag,
+    /// Controles de formulário têm nome acessível
+    ControlName,
+    /// aria-valuenow dentro de aria-valuemin..aria-valuemax
+    ValueRange,
+    /// Várias navigation landmarks — cada uma com nome
+    LandmarkName,
+    /// aria-current com valor válido
+    CurrentValue,
+    /// Tabelas e grids com cabeçalho
+    TableHeaders,
+}
+
+/// Valem para o documento inteiro, dentro ou fora de componentes
+pub const GLOBAL_RULES: &[AriaRule] = &[
+    AriaRule::UniqueIds,
+    AriaRule::References,
+    AriaRule::ExpandedState,
+    AriaRule::RoleContext,
+    AriaRule::OwnedRoles,
+    AriaRule::ImageName,
+];
+
+/// Regras extras para elementos dentro de um root da família
+pub fn family_rules(family: &ComponentFamily) -> &'static [AriaRule] {
+    match family {
+        ComponentFamily::Overlay     => &[AriaRule::DialogName, AriaRule::ModalFlag],
+        ComponentFamily::Input       => &[AriaRule::ControlName, AriaRule::ValueRange],
+        ComponentFamily::Interactive => &[AriaRule::ControlName, AriaRule::ValueRange],
+        ComponentFamily::Navigation  => &[AriaRule::LandmarkName, AriaRule::CurrentValue],
+        ComponentFamily::Feedback    => &[AriaRule::ValueRange],
+        ComponentFamily::DataDisplay => &[AriaRule::TableHeaders],
+        ComponentFamily::Layout | ComponentFamily::Typography | ComponentFamily::Utility => &[],
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum AriaViolation {
+    DuplicateId { id: String },
+    MissingReference { attr: String, target: String },
+    ExpandedMismatch { expanded: String, visibility: String },
+    InvalidValue { attr: String, value: String },
+    /// Role fora do container exigido
+    MissingContext { role: String, expected: Vec<&'static str> },
+    /// Container com filho de role inesperado
+    UnexpectedChild { role: String, child_role: String },
+    MissingName { role: String },
+    ModalNotSet,
+    ValueOutOfRange { value: f64, min: f64, max: f64 },
+    MissingTableHeaders,
+}
+
+impl AriaViolation {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::ModalNotSet | Self::MissingTableHeaders => Severity::Warning,
+            Self::MissingName { role } if role == "navigation" => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for AriaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateId { id } =>
+                write!(f, "id '{}' is used more than once", id),
+            Self::MissingReference { attr, target } =>
+                write!(f, "{} references missing id '{}'", attr, target),
+            Self::ExpandedMismatch { expanded, visibility } =>
+                write!(f, "aria-expanded=\"{}\" but the controlled element is '{}'", expanded, visibility),
+            Self::InvalidValue { attr, value } =>
+                write!(f, "{}=\"{}\" is not a valid value", attr, value),
+            Self::MissingContext { role, expected } =>
+                write!(f, "role '{}' must be inside {}", role, expected.join(" or ")),
+            Self::UnexpectedChild { role, child_role } =>
+                write!(f, "role '{}' cannot own role '{}'", role, child_role),
+            Self::MissingName { role } =>
+                write!(f, "'{}' has no accessible name", role),
+            Self::ModalNotSet =>
+                write!(f, "modal dialog is missing aria-modal=\"true\""),
+            Self::ValueOutOfRange { value, min, max } =>
+                write!(f, "aria-valuenow {} is outside {}..{}", value, min, max),
+            Self::MissingTableHeaders =>
+                write!(f, "table has no column or row headers"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AriaIssue {
+    pub path:      String,
+    pub severity:  Severity,
+    pub rule:      AriaRule,
+    /// Família do root de componente mais próximo — None fora de componentes
+    pub family:    Option<&'static str>,
+    pub component: Option<&'static str>,
+    pub message:   String,
+    pub violation: AriaViolation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AriaReport {
+    pub errors:   usize,
+    pub warnings: usize,
+    pub issues:   Vec<AriaIssue>,
+}
+
+impl AriaReport {
+    pub fn new(issues: Vec<AriaIssue>) -> Self {
+        let errors = issues.iter().filter(|i| i.severity == Severity::Error).count();
+        Self { errors, warnings: issues.len() - errors, issues }
+    }
+
+    /// Válido quando não há erros — warnings não bloqueiam
+    pub fn is_valid(&self) -> bool {
+        self.errors == 0
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Para testes/CI — panic listando todos os erros
+    pub fn assert_valid(&self) {
+        if self.is_valid() { return; }
+        let lines: Vec<String> = self.issues
+            .iter()
+            .filter(|i| i.severity == Severity::Error)
+            .map(|i| format!("  [{:?}] {} — {}", i.rule, i.path, i.message))
+            .collect();
+        panic!("ARIA audit failed ({} errors):\n{}", self.errors, lines.join("\n"));
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AriaAudit {
+    disabled: Vec<AriaRule>,
+}
+
+/// Atalho: `AriaAudit::new().audit(html)`
+pub fn aria_audit(html: &str) -> AriaReport {
+    AriaAudit::new().audit(html)
+}
+
+const REFERENCE_ATTRS: &[&str] = &["aria-controls", "aria-labelledby", "aria-describedby", "aria-owns", "aria-activedescendant"];
+const CURRENT_VALUES: &[&str] = &["page", "step", "location", "date", "time", "true", "false"];
+const CONTROL_ROLES: &[&str] = &["checkbox", "switch", "radio", "slider", "combobox", "textbox", "searchbox", "spinbutton"];
+/// Roles cujo nome pode vir do próprio conteúdo
+const NAME_FROM_CONTENT: &[&str] = &["checkbox", "switch", "radio"];
+const RANGE_ROLES: &[&str] = &["progressbar", "slider", "spinbutton", "meter", "scrollbar"];
+
+/// (role filho, containers aceitos)
+const CONTEXT: &[(&str, &[&str])] = &[
+    ("tab",              &["tablist"]),
+    ("option",           &["listbox", "group"]),
+    ("menuitem",         &["menu", "menubar", "group"]),
+    ("menuitemcheckbox", &["menu", "menubar", "group"]),
+    ("menuitemradio",    &["menu", "menubar", "group"]),
+    ("treeitem",         &["tree", "group"]),
+    ("row",              &["grid", "table", "treegrid", "rowgroup"]),
+    ("gridcell",         &["row"]),
+    ("cell",             &["row"]),
+    ("columnheader",     &["row"]),
+    ("rowheader",        &["row"]),
+    ("listitem",         &["list", "group"]),
+];
+
+/// (container, roles que pode possuir)
+const OWNED: &[(&str, &[&str])] = &[
+    ("tablist",    &["tab"]),
+    ("listbox",    &["option", "group"]),
+    ("menu",       &["menuitem", "menuitemcheckbox", "menuitemradio", "group", "separator"]),
+    ("menubar",    &["menuitem", "menuitemcheckbox", "menuitemradio", "group", "separator"]),
+    ("tree",       &["treeitem", "group"]),
+    ("grid",       &["row", "rowgroup"]),
+    ("treegrid",   &["row", "rowgroup"]),
+    ("table",      &["row", "rowgroup"]),
+    ("rowgroup",   &["row"]),
+    ("row",        &["cell", "gridcell", "columnheader", "rowheader"]),
+    ("radiogroup", &["radio"]),
+];
+
+struct Ctx<'a> {
+    tree:     &'a HtmlTree,
+    owners:   HashMap<HtmlNodeId, &'static ComponentMeta>,
+    first_id: HashMap<&'a str, HtmlNodeId>,
+    navs:     usize,
+}
+
+impl AriaAudit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Desliga uma regra (ex: TableHeaders em páginas de demo)
+    pub fn without(mut self, rule: AriaRule) -> Self {
+        self.disabled.push(rule);
+        self
+    }
+
+    pub fn audit(&self, html: &str) -> AriaReport {
+        self.audit_tree(&HtmlTree::parse(html))
+    }
+
+    pub fn audit_tree(&self, tree: &HtmlTree) -> AriaReport {
+        let mut first_id = HashMap::new();
+        let mut navs = 0;
+        for e in tree.elements() {
+            if let Some(id) = tree.node(e).attr("id").filter(|v| !v.is_empty()) {
+                first_id.entry(id).or_insert(e);
+            }
+            if role_of(tree, e) == Some("navigation") { navs += 1; }
+        }
+        let ctx = Ctx { tree, owners: component_roots(tree).into_iter().collect(), first_id, navs };
+
+        let mut issues = vec![];
+        for element in tree.elements() {
+            let owner = std::iter::once(element).chain(tree.ancestors(element)).find_map(|a| ctx.owners.get(&a).copied());
+            let family = owner.map_or(&[][..], |m| family_rules(&m.family));
+            for rule in GLOBAL_RULES.iter().chain(family).filter(|r| !self.disabled.contains(r)) {
+                for violation in check(&ctx, *rule, element, owner) {
+                    issues.push(AriaIssue {
+                        path: tree.path(element),
+                        severity: violation.severity(),
+                        rule: *rule,
+                        family: owner.map(|m| m.family.as_str()),
+                        component: owner.map(|m| m.id),
+                        message: violation.message(),
+                        violation,
+                    });
+                }
+            }
+        }
+        AriaReport::new(issues)
+    }
+}
+
+/// Role explícito, ou implícito para as tags que participam das regras de contexto
+fn role_of(tree: &HtmlTree, e: HtmlNodeId) -> Option<&str> {
+    let node = tree.node(e);
+    if let Some(role) = node.attr("role").and_then(|r| r.split_whitespace().next()) {
+        return Some(role);
+    }
+    match node.tag.as_str() {
+        "table"                     => Some("table"),
+        "thead" | "tbody" | "tfoot" => Some("rowgroup"),
+        "tr"                        => Some("row"),
+        "td"                        => Some("cell"),
+        "th"                        => Some("columnheader"),
+        "ul" | "ol"                 => Some("list"),
+        "li"                        => Some("listitem"),
+        "nav"                       => Some("navigation"),
+        "dialog"                    => Some("dialog"),
+        "progress"                  => Some("progressbar"),
+        "img"                       => Some("img"),
+        _                           => None,
+    }
+}
+
+fn is_semantic(role: Option<&str>) -> bool {
+    !matches!(role, None | Some("none") | Some("presentation") | Some("generic"))
+}
+
+/// Elementos com role semântico mais próximos abaixo de `e` — wrappers sem role são atravessados
+fn owned(tree: &HtmlTree, e: HtmlNodeId) -> Vec<HtmlNodeId> {
+    let mut out = vec![];
+    for &child in &tree.node(e).children {
+        if !tree.node(child).is_element() { continue; }
+        if is_semantic(role_of(tree, child)) {
+            out.push(child);
+        } else {
+            out.extend(owned(tree, child));
+        }
+    }
+    out
+}
+
+/// Nome acessível explícito: aria-label, aria-labelledby, <label for>, <label> ancestral, title
+fn accessible_name(tree: &HtmlTree, e: HtmlNodeId) -> String {
+    let node = tree.node(e);
+    if let Some(label) = node.attr("aria-label").filter(|l| !l.trim().is_empty()) {
+        return label.trim().to_string();
+    }
+    if let Some(ids) = node.attr("aria-labelledby") {
+        let text: Vec<String> = ids.split_whitespace()
+            .filter_map(|id| tree.find_by_id(id))
+            .map(|t| tree.text_content(t))
+            .collect();
+        let text = text.join(" ");
+        if !text.trim().is_empty() { return text; }
+    }
+    if let Some(id) = node.attr("id").filter(|v| !v.is_empty()) {
+        if let Some(label) = tree.elements().find(|&l| tree.node(l).tag == "label" && tree.node(l).attr("for") == Some(id)) {
+            let text = tree.text_content(label);
+            if !text.is_empty() { return text; }
+        }
+    }
+    if let Some(label) = tree.ancestors(e).find(|&a| tree.node(a).tag == "label") {
+        let text = tree.text_content(label);
+        if !text.is_empty() { return text; }
+    }
+    node.attr("title").unwrap_or_default().trim().to_string()
+}
+
+fn check(ctx: &Ctx, rule: AriaRule, e: HtmlNodeId, owner: Option<&ComponentMeta>) -> Vec<AriaViolation> {
+    let tree = ctx.tree;
+    let node = tree.node(e);
+    let role = role_of(tree, e);
+    let mut out = vec![];
+    match rule {
+        AriaRule::UniqueIds => {
+            if let Some(id) = node.attr("id").filter(|v| !v.is_empty()) {
+                if ctx.first_id.get(id) != Some(&e) {
+                    out.push(AriaViolation::DuplicateId { id: id.into() });
+                }
+            }
+        }
+
+        AriaRule::References => {
+            for attr in REFERENCE_ATTRS {
+                for target in node.attr(attr).unwrap_or_default().split_whitespace() {
+                    if !ctx.first_id.contains_key(target) {
+                        out.push(AriaViolation::MissingReference { attr: attr.to_string(), target: target.into() });
+                    }
+                }
+            }
+        }
+
+        AriaRule::ExpandedState => {
+            let Some(expanded) = node.attr("aria-expanded") else { return out };
+            if expanded != "true" && expanded != "false" {
+                out.push(AriaViolation::InvalidValue { attr: "aria-expanded".into(), value: expanded.into() });
+                return out;
+            }
+            // Fonte do estado: o próprio trigger, o que ele controla, ou o root/item que o contém
+            let controlled = node.attr("aria-controls").unwrap_or_default()
+                .split_whitespace()
+                .filter_map(|id| ctx.first_id.get(id).copied());
+            let visibility = std::iter::once(e)
+                .chain(controlled)
+                .chain(tree.ancestors(e))
+                .find_map(|a| tree.node(a).attr("data-rs-visibility"));
+            if let Some(visibility) = visibility.filter(|v| *v == "open" || *v == "closed") {
+                if (expanded == "true") != (visibility == "open") {
+                    out.push(AriaViolation::ExpandedMismatch { expanded: expanded.into(), visibility: visibility.into() });
+                }
+            }
+        }
+
+        AriaRule::RoleContext => {
+            let Some((role, expected)) = role.and_then(|r| CONTEXT.iter().find(|(c, _)| *c == r)) else { return out };
+            let container = tree.ancestors(e).map(|a| role_of(tree, a)).find(|r| is_semantic(*r)).flatten();
+            // `group` só conta quando ele mesmo está no container certo
+            let ok = container.is_some_and(|c| expected.contains(&c));
+            if !ok {
+                out.push(AriaViolation::MissingContext { role: role.to_string(), expected: expected.to_vec() });
+            }
+        }
+
+        AriaRule::OwnedRoles => {
+            let Some((role, allowed)) = role.and_then(|r| OWNED.iter().find(|(c, _)| *c == r)) else { return out };
+            for child in owned(tree, e) {
+                let child_role = role_of(tree, child).unwrap_or_default();
+                if !allowed.contains(&child_role) {
+                    out.push(AriaViolation::UnexpectedChild { role: role.to_string(), child_role: child_role.into() });
+                }
+            }
+        }
+
+        AriaRule::ImageName => {
+            if node.tag == "img" {
+                if !node.has_attr("alt") && accessible_name(tree, e).is_empty() {
+                    out.push(AriaViolation::MissingName { role: "img".into() });
+                }
+            } else if role == Some("img") && node.attr("aria-hidden") != Some("true") && accessible_name(tree, e).is_empty() {
+                out.push(AriaViolation::MissingName { role: "img".into() });
+            }
+        }
+
+        AriaRule::DialogName => {
+            if !matches!(role, Some("dialog") | Some("alertdialog")) { return out; }
+            let has_title = tree.descendants(e).into_iter().any(|d| {
+                tree.node(d).attrs.iter().any(|(k, v)| k.starts_with("data-rs-") && k.ends_with("-title") && v.is_empty())
+                    && !tree.text_content(d).is_empty()
+            });
+            if !has_title && accessible_name(tree, e).is_empty() {
+                out.push(AriaViolation::MissingName { role: role.unwrap_or("dialog").into() });
+            }
+        }
+
+        AriaRule::ModalFlag => {
+            let modal = owner.is_some_and(|m| m.capabilities.contains(&Capability::AriaModal));
+            if modal && matches!(role, Some("dialog") | Some("alertdialog")) && node.attr("aria-modal") != Some("true") {
+                out.push(AriaViolation::ModalNotSet);
+            }
+        }
+
+        AriaRule::ControlName => {
+            let input_type = node.attr("type").unwrap_or("text");
+            let native = match node.tag.as_str() {
+                "input"              => !matches!(input_type, "hidden" | "submit" | "reset" | "button" | "image"),
+                "select" | "textarea" => true,
+                _                    => false,
+            };
+            let aria = role.is_some_and(|r| CONTROL_ROLES.contains(&r));
+            if !(native || aria) || node.attr("aria-hidden") == Some("true") { return out; }
+            let from_content = role.is_some_and(|r| NAME_FROM_CONTENT.contains(&r)) && !tree.text_content(e).is_empty();
+            if !from_content && accessible_name(tree, e).is_empty() {
+                let role = role.map(str::to_string).unwrap_or_else(|| node.tag.clone());
+                out.push(AriaViolation::MissingName { role });
+            }
+        }
+
+        AriaRule::ValueRange => {
+            if !role.is_some_and(|r| RANGE_ROLES.contains(&r)) { return out; }
+            let Some(raw) = node.attr("aria-valuenow") else { return out };
+            let parse = |attr: &str, default: f64| -> Result<f64, AriaViolation> {
+                match node.attr(attr) {
+                    None    => Ok(default),
+                    Some(v) => v.trim().parse::<f64>().map_err(|_| AriaViolation::InvalidValue { attr: attr.into(), value: v.into() }),
+                }
+            };
+            let parsed = (
+                raw.trim().parse::<f64>().map_err(|_| AriaViolation::InvalidValue { attr: "aria-valuenow".into(), value: raw.into() }),
+                parse("aria-valuemin", 0.0),
+                parse("aria-valuemax", 100.0),
+            );
+            match parsed {
+                (Ok(value), Ok(min), Ok(max)) => {
+                    if value < min || value > max {
+                        out.push(AriaViolation::ValueOutOfRange { value, min, max });
+                    }
+                }
+                (value, min, max) => out.extend([value.err(), min.err(), max.err()].into_iter().flatten()),
+            }
+        }
+
+        AriaRule::LandmarkName => {
+            if role == Some("navigation") && ctx.navs > 1 && accessible_name(tree, e).is_empty() {
+                out.push(AriaViolation::MissingName { role: "navigation".into() });
+            }
+        }
+
+        AriaRule::CurrentValue => {
+            if let Some(current) = node.attr("aria-current") {
+                if !CURRENT_VALUES.contains(&current) {
+                    out.push(AriaViolation::InvalidValue { attr: "aria-current".into(), value: current.into() });
+                }
+            }
+        }
+
+        AriaRule::TableHeaders => {
+            if !matches!(role, Some("table") | Some("grid") | Some("treegrid")) { return out; }
+            let has_header = tree.descendants(e).into_iter()
+                .any(|d| matches!(role_of(tree, d), Some("columnheader") | Some("rowheader")));
+            if !has_header {
+                out.push(AriaViolation::MissingTableHeaders);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(report: &AriaReport) -> Vec<AriaRule> {
+        report.issues.iter().map(|i| i.rule).collect()
+    }
+
+    #[test]
+    fn test_global_rules() {
+        let html = r#"
+            <button aria-controls="panel" aria-expanded="true">Toggle</button>
+            <div id="panel" data-rs-visibility="closed">...</div>
+            <span id="panel">dup</span>
+            <div aria-labelledby="ghost"></div>
+            <div role="grid"><div role="option">x</div></div>
+            <img src="a.png">
+            <ul role="tablist"><li role="tab">A</li></ul>"#;
+        let report = aria_audit(html);
+        let found = rules(&report);
+        for rule in [AriaRule::ExpandedState, AriaRule::UniqueIds, AriaRule::References, AriaRule::OwnedRoles, AriaRule::RoleContext, AriaRule::ImageName] {
+            assert!(found.contains(&rule), "{:?} not in {:?}", rule, found);
+        }
+        // tab dentro de tablist é válido
+        assert!(!report.issues.iter().any(|i| i.message.contains("'tab' must be inside")));
+        assert!(report.to_json().unwrap().contains("\"code\":\"expanded_mismatch\""));
+    }
+
+    #[test]
+    fn test_family_rules_only_inside_roots() {
+        let untitled = r#"<div role="dialog"><p>no title</p></div>"#;
+        assert!(aria_audit(untitled).is_valid());
+
+        let dialog = r#"
+            <div data-rs-dialog="" data-rs-interaction="overlay" data-rs-uid="d1" data-rs-visibility="open">
+                <div data-rs-dialog-content="" role="dialog"><p>no title</p></div>
+            </div>"#;
+        let report = aria_audit(dialog);
+        let issue = report.issues.iter().find(|i| i.rule == AriaRule::DialogName).expect("dialog name");
+        assert_eq!(issue.component, Some("dialog"));
+        assert_eq!(issue.severity, Severity::Error);
+
+        let titled = dialog.replace("<p>no title</p>", r#"<h2 data-rs-dialog-title="">Delete file</h2>"#);
+        assert!(!aria_audit(&titled).issues.iter().any(|i| i.rule == AriaRule::DialogName));
+        assert!(AriaAudit::new().without(AriaRule::DialogName).without(AriaRule::ModalFlag).audit(dialog).issues.is_empty());
+    }
+}
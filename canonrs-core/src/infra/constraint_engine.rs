@@ -1,6 +1,8 @@
 //! Constraint Engine — valida composição e decide aceitação
 //! Opera sobre dados estáticos gerados (BlockDefinition, ComponentMeta, CatalogEntry)
 
+use std::fmt;
+use serde::Serialize;
 use crate::block_types::{BlockDefinition, BlockCategory, AcceptRule};
 use crate::catalog_types::{CatalogEntry, CatalogKind};
 use crate::meta_types::ComponentMeta;
@@ -13,20 +15,67 @@ pub enum ValidationResult {
     Invalid(Vec<ConstraintViolation>),
 }
 
-#[derive(Debug, PartialEq)]
+/// Error bloqueia render/CI; Warning indica composição incompleta
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
 pub enum ConstraintViolation {
     /// Region não aceita este bloco
     RegionRejectsBlock { region: String, block_id: String },
+    /// Parent não declara esta region
+    UnknownRegion { block_id: String, region: String },
     /// Bloco não é container mas recebeu filhos
     NotAContainer { block_id: String },
     /// Region excedeu max_children
     RegionFull { region: String, max: usize },
     /// Required parts ausentes
     MissingParts { component: String, missing: Vec<&'static str> },
+    /// Required region ausente (regions_required)
+    MissingRegion { block_id: String, region: &'static str },
     /// Bloco não existe no catálogo
     UnknownBlock { block_id: String },
 }
 
+impl ConstraintViolation {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::MissingParts { .. } | Self::MissingRegion { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RegionRejectsBlock { region, block_id } =>
+                write!(f, "region '{}' does not accept '{}'", region, block_id),
+            Self::UnknownRegion { block_id, region } =>
+                write!(f, "'{}' has no region '{}'", block_id, region),
+            Self::NotAContainer { block_id } =>
+                write!(f, "'{}' is not a container and cannot have children", block_id),
+            Self::RegionFull { region, max } =>
+                write!(f, "region '{}' accepts at most {} children", region, max),
+            Self::MissingParts { component, missing } =>
+                write!(f, "'{}' is missing required parts: {}", component, missing.join(", ")),
+            Self::MissingRegion { block_id, region } =>
+                write!(f, "'{}' is missing required region '{}'", block_id, region),
+            Self::UnknownBlock { block_id } =>
+                write!(f, "'{}' does not exist in the catalog", block_id),
+        }
+    }
+}
+
 impl ValidationResult {
     pub fn is_valid(&self) -> bool {
         matches!(self, ValidationResult::Valid)
@@ -67,23 +116,22 @@ impl ConstraintEngine {
         let region = match parent.find_region(region_id) {
             Some(r) => r,
             None    => return ValidationResult::Invalid(vec![
-                ConstraintViolation::RegionRejectsBlock {
+                ConstraintViolation::UnknownRegion {
+                    block_id: parent_id.to_string(),
                     region: region_id.to_string(),
-                    block_id: child_id.to_string(),
                 }
             ]),
         };
 
-        // Verifica max_children
+        // max_children e AcceptRule são independentes — reporta ambos
+        let mut violations = vec![];
+
         if let Some(max) = region.max_children {
             if current_children >= max {
-                return ValidationResult::Invalid(vec![
-                    ConstraintViolation::RegionFull { region: region_id.to_string(), max }
-                ]);
+                violations.push(ConstraintViolation::RegionFull { region: region_id.to_string(), max });
             }
         }
 
-        // Verifica AcceptRule
         let child_category = Self::resolve_category(child_id);
         let accepted = region.accepts.iter().any(|rule| match rule {
             AcceptRule::Any           => true,
@@ -91,15 +139,17 @@ impl ConstraintEngine {
             AcceptRule::Block(id)     => *id == child_id,
         });
 
-        if accepted {
+        if !accepted {
+            violations.push(ConstraintViolation::RegionRejectsBlock {
+                region: region_id.to_string(),
+                block_id: child_id.to_string(),
+            });
+        }
+
+        if violations.is_empty() {
             ValidationResult::Valid
         } else {
-            ValidationResult::Invalid(vec![
-                ConstraintViolation::RegionRejectsBlock {
-                    region: region_id.to_string(),
-                    block_id: child_id.to_string(),
-                }
-            ])
+            ValidationResult::Invalid(violations)
         }
     }
 
@@ -165,6 +215,27 @@ impl ConstraintEngine {
 
     /// Verifica se child pode entrar numa region específica do parent — via region_rules do catalog
     pub fn catalog_can_nest_in_region(parent: &CatalogEntry, region_id: &str, child: &CatalogEntry) -> bool {
+        Self::catalog_check_nest_in_region(parent, region_id, child).is_valid()
+    }
+
+    /// Mesma regra de catalog_can_nest_in_region, com o motivo da recusa
+    pub fn catalog_check_nest_in_region(parent: &CatalogEntry, region_id: &str, child: &CatalogEntry) -> ValidationResult {
+        let accepted = Self::catalog_region_accepts(parent, region_id, child);
+        if accepted {
+            return ValidationResult::Valid;
+        }
+        let violation = if parent.accepts.is_empty() && parent.region_rules.is_empty() {
+            ConstraintViolation::NotAContainer { block_id: parent.id.to_string() }
+        } else {
+            ConstraintViolation::RegionRejectsBlock {
+                region: region_id.to_string(),
+                block_id: child.id.to_string(),
+            }
+        };
+        ValidationResult::Invalid(vec![violation])
+    }
+
+    fn catalog_region_accepts(parent: &CatalogEntry, region_id: &str, child: &CatalogEntry) -> bool {
         use crate::catalog_types::CatalogAcceptRule;
         // Tenta region_rules primeiro (mais específico)
         if let Some(region_rule) = parent.region_rules.iter().find(|r| r.region == region_id) {
@@ -278,6 +349,27 @@ mod tests {
         assert!(!result.is_valid(), "header should not accept data-table");
     }
 
+    #[test]
+    fn test_violation_severity_and_message() {
+        let v = ConstraintViolation::MissingRegion { block_id: "hero".into(), region: "content" };
+        assert_eq!(v.severity(), Severity::Warning);
+        assert_eq!(v.message(), "'hero' is missing required region 'content'");
+        assert_eq!(ConstraintViolation::UnknownBlock { block_id: "x".into() }.severity(), Severity::Error);
+    }
+
+    #[test]
+    fn test_catalog_check_reports_reason() {
+        use crate::catalog_types::{CatalogEntry, CatalogCategory, CatalogKind};
+        let parent = CatalogEntry {
+            id: "badge", label: "Badge", description: "",
+            kind: CatalogKind::Component, category: CatalogCategory::Display,
+            tags: &[], parts: &[], regions: &[], accepts: &[], region_rules: &[],
+            keywords: &[], related: &[],
+        };
+        let result = ConstraintEngine::catalog_check_nest_in_region(&parent, "content", &parent);
+        assert_eq!(result.violations(), &[ConstraintViolation::NotAContainer { block_id: "badge".into() }]);
+    }
+
     #[test]
     fn test_catalog_cannot_nest_layout_in_layout() {
         use crate::catalog_types::{CatalogEntry, CatalogCategory, CatalogKind};
@@ -285,11 +377,13 @@ mod tests {
             id: "dashboard", label: "Dashboard", description: "",
             kind: CatalogKind::Layout, category: CatalogCategory::Layout,
             tags: &[], parts: &[], regions: &["header", "sidebar", "content"], accepts: &[], region_rules: &[],
+            keywords: &[], related: &[],
         };
         let child = CatalogEntry {
             id: "marketing", label: "Marketing", description: "",
             kind: CatalogKind::Layout, category: CatalogCategory::Layout,
             tags: &[], parts: &[], regions: &[], accepts: &[], region_rules: &[],
+            keywords: &[], related: &[],
         };
         assert!(!ConstraintEngine::catalog_can_nest(&parent, &child));
     }
@@ -0,0 +1,24 @@
+use leptos::prelude::*;
+use super::density_types::{Density, DensityContext};
+
+/// `data-density` na subárvore — os tokens de densidade são reescopados por CSS, sem lógica por componente
+#[component]
+pub fn DensityProvider(
+    #[prop(default = Density::Comfortable)] density: Density,
+    children: Children,
+) -> impl IntoView {
+    let density = RwSignal::new(density);
+
+    provide_context(DensityContext { density });
+
+    view! {
+        <div data-density=move || density.get().as_str() style="display: contents">
+            {children()}
+        </div>
+    }
+}
+
+pub fn use_density() -> DensityContext {
+    use_context::<DensityContext>()
+        .expect("use_density must be used within DensityProvider")
+}
@@ -0,0 +1,66 @@
+use leptos::prelude::*;
+
+/// Densidade global — casa com `[data-density]` gerado pelo tokens-engine (density.css)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Density {
+    Compact,
+    #[default]
+    Comfortable,
+    Spacious,
+}
+
+impl Density {
+    pub const ALL: [Density; 3] = [Self::Compact, Self::Comfortable, Self::Spacious];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Compact     => "compact",
+            Self::Comfortable => "comfortable",
+            Self::Spacious    => "spacious",
+        }
+    }
+
+    /// Valores desconhecidos viram None — `unwrap_or_default()` cai em Comfortable
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "compact"     => Some(Self::Compact),
+            "comfortable" => Some(Self::Comfortable),
+            "spacious"    => Some(Self::Spacious),
+            _             => None,
+        }
+    }
+}
+
+/// Density context — o mais próximo vence (providers aninhados)
+#[derive(Clone, Copy)]
+pub struct DensityContext {
+    pub density: RwSignal<Density>,
+}
+
+impl DensityContext {
+    pub fn get(&self) -> Density {
+        self.density.get()
+    }
+
+    pub fn set(&self, density: Density) {
+        self.density.set(density);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_density() {
+        for density in Density::ALL {
+            assert_eq!(Density::parse(density.as_str()), Some(density));
+        }
+    }
+
+    #[test]
+    fn unknown_density_falls_back_to_comfortable() {
+        assert_eq!(Density::parse("dense"), None);
+        assert_eq!(Density::parse("dense").unwrap_or_default(), Density::Comfortable);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod density_provider;
+pub mod density_types;
+
+pub use density_types::{Density, DensityContext};
+pub use density_provider::{DensityProvider, use_density};
@@ -0,0 +1,486 @@
+//! DomVerifier — valida o HTML final do SSR contra o DOM contract
+//! Acha todo root `data-rs-<id>=""` e confere: required parts, required regions,
+//! atributos de estado (`data-rs-uid`, `data-rs-visibility`, `data-rs-interaction`) e aninhamento
+//! Diferente de dom_contract::validate_structure, não depende do chamador listar os parts
+//! Uso: testes (`verify_html(html).assert_valid()`) e middleware SSR em debug (canonrs_server::dev::verify)
+
+use std::collections::HashMap;
+use std::fmt;
+use serde::Serialize;
+
+use crate::block_types::BlockDefinition;
+use crate::catalog_types::{CatalogEntry, CatalogKind};
+use crate::infra::constraint_engine::{ConstraintEngine, Severity, ValidationResult};
+use crate::infra::html_tree::{HtmlNodeId, HtmlTree};
+use crate::meta_types::{Capability, ComponentMeta};
+
+/// Grupos registrados no dispatcher de canonrs-interactions
+pub const INTERACTION_GROUPS: &[&str] = &["init", "nav", "data", "gesture", "overlay", "selection", "content"];
+
+/// Capabilities que só funcionam com um engine de interação ligado ao root
+const RUNTIME_CAPABILITIES: &[Capability] = &[
+    Capability::OpenClose,
+    Capability::Selected,
+    Capability::Active,
+    Capability::Pressed,
+    Capability::Value,
+    Capability::KeyboardArrows,
+    Capability::Roving,
+    Capability::Typeahead,
+    Capability::VirtualScroll,
+    Capability::DragDrop,
+    Capability::Resize,
+    Capability::Overflow,
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum DomViolation {
+    /// Required part não renderizado dentro do root
+    MissingPart { component: String, part: String, portal: bool },
+    /// Part renderizado fora de qualquer root do seu componente
+    OrphanPart { component: String, part: String },
+    /// Required region ausente no block/layout
+    MissingRegion { block_id: String, region: String },
+    /// `data-rs-region` que o block/layout não declara
+    UnknownRegion { block_id: String, region: String },
+    MissingUid { component: String },
+    DuplicateUid { uid: String },
+    /// Capability OpenClose sem `data-rs-visibility` no root nem nos parts
+    MissingVisibility { component: String },
+    InvalidVisibility { value: String },
+    /// Componente com comportamento de runtime sem `data-rs-interaction`
+    MissingInteraction { component: String },
+    UnknownInteraction { component: String, group: String },
+    /// Filho recusado pela region — `reason` vem do ConstraintEngine
+    InvalidNesting { parent: String, region: String, child: String, reason: String },
+}
+
+impl DomViolation {
+    pub fn severity(&self) -> Severity {
+        match self {
+            // Conteúdo em <Portal> não sai no SSR — part ausente em overlay é só aviso
+            Self::MissingPart { portal, .. } if *portal => Severity::Warning,
+            Self::OrphanPart { .. } | Self::UnknownInteraction { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for DomViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPart { component, part, portal } => {
+                write!(f, "'{}' is missing required part '{}'", component, part)?;
+                if *portal { write!(f, " (may be rendered in a portal)")?; }
+                Ok(())
+            }
+            Self::OrphanPart { component, part } =>
+                write!(f, "part '{}' is rendered outside any '{}' root", part, component),
+            Self::MissingRegion { block_id, region } =>
+                write!(f, "'{}' is missing required region '{}'", block_id, region),
+            Self::UnknownRegion { block_id, region } =>
+                write!(f, "'{}' has no region '{}'", block_id, region),
+            Self::MissingUid { component } =>
+                write!(f, "'{}' root has no data-rs-uid", component),
+            Self::DuplicateUid { uid } =>
+                write!(f, "data-rs-uid '{}' is used more than once", uid),
+            Self::MissingVisibility { component } =>
+                write!(f, "'{}' opens and closes but renders no data-rs-visibility", component),
+            Self::InvalidVisibility { value } =>
+                write!(f, "data-rs-visibility '{}' is not 'open' or 'closed'", value),
+            Self::MissingInteraction { component } =>
+                write!(f, "'{}' root has no data-rs-interaction group", component),
+            Self::UnknownInteraction { component, group } =>
+                write!(f, "'{}' uses unknown interaction group '{}'", component, group),
+            Self::InvalidNesting { parent, region, child, reason } =>
+                write!(f, "'{}' cannot be placed in region '{}' of '{}': {}", child, region, parent, reason),
+        }
+    }
+}
+
+/// Uma violação localizada no HTML — `path` é o caminho legível do elemento
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DomIssue {
+    pub path:      String,
+    pub severity:  Severity,
+    pub message:   String,
+    pub violation: DomViolation,
+}
+
+impl DomIssue {
+    fn new(tree: &HtmlTree, element: HtmlNodeId, violation: DomViolation) -> Self {
+        Self {
+            path: tree.path(element),
+            severity: violation.severity(),
+            message: violation.message(),
+            violation,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DomReport {
+    /// Roots de componente/block/layout encontrados
+    pub roots:    usize,
+    pub errors:   usize,
+    pub warnings: usize,
+    pub issues:   Vec<DomIssue>,
+}
+
+impl DomReport {
+    pub fn new(roots: usize, issues: Vec<DomIssue>) -> Self {
+        let errors = issues.iter().filter(|i| i.severity == Severity::Error).count();
+        Self { roots, errors, warnings: issues.len() - errors, issues }
+    }
+
+    /// Válido quando não há erros — warnings não bloqueiam
+    pub fn is_valid(&self) -> bool {
+        self.errors == 0
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Para testes — panic listando todos os erros
+    pub fn assert_valid(&self) {
+        if self.is_valid() { return; }
+        let lines: Vec<String> = self.issues
+            .iter()
+            .filter(|i| i.severity == Severity::Error)
+            .map(|i| format!("  {} — {}", i.path, i.message))
+            .collect();
+        panic!("DOM contract violated ({} errors):\n{}", self.errors, lines.join("\n"));
+    }
+}
+
+/// Papel de um elemento no contrato
+enum Role {
+    Component(&'static ComponentMeta),
+    Block(&'static BlockDefinition),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DomVerifier {
+    skip: Vec<String>,
+}
+
+impl DomVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignora um componente/block pelo id (exceções conhecidas)
+    pub fn skip(mut self, id: &str) -> Self {
+        self.skip.push(id.to_string());
+        self
+    }
+
+    pub fn verify(&self, html: &str) -> DomReport {
+        self.verify_tree(&HtmlTree::parse(html))
+    }
+
+    pub fn verify_tree(&self, tree: &HtmlTree) -> DomReport {
+        let ids = component_ids();
+        let roles: HashMap<HtmlNodeId, Vec<Role>> = tree.elements()
+            .map(|e| (e, self.roles(tree, e, &ids)))
+            .filter(|(_, r)| !r.is_empty())
+            .collect();
+
+        let mut issues = vec![];
+        let mut uids: HashMap<&str, HtmlNodeId> = HashMap::new();
+        for element in tree.elements() {
+            let node = tree.node(element);
+            if let Some(value) = node.attr("data-rs-visibility") {
+                if value != "open" && value != "closed" {
+                    issues.push(DomIssue::new(tree, element, DomViolation::InvalidVisibility { value: value.into() }));
+                }
+            }
+            if let Some(uid) = node.attr("data-rs-uid").filter(|u| !u.is_empty()) {
+                if uids.insert(uid, element).is_some() {
+                    issues.push(DomIssue::new(tree, element, DomViolation::DuplicateUid { uid: uid.into() }));
+                }
+            }
+            for (component, part) in parts_of(node.attrs.iter(), &ids) {
+                if self.is_skipped(component) { continue; }
+                let marker = format!("data-rs-{}", component);
+                let inside = std::iter::once(element).chain(tree.ancestors(element))
+                    .any(|a| tree.node(a).attr(&marker) == Some(""));
+                if !inside {
+                    issues.push(DomIssue::new(tree, element, DomViolation::OrphanPart {
+                        component: component.into(),
+                        part: part.into(),
+                    }));
+                }
+            }
+            for role in roles.get(&element).into_iter().flatten() {
+                match role {
+                    Role::Component(meta) => check_component(tree, element, meta, &mut issues),
+                    Role::Block(def)      => check_block(tree, element, def, &roles, &mut issues),
+                }
+                check_nesting(tree, element, role, &roles, &mut issues);
+            }
+        }
+        DomReport::new(roles.len(), issues)
+    }
+
+    fn is_skipped(&self, id: &str) -> bool {
+        self.skip.iter().any(|s| s == id)
+    }
+
+    /// Marcadores vazios `data-rs-<id>=""` — um elemento que é part de outro componente
+    /// não vira root dos componentes usados só como estilo (ex: trigger com `data-rs-button`)
+    fn roles(&self, tree: &HtmlTree, element: HtmlNodeId, ids: &[&'static str]) -> Vec<Role> {
+        let node = tree.node(element);
+        let is_part = parts_of(node.attrs.iter(), ids).next().is_some();
+        let mut roles = vec![];
+        for (key, value) in &node.attrs {
+            let Some(name) = key.strip_prefix("data-rs-") else { continue };
+            if !value.is_empty() || self.is_skipped(name) { continue; }
+            if !is_part {
+                if let Some(meta) = ConstraintEngine::resolve_meta(name) {
+                    roles.push(Role::Component(meta));
+                }
+            }
+            if let Some(def) = crate::generated::block_definitions::BLOCK_DEFINITIONS_GENERATED.iter().find(|d| d.id == name) {
+                roles.push(Role::Block(def));
+            }
+        }
+        if let Some(layout) = node.attr("data-rs-layout").filter(|l| !self.is_skipped(l)) {
+            if let Some(def) = BlockDefinition::find(layout).or_else(|| BlockDefinition::find(&format!("{}-layout", layout))) {
+                roles.push(Role::Block(def));
+            }
+        }
+        roles
+    }
+}
+
+/// Roots de componente em ordem de documento — mesma regra de marcadores do verifier
+pub fn component_roots(tree: &HtmlTree) -> Vec<(HtmlNodeId, &'static ComponentMeta)> {
+    let verifier = DomVerifier::new();
+    let ids = component_ids();
+    tree.elements()
+        .flat_map(|e| {
+            verifier.roles(tree, e, &ids).into_iter().filter_map(move |role| match role {
+                Role::Component(meta) => Some((e, meta)),
+                Role::Block(_)        => None,
+            })
+        })
+        .collect()
+}
+
+fn component_ids() -> Vec<&'static str> {
+    crate::generated::component_definitions::COMPONENT_DEFINITIONS_GENERATED
+        .iter()
+        .map(|d| d.id)
+        .collect()
+}
+
+/// Atalho: `DomVerifier::new().verify(html)`
+pub fn verify_html(html: &str) -> DomReport {
+    DomVerifier::new().verify(html)
+}
+
+/// (componente dono, part) para cada marcador `data-rs-<id>-<part>=""` — maior prefixo de id vence
+fn parts_of<'a>(
+    attrs: impl Iterator<Item = &'a (String, String)> + 'a,
+    ids: &'a [&'static str],
+) -> impl Iterator<Item = (&'static str, &'a str)> + 'a {
+    attrs
+        .filter(|(_, v)| v.is_empty())
+        .filter_map(|(k, _)| k.strip_prefix("data-rs-"))
+        .filter(|name| !ids.contains(name))
+        .filter_map(move |name| {
+            ids.iter()
+                .filter(|id| name.len() > id.len() && name.starts_with(*id) && name.as_bytes()[id.len()] == b'-')
+                .max_by_key(|id| id.len())
+                .map(|id| (*id, name))
+        })
+}
+
+/// `DialogContent` → `dialog-content`
+fn part_marker(part: &str) -> String {
+    let mut out = String::from("data-rs-");
+    for (i, c) in part.chars().enumerate() {
+        if c.is_uppercase() && i > 0 { out.push('-'); }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+fn check_component(tree: &HtmlTree, root: HtmlNodeId, meta: &ComponentMeta, issues: &mut Vec<DomIssue>) {
+    let node = tree.node(root);
+    let component = meta.id.to_string();
+    let inside = tree.descendants(root);
+
+    if node.attr("data-rs-uid").is_none_or(str::is_empty) {
+        issues.push(DomIssue::new(tree, root, DomViolation::MissingUid { component: component.clone() }));
+    }
+
+    let group = node.attr("data-rs-interaction");
+    match group {
+        Some(g) if !INTERACTION_GROUPS.contains(&g) => issues.push(DomIssue::new(tree, root, DomViolation::UnknownInteraction {
+            component: component.clone(),
+            group: g.into(),
+        })),
+        None if meta.capabilities.iter().any(|c| RUNTIME_CAPABILITIES.contains(c)) => {
+            issues.push(DomIssue::new(tree, root, DomViolation::MissingInteraction { component: component.clone() }));
+        }
+        _ => {}
+    }
+
+    if meta.capabilities.contains(&Capability::OpenClose) {
+        let has_visibility = std::iter::once(root).chain(inside.iter().copied())
+            .any(|e| tree.node(e).has_attr("data-rs-visibility"));
+        if !has_visibility {
+            issues.push(DomIssue::new(tree, root, DomViolation::MissingVisibility { component: component.clone() }));
+        }
+    }
+
+    let portal = group == Some("overlay");
+    for part in meta.required_parts {
+        let marker = part_marker(part);
+        let present = std::iter::once(root).chain(inside.iter().copied())
+            .any(|e| tree.node(e).has_attr(&marker));
+        if !present {
+            issues.push(DomIssue::new(tree, root, DomViolation::MissingPart {
+                component: component.clone(),
+                part: part.to_string(),
+                portal,
+            }));
+        }
+    }
+}
+
+/// Block/layout dono de um elemento `data-rs-region` — o root mais próximo acima dele
+fn region_owner<'a>(tree: &HtmlTree, region: HtmlNodeId, roles: &'a HashMap<HtmlNodeId, Vec<Role>>) -> Option<(HtmlNodeId, &'a BlockDefinition)> {
+    tree.ancestors(region).find_map(|a| {
+        roles.get(&a)?.iter().find_map(|r| match r {
+            Role::Block(def) => Some((a, *def)),
+            Role::Component(_) => None,
+        })
+    })
+}
+
+fn check_block(tree: &HtmlTree, root: HtmlNodeId, def: &BlockDefinition, roles: &HashMap<HtmlNodeId, Vec<Role>>, issues: &mut Vec<DomIssue>) {
+    let own_regions: Vec<(HtmlNodeId, &str)> = tree.descendants(root)
+        .into_iter()
+        .filter_map(|e| tree.node(e).attr("data-rs-region").map(|r| (e, r)))
+        .filter(|(e, _)| region_owner(tree, *e, roles).is_some_and(|(owner, _)| owner == root))
+        .collect();
+
+    for required in def.regions_required {
+        if !own_regions.iter().any(|(_, r)| r == required) {
+            issues.push(DomIssue::new(tree, root, DomViolation::MissingRegion {
+                block_id: def.id.into(),
+                region: required.to_string(),
+            }));
+        }
+    }
+    // Sem regions declaradas no BlockDefinition não há como julgar
+    if def.regions.is_empty() { return; }
+    for (element, region) in own_regions {
+        if def.find_region(region).is_none() {
+            issues.push(DomIssue::new(tree, element, DomViolation::UnknownRegion {
+                block_id: def.id.into(),
+                region: region.into(),
+            }));
+        }
+    }
+}
+
+/// Root logo abaixo de uma region: a region do parent precisa aceitar o filho (catalog region_rules)
+fn check_nesting(tree: &HtmlTree, element: HtmlNodeId, role: &Role, roles: &HashMap<HtmlNodeId, Vec<Role>>, issues: &mut Vec<DomIssue>) {
+    let Some(region) = tree.ancestors(element)
+        .take_while(|a| !roles.contains_key(a))
+        .find(|&a| tree.node(a).has_attr("data-rs-region"))
+    else { return };
+    let Some((_, parent_def)) = region_owner(tree, region, roles) else { return };
+    let region_id = tree.node(region).attr("data-rs-region").unwrap_or_default();
+
+    let parent = catalog_entry(parent_def);
+    let child = match role {
+        Role::Component(meta) => CatalogEntry::find(meta.id),
+        Role::Block(def)      => catalog_entry(def),
+    };
+    let (Some(parent), Some(child)) = (parent, child) else { return };
+    // Parent sem regras de aceitação no catalog — nada a verificar
+    if parent.accepts.is_empty() && parent.region_rules.is_empty() { return; }
+
+    if let ValidationResult::Invalid(violations) = ConstraintEngine::catalog_check_nest_in_region(parent, region_id, child) {
+        for violation in violations {
+            issues.push(DomIssue::new(tree, element, DomViolation::InvalidNesting {
+                parent: parent_def.id.into(),
+                region: region_id.into(),
+                child: child.id.into(),
+                reason: violation.message(),
+            }));
+        }
+    }
+}
+
+fn catalog_entry(def: &BlockDefinition) -> Option<&'static CatalogEntry> {
+    CatalogEntry::find(&format!("block.{}", def.id))
+        .or_else(|| CatalogEntry::find(&format!("layout.{}", def.id)))
+        .filter(|e| matches!(e.kind, CatalogKind::Block | CatalogKind::Layout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(report: &DomReport) -> Vec<&'static str> {
+        report.issues.iter().map(|i| match i.violation {
+            DomViolation::MissingPart { .. }        => "missing_part",
+            DomViolation::OrphanPart { .. }         => "orphan_part",
+            DomViolation::MissingRegion { .. }      => "missing_region",
+            DomViolation::UnknownRegion { .. }      => "unknown_region",
+            DomViolation::MissingUid { .. }         => "missing_uid",
+            DomViolation::DuplicateUid { .. }       => "duplicate_uid",
+            DomViolation::MissingVisibility { .. }  => "missing_visibility",
+            DomViolation::InvalidVisibility { .. }  => "invalid_visibility",
+            DomViolation::MissingInteraction { .. } => "missing_interaction",
+            DomViolation::UnknownInteraction { .. } => "unknown_interaction",
+            DomViolation::InvalidNesting { .. }     => "invalid_nesting",
+        }).collect()
+    }
+
+    #[test]
+    fn test_valid_dialog_markup() {
+        let html = r#"
+            <div data-rs-dialog="" data-rs-interaction="overlay" data-rs-uid="dlg-1" data-rs-visibility="closed">
+                <button type="button" data-rs-dialog-trigger="" data-rs-button="">Open</button>
+                <div data-rs-dialog-content="" data-rs-visibility="closed" role="dialog">
+                    <h2 data-rs-dialog-title="">Title</h2>
+                </div>
+            </div>"#;
+        let report = verify_html(html);
+        assert_eq!(report.roots, 1, "{:?}", report.issues);
+        report.assert_valid();
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_reports_state_and_part_violations() {
+        let html = r#"
+            <div data-rs-dialog="" data-rs-uid="dlg-1" data-rs-visibility="shown">
+                <div data-rs-dialog-content=""></div>
+            </div>
+            <h2 data-rs-dialog-title="">Stray</h2>
+            <button data-rs-button="" data-rs-interaction="init" data-rs-uid="dlg-1">Ok</button>"#;
+        let report = verify_html(html);
+        let found = codes(&report);
+        for code in ["invalid_visibility", "missing_interaction", "missing_part", "orphan_part", "duplicate_uid"] {
+            assert!(found.contains(&code), "{} not in {:?}", code, found);
+        }
+        assert!(!report.is_valid());
+        let json = report.to_json().unwrap();
+        assert!(json.contains("\"code\":\"missing_part\""));
+        assert!(json.contains("\"severity\":\"error\""));
+    }
+}
@@ -0,0 +1,282 @@
+//! HtmlTree — parser tolerante para HTML gerado pelo SSR
+//! Sem dependências: cobre o que o Leptos emite (atributos com aspas, void elements, comentários de hidratação)
+//! Não é um parser HTML5 completo — tags mal fechadas são recuperadas fechando até o ancestral correspondente
+
+/// Índice de um node em `HtmlTree::nodes` — 0 é o documento
+pub type HtmlNodeId = usize;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum HtmlNodeKind {
+    Document,
+    Element,
+    Text,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HtmlNode {
+    pub kind:     HtmlNodeKind,
+    /// Lowercase para elementos, `#text` / `#document` para os demais
+    pub tag:      String,
+    pub attrs:    Vec<(String, String)>,
+    /// Conteúdo de nodes `Text` — vazio nos demais
+    pub text:     String,
+    pub parent:   Option<HtmlNodeId>,
+    pub children: Vec<HtmlNodeId>,
+}
+
+impl HtmlNode {
+    pub fn is_element(&self) -> bool {
+        self.kind == HtmlNodeKind::Element
+    }
+
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    pub fn has_attr(&self, name: &str) -> bool {
+        self.attr(name).is_some()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HtmlTree {
+    pub nodes: Vec<HtmlNode>,
+}
+
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr",
+];
+const RAW_TEXT: &[&str] = &["script", "style", "textarea", "title"];
+
+impl HtmlTree {
+    pub fn parse(html: &str) -> Self {
+        let mut tree = Self {
+            nodes: vec![HtmlNode {
+                kind: HtmlNodeKind::Document,
+                tag: "#document".into(),
+                attrs: vec![],
+                text: String::new(),
+                parent: None,
+                children: vec![],
+            }],
+        };
+        let mut stack: Vec<HtmlNodeId> = vec![0];
+        let mut rest = html;
+
+        while !rest.is_empty() {
+            let current = *stack.last().unwrap_or(&0);
+            if let Some(after) = rest.strip_prefix("<!--") {
+                rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+            } else if rest.starts_with("<!") || rest.starts_with("<?") {
+                rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            } else if let Some(after) = rest.strip_prefix("</") {
+                let end = after.find('>').unwrap_or(after.len());
+                let name = after[..end].trim().to_ascii_lowercase();
+                // Fecha até o ancestral com o mesmo nome — closing tag sem abertura é ignorada
+                if let Some(pos) = stack.iter().rposition(|&id| tree.nodes[id].tag == name) {
+                    stack.truncate(pos.max(1));
+                }
+                rest = after.get(end + 1..).unwrap_or("");
+            } else if rest.starts_with('<') && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                let (tag, attrs, self_closing, after) = parse_tag(&rest[1..]);
+                let id = tree.push(current, HtmlNodeKind::Element, tag.clone(), attrs, String::new());
+                rest = after;
+                if RAW_TEXT.contains(&tag.as_str()) {
+                    let close = format!("</{}", tag);
+                    let end = find_ignore_case(rest, &close).unwrap_or(rest.len());
+                    if end > 0 {
+                        tree.push(id, HtmlNodeKind::Text, "#text".into(), vec![], decode_entities(&rest[..end]));
+                    }
+                    rest = &rest[end..];
+                    rest = rest.find('>').map_or("", |e| &rest[e + 1..]);
+                } else if !self_closing && !VOID.contains(&tag.as_str()) {
+                    stack.push(id);
+                }
+            } else {
+                let first = rest.chars().next().map_or(1, char::len_utf8);
+                let end = rest[first..].find('<').map_or(rest.len(), |e| e + first);
+                let text = decode_entities(&rest[..end]);
+                if !text.trim().is_empty() {
+                    tree.push(current, HtmlNodeKind::Text, "#text".into(), vec![], text);
+                }
+                rest = &rest[end..];
+            }
+        }
+        tree
+    }
+
+    fn push(&mut self, parent: HtmlNodeId, kind: HtmlNodeKind, tag: String, attrs: Vec<(String, String)>, text: String) -> HtmlNodeId {
+        let id = self.nodes.len();
+        self.nodes.push(HtmlNode { kind, tag, attrs, text, parent: Some(parent), children: vec![] });
+        self.nodes[parent].children.push(id);
+        id
+    }
+
+    pub fn node(&self, id: HtmlNodeId) -> &HtmlNode {
+        &self.nodes[id]
+    }
+
+    /// Todos os elementos em ordem de documento
+    pub fn elements(&self) -> impl Iterator<Item = HtmlNodeId> + '_ {
+        self.descendants(0).into_iter()
+    }
+
+    /// Elementos descendentes em ordem de documento (pre-order) — sem o próprio node
+    pub fn descendants(&self, id: HtmlNodeId) -> Vec<HtmlNodeId> {
+        let mut out = vec![];
+        let mut pending: Vec<HtmlNodeId> = self.nodes[id].children.iter().rev().copied().collect();
+        while let Some(next) = pending.pop() {
+            if self.nodes[next].is_element() {
+                out.push(next);
+                pending.extend(self.nodes[next].children.iter().rev());
+            }
+        }
+        out
+    }
+
+    /// Ancestrais elementos, do pai até a raiz
+    pub fn ancestors(&self, id: HtmlNodeId) -> impl Iterator<Item = HtmlNodeId> + '_ {
+        std::iter::successors(self.nodes[id].parent, move |&p| self.nodes[p].parent)
+            .filter(move |&p| self.nodes[p].is_element())
+    }
+
+    /// Texto concatenado dos descendentes, com espaços normalizados
+    pub fn text_content(&self, id: HtmlNodeId) -> String {
+        let mut out = String::new();
+        self.collect_text(id, &mut out);
+        out.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn collect_text(&self, id: HtmlNodeId, out: &mut String) {
+        for &child in &self.nodes[id].children {
+            match self.nodes[child].kind {
+                HtmlNodeKind::Text => { out.push(' '); out.push_str(&self.nodes[child].text); }
+                _ => self.collect_text(child, out),
+            }
+        }
+    }
+
+    /// Elemento com `id` igual — primeiro em ordem de documento
+    pub fn find_by_id(&self, element_id: &str) -> Option<HtmlNodeId> {
+        self.elements().find(|&e| self.nodes[e].attr("id") == Some(element_id))
+    }
+
+    /// Caminho legível para relatórios: `body > main > div[data-rs-dialog] > h2`
+    pub fn path(&self, id: HtmlNodeId) -> String {
+        let mut segments: Vec<String> = self.ancestors(id).map(|a| self.describe(a)).collect();
+        segments.reverse();
+        segments.push(self.describe(id));
+        segments.join(" > ")
+    }
+
+    /// `tag#id`, `tag[data-rs-x]` (primeiro marcador data-rs-*) ou só `tag`
+    pub fn describe(&self, id: HtmlNodeId) -> String {
+        let node = &self.nodes[id];
+        if let Some(element_id) = node.attr("id").filter(|v| !v.is_empty()) {
+            return format!("{}#{}", node.tag, element_id);
+        }
+        match node.attrs.iter().find(|(k, v)| k.starts_with("data-rs-") && v.is_empty()) {
+            Some((k, _)) => format!("{}[{}]", node.tag, k),
+            None         => node.tag.clone(),
+        }
+    }
+}
+
+/// Lê `tag attr="v" ...>` — retorna (tag, attrs, self-closing, resto após `>`)
+fn parse_tag(input: &str) -> (String, Vec<(String, String)>, bool, &str) {
+    let name_end = input.find(|c: char| c.is_whitespace() || c == '>' || c == '/').unwrap_or(input.len());
+    let tag = input[..name_end].to_ascii_lowercase();
+    let mut rest = &input[name_end..];
+    let mut attrs = vec![];
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("/>") {
+            return (tag, attrs, true, after);
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            return (tag, attrs, false, after);
+        }
+        if let Some(after) = rest.strip_prefix('/') {
+            rest = after;
+            continue;
+        }
+        if rest.is_empty() {
+            return (tag, attrs, false, rest);
+        }
+        let key_end = rest.find(|c: char| c.is_whitespace() || c == '=' || c == '>' || c == '/').unwrap_or(rest.len());
+        let key = rest[..key_end].to_ascii_lowercase();
+        rest = rest[key_end..].trim_start();
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (raw, remaining) = match after.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    let body = &after[1..];
+                    let end = body.find(q).unwrap_or(body.len());
+                    (&body[..end], body.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after.find(|c: char| c.is_whitespace() || c == '>').unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                }
+            };
+            value = decode_entities(raw);
+            rest = remaining;
+        }
+        if !key.is_empty() {
+            attrs.push((key, value));
+        }
+    }
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack.to_ascii_lowercase().find(&needle.to_ascii_lowercase())
+}
+
+fn decode_entities(s: &str) -> String {
+    if !s.contains('&') {
+        return s.to_string();
+    }
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_leptos_markup() {
+        let html = r#"<!DOCTYPE html><html><body><!--hk=0-->
+            <div data-rs-dialog="" data-rs-uid="dlg-1" class="a &amp; b">
+                <input type="text" disabled><br/>
+                <h2 data-rs-dialog-title="">Hello <b>world</b></h2>
+                <script>if (a < b) {}</script>
+            </div></body></html>"#;
+        let tree = HtmlTree::parse(html);
+        let dialog = tree.elements().find(|&e| tree.node(e).has_attr("data-rs-dialog")).unwrap();
+        assert_eq!(tree.node(dialog).attr("class"), Some("a & b"));
+        assert_eq!(tree.node(dialog).attr("data-rs-uid"), Some("dlg-1"));
+
+        let title = tree.elements().find(|&e| tree.node(e).tag == "h2").unwrap();
+        assert_eq!(tree.text_content(title), "Hello world");
+        assert!(tree.ancestors(title).any(|a| a == dialog));
+        assert_eq!(tree.path(title), "html > body > div[data-rs-dialog] > h2[data-rs-dialog-title]");
+
+        let tags: Vec<&str> = tree.descendants(dialog).iter().map(|&e| tree.node(e).tag.as_str()).collect();
+        assert_eq!(tags, ["input", "br", "h2", "b", "script"]);
+    }
+
+    #[test]
+    fn test_recovers_from_unclosed_tags() {
+        let tree = HtmlTree::parse("<ul><li>one<li>two</ul><p id=x>after</p>");
+        let p = tree.find_by_id("x").unwrap();
+        assert_eq!(tree.ancestors(p).count(), 0);
+        assert_eq!(tree.text_content(p), "after");
+    }
+}
@@ -0,0 +1,292 @@
+//! Hydration check — detecta divergência SSR × hydrate na sequência de UIDs e nos atributos de estado
+//! Só em debug: o SSR embute um manifest compacto, o hydrate compara com a própria sequência
+//! O estado SSR é gravado no servidor sobre o HTML final (stamp_ssr_state) — o client compara o DOM contra ele
+//! O componente que emite/compara vive em infra::uid (UidRoot) — aqui só o manifest puro
+//! Reporta só o primeiro componente divergente (prefixo + posição) — os seguintes são consequência
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::infra::html_tree::HtmlTree;
+
+/// `<script type="application/json" id=...>` emitido pelo UidRoot em debug
+pub const MANIFEST_ID: &str = "rs-hydration-manifest";
+
+/// Marca no `<script>` do manifest que o servidor gravou o estado — ausente = só UIDs comparáveis
+pub const STAMPED_ATTR: &str = "data-rs-ssr-state";
+
+/// Atributos dos domínios de estado (ToDataAttr)
+pub const STATE_ATTRS: &[&str] = &[
+    "data-rs-visibility", "data-rs-activity", "data-rs-selection", "data-rs-toggle",
+    "data-rs-navigation", "data-rs-disabled", "data-rs-loading", "data-rs-invalid",
+    "data-rs-readonly", "data-rs-required", "data-rs-expanded", "data-rs-checked",
+];
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct HydrationEntry {
+    pub uid:   String,
+    /// (atributo sem `data-rs-`, valor) — só os presentes
+    pub state: Vec<(String, String)>,
+}
+
+impl HydrationEntry {
+    /// `btn-0000000a` / `btn-k3j2.0000000a` → `btn`
+    pub fn prefix(&self) -> &str {
+        uid_prefix(&self.uid)
+    }
+}
+
+pub fn uid_prefix(uid: &str) -> &str {
+    uid.rsplit_once('-').map_or(uid, |(prefix, _)| prefix)
+}
+
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct HydrationManifest {
+    pub entries: Vec<HydrationEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MismatchKind {
+    /// SSR e hydrate geraram UIDs diferentes na mesma posição
+    Uid,
+    /// SSR gerou mais UIDs — componente não renderizado no client
+    MissingOnClient,
+    /// Hydrate gerou UID sem correspondente no SSR
+    ExtraOnClient,
+    State { attr: String },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HydrationMismatch {
+    /// Índice na sequência de UIDs
+    pub position: usize,
+    pub prefix:   String,
+    pub kind:     MismatchKind,
+    pub ssr:      Option<String>,
+    pub client:   Option<String>,
+}
+
+impl fmt::Display for HydrationMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ssr = self.ssr.as_deref().unwrap_or("(none)");
+        let client = self.client.as_deref().unwrap_or("(none)");
+        match &self.kind {
+            MismatchKind::Uid =>
+                write!(f, "#{} '{}': SSR generated '{}' but hydrate generated '{}'", self.position, self.prefix, ssr, client),
+            MismatchKind::MissingOnClient =>
+                write!(f, "#{} '{}': SSR generated '{}' but hydrate never reached it", self.position, self.prefix, ssr),
+            MismatchKind::ExtraOnClient =>
+                write!(f, "#{} '{}': hydrate generated '{}' with no SSR counterpart", self.position, self.prefix, client),
+            MismatchKind::State { attr } =>
+                write!(f, "#{} '{}': data-rs-{} was '{}' in SSR but '{}' after hydrate", self.position, self.prefix, attr, ssr, client),
+        }
+    }
+}
+
+impl HydrationManifest {
+    pub fn from_uids(uids: impl IntoIterator<Item = String>) -> Self {
+        Self { entries: uids.into_iter().map(|uid| HydrationEntry { uid, state: vec![] }).collect() }
+    }
+
+    /// Preenche o estado de cada UID — `lookup(uid, attr)` lê o atributo do elemento
+    pub fn with_state(mut self, lookup: impl Fn(&str, &str) -> Option<String>) -> Self {
+        for entry in &mut self.entries {
+            entry.state = STATE_ATTRS
+                .iter()
+                .filter_map(|attr| lookup(&entry.uid, attr).map(|v| (attr["data-rs-".len()..].to_string(), v)))
+                .collect();
+        }
+        self
+    }
+
+    /// Estado lido do HTML renderizado — UIDs sem elemento ficam sem estado
+    pub fn with_state_from_html(self, tree: &HtmlTree) -> Self {
+        let by_uid: HashMap<&str, usize> = tree.elements()
+            .filter_map(|e| tree.node(e).attr("data-rs-uid").map(|uid| (uid, e)))
+            .collect();
+        self.with_state(|uid, attr| by_uid.get(uid).and_then(|&e| tree.node(e).attr(attr)).map(str::to_string))
+    }
+
+    /// Estado gravado no SSR — sem ele o hydrate só compara a sequência de UIDs
+    pub fn has_state(&self) -> bool {
+        self.entries.iter().any(|e| !e.state.is_empty())
+    }
+
+    /// JSON compacto: `["btn-00000000|toggle=on,disabled=disabled", ...]`
+    pub fn encode(&self) -> String {
+        let items: Vec<String> = self.entries.iter().map(|e| {
+            let state: Vec<String> = e.state.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+            if state.is_empty() { e.uid.clone() } else { format!("{}|{}", e.uid, state.join(",")) }
+        }).collect();
+        serde_json::to_string(&items).unwrap_or_else(|_| "[]".into())
+    }
+
+    pub fn decode(raw: &str) -> Result<Self, serde_json::Error> {
+        let items: Vec<String> = serde_json::from_str(raw)?;
+        let entries = items.into_iter().map(|item| {
+            let (uid, state) = item.split_once('|').unwrap_or((&item, ""));
+            HydrationEntry {
+                uid: uid.to_string(),
+                state: state.split(',')
+                    .filter_map(|kv| kv.split_once('='))
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            }
+        }).collect();
+        Ok(Self { entries })
+    }
+
+    /// Primeira divergência em ordem de render — UID antes de estado
+    /// Cada namespace de UidScope é comparado isolado: scopes resolvidos fora de ordem não divergem
+    pub fn first_mismatch(&self, client: &Self) -> Option<HydrationMismatch> {
+        let ssr_groups = group_by_namespace(&self.entries);
+        let client_groups = group_by_namespace(&client.entries);
+        let namespaces = ssr_groups.iter().chain(&client_groups).map(|(ns, _)| *ns);
+        let mut seen = vec![];
+        namespaces
+            .filter(|ns| if seen.contains(ns) { false } else { seen.push(*ns); true })
+            .filter_map(|ns| compare_sequences(group_of(&ssr_groups, ns), group_of(&client_groups, ns)))
+            .min_by_key(|m| m.position)
+    }
+}
+
+/// SSR: grava no manifest embutido o estado `data-rs-*` do HTML renderizado
+/// O UidRoot só conhece a sequência de UIDs — o estado sai do HTML final, antes de chegar ao browser
+/// Marca o script com STAMPED_ATTR — mesmo sem nenhum `data-rs-*` o client sabe que o estado foi gravado
+/// None se o HTML não tem manifest (release, página sem UidRoot)
+pub fn stamp_ssr_state(html: &str, tree: &HtmlTree) -> Option<String> {
+    let script = tree.find_by_id(MANIFEST_ID)?;
+    let manifest = HydrationManifest::decode(&tree.text_content(script)).ok()?.with_state_from_html(tree);
+    let open = html.find(&format!("id=\"{}\"", MANIFEST_ID))?;
+    let close = open + html[open..].find('>')?;
+    let end = close + html[close..].find("</script>")?;
+    Some(format!("{} {}=\"\">{}{}", &html[..close], STAMPED_ATTR, manifest.encode(), &html[end..]))
+}
+
+/// `btn-k3j2.0000000a` → `Some("k3j2")`; UIDs do root → None
+pub fn uid_namespace(uid: &str) -> Option<&str> {
+    let (_, rest) = uid.rsplit_once('-')?;
+    rest.split_once('.').map(|(ns, _)| ns)
+}
+
+type Group<'a> = (Option<&'a str>, Vec<(usize, &'a HydrationEntry)>);
+
+fn group_by_namespace(entries: &[HydrationEntry]) -> Vec<Group<'_>> {
+    let mut groups: Vec<Group<'_>> = vec![];
+    for (position, entry) in entries.iter().enumerate() {
+        let ns = uid_namespace(&entry.uid);
+        match groups.iter_mut().find(|(n, _)| *n == ns) {
+            Some((_, group)) => group.push((position, entry)),
+            None             => groups.push((ns, vec![(position, entry)])),
+        }
+    }
+    groups
+}
+
+fn group_of<'g, 'a>(groups: &'g [Group<'a>], ns: Option<&str>) -> &'g [(usize, &'a HydrationEntry)] {
+    groups.iter().find(|(n, _)| *n == ns).map_or(&[], |(_, g)| g.as_slice())
+}
+
+/// Compara uma sequência (root ou um scope) — `position` é o índice global
+fn compare_sequences(ssr: &[(usize, &HydrationEntry)], client: &[(usize, &HydrationEntry)]) -> Option<HydrationMismatch> {
+    for index in 0..ssr.len().max(client.len()) {
+        let (s, c) = (ssr.get(index), client.get(index));
+        let position = s.or(c).map_or(0, |(p, _)| *p);
+        let mismatch = |prefix: &str, kind, ssr: Option<&str>, client: Option<&str>| HydrationMismatch {
+            position,
+            prefix: prefix.to_string(),
+            kind,
+            ssr: ssr.map(str::to_string),
+            client: client.map(str::to_string),
+        };
+        match (s.map(|(_, e)| *e), c.map(|(_, e)| *e)) {
+            (Some(s), None) =>
+                return Some(mismatch(s.prefix(), MismatchKind::MissingOnClient, Some(&s.uid), None)),
+            (None, Some(c)) =>
+                return Some(mismatch(c.prefix(), MismatchKind::ExtraOnClient, None, Some(&c.uid))),
+            (Some(s), Some(c)) if s.uid != c.uid =>
+                return Some(mismatch(s.prefix(), MismatchKind::Uid, Some(&s.uid), Some(&c.uid))),
+            (Some(s), Some(c)) => {
+                let get = |state: &[(String, String)], attr: &str| state.iter().find(|(k, _)| k == attr).map(|(_, v)| v.clone());
+                for attr in s.state.iter().chain(&c.state).map(|(k, _)| k.as_str()) {
+                    let (before, after) = (get(&s.state, attr), get(&c.state, attr));
+                    if before != after {
+                        let kind = MismatchKind::State { attr: attr.to_string() };
+                        return Some(mismatch(s.prefix(), kind, before.as_deref(), after.as_deref()));
+                    }
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_roundtrip_with_html_state() {
+        let tree = HtmlTree::parse(r#"<button data-rs-uid="btn-00000000" data-rs-toggle="on"></button><div data-rs-uid="dlg-00000001" data-rs-visibility="closed"></div>"#);
+        let manifest = HydrationManifest::from_uids(["btn-00000000".to_string(), "dlg-00000001".to_string()]).with_state_from_html(&tree);
+        let encoded = manifest.encode();
+        assert_eq!(encoded, r#"["btn-00000000|toggle=on","dlg-00000001|visibility=closed"]"#);
+        assert_eq!(HydrationManifest::decode(&encoded).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_stamp_ssr_state_records_rendered_attrs() {
+        let html = r#"<main><button data-rs-uid="btn-00000000" data-rs-toggle="on"></button><input data-rs-uid="inp-00000001" data-rs-invalid="invalid"></main><script type="application/json" id="rs-hydration-manifest">["btn-00000000","inp-00000001"]</script>"#;
+        let stamped = stamp_ssr_state(html, &HtmlTree::parse(html)).unwrap();
+        assert!(stamped.starts_with("<main>"));
+        assert!(stamped.ends_with(r#"id="rs-hydration-manifest" data-rs-ssr-state="">["btn-00000000|toggle=on","inp-00000001|invalid=invalid"]</script>"#));
+
+        let tree = HtmlTree::parse(&stamped);
+        let script = tree.find_by_id(MANIFEST_ID).unwrap();
+        assert_eq!(tree.node(script).attr(STAMPED_ATTR), Some(""));
+        let ssr = HydrationManifest::decode(&tree.text_content(script)).unwrap();
+        assert!(ssr.has_state());
+        // client com o DOM já mutado pelo hydrate
+        let client = HydrationManifest::decode(r#"["btn-00000000|toggle=off","inp-00000001|invalid=invalid"]"#).unwrap();
+        assert_eq!(ssr.first_mismatch(&client).unwrap().kind, MismatchKind::State { attr: "toggle".into() });
+
+        assert!(stamp_ssr_state("<main></main>", &HtmlTree::parse("<main></main>")).is_none());
+        // Página sem nenhum estado ainda sai marcada
+        let bare = r#"<p data-rs-uid="p-00000000"></p><script type="application/json" id="rs-hydration-manifest">["p-00000000"]</script>"#;
+        assert!(stamp_ssr_state(bare, &HtmlTree::parse(bare)).unwrap().contains(r#"data-rs-ssr-state="">["p-00000000"]"#));
+        assert!(!HydrationManifest::from_uids(["btn-00000000".to_string()]).has_state());
+    }
+
+    #[test]
+    fn test_first_mismatch_reports_prefix_and_position() {
+        let ssr = HydrationManifest::decode(r#"["btn-00000000","sw-00000001|toggle=off","dlg-00000002"]"#).unwrap();
+
+        let shifted = HydrationManifest::decode(r#"["btn-00000000","dlg-00000001"]"#).unwrap();
+        let m = ssr.first_mismatch(&shifted).unwrap();
+        assert_eq!((m.position, m.prefix.as_str(), &m.kind), (1, "sw", &MismatchKind::Uid));
+
+        let toggled = HydrationManifest::decode(r#"["btn-00000000","sw-00000001|toggle=on","dlg-00000002"]"#).unwrap();
+        let m = ssr.first_mismatch(&toggled).unwrap();
+        assert_eq!(m.kind, MismatchKind::State { attr: "toggle".into() });
+        assert_eq!(m.to_string(), "#1 'sw': data-rs-toggle was 'off' in SSR but 'on' after hydrate");
+
+        let truncated = HydrationManifest::decode(r#"["btn-00000000","sw-00000001|toggle=off"]"#).unwrap();
+        assert_eq!(ssr.first_mismatch(&truncated).unwrap().kind, MismatchKind::MissingOnClient);
+        assert!(ssr.first_mismatch(&ssr).is_none());
+    }
+
+    #[test]
+    fn test_scopes_compared_independently() {
+        assert_eq!(uid_namespace("btn-k3j2.0000000a"), Some("k3j2"));
+        assert_eq!(uid_prefix("btn-k3j2.0000000a"), "btn");
+        // Suspense resolveu "b" antes de "a" no client — cada scope continua igual
+        let ssr = HydrationManifest::decode(r#"["nav-00000000","btn-aa.00000000","btn-bb.00000000","ft-00000001"]"#).unwrap();
+        let client = HydrationManifest::decode(r#"["nav-00000000","btn-bb.00000000","btn-aa.00000000","ft-00000001"]"#).unwrap();
+        assert!(ssr.first_mismatch(&client).is_none());
+
+        let drifted = HydrationManifest::decode(r#"["nav-00000000","btn-bb.00000000","ft-00000001"]"#).unwrap();
+        let m = ssr.first_mismatch(&drifted).unwrap();
+        assert_eq!((m.position, m.kind), (1, MismatchKind::MissingOnClient));
+    }
+}
@@ -1,12 +1,20 @@
 //! infra — design, state_engine, behavior
 
 pub mod theme;
+pub mod density;
 pub mod dom_contract;
 pub mod constraint_engine;
 pub use constraint_engine::ConstraintEngine;
 
 pub mod uid;
+pub mod hydration;
 
 pub mod validate;
 
+pub mod html_tree;
+pub mod dom_verify;
+pub use dom_verify::{verify_html, DomReport, DomVerifier};
+pub mod aria_audit;
+pub use aria_audit::{aria_audit, AriaAudit, AriaReport};
+
 pub mod slot;
@@ -1,5 +1,12 @@
 pub mod theme_provider;
 pub mod theme_types;
+pub mod theme_registry;
+pub mod theme_scope;
 
-pub use theme_types::{ThemeContext, ThemeMode};
+pub use theme_types::{
+    ThemeContext, ThemeMode, theme_cookie, theme_from_cookies,
+    THEME_COOKIE_ATTRS, THEME_MODE_COOKIE, THEME_PRESET_COOKIE,
+};
+pub use theme_registry::{ThemePreset, ThemeRegistry, ThemeSwatches};
 pub use theme_provider::{ThemeProvider, CanonRSRoot, use_theme, canonrs_theme_script};
+pub use theme_scope::ThemeScope;
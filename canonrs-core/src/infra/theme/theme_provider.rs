@@ -1,42 +1,83 @@
 use leptos::prelude::*;
-use super::theme_types::{ThemeContext, ThemeMode};
+use super::theme_registry::ThemeRegistry;
+use super::theme_types::{ThemeContext, ThemeMode, THEME_MODE_COOKIE, THEME_PRESET_COOKIE};
 use crate::infra::uid::UidRoot;
 
+/// Tema do documento — `data-theme` e `.dark` no <html>, persistidos nos cookies `theme-mode`/`theme-preset` (Canon rule 32)
+/// No SSR a app lê o header `Cookie` (`theme_from_cookies`) e passa `mode`/`preset`; na hidratação vale o cookie
 #[component]
-pub fn ThemeProvider(children: Children) -> impl IntoView {
-    let mode = RwSignal::new(ThemeMode::Dark);
-    let preset = RwSignal::new("canonrs".to_string());
+pub fn ThemeProvider(
+    /// Modo inicial — None: Dark
+    #[prop(into, default = None)] mode: Option<ThemeMode>,
+    /// Preset inicial — None: default do ThemeRegistry
+    #[prop(into, default = None)] preset: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    #[cfg(feature = "hydrate")]
+    let (mode, preset) = {
+        let (saved_mode, saved_preset) = super::theme_types::theme_from_cookies(&document_cookie());
+        (saved_mode.or(mode), saved_preset.or(preset))
+    };
+    let mode = RwSignal::new(mode.unwrap_or(ThemeMode::Dark));
+    let preset = RwSignal::new(preset.unwrap_or_else(|| ThemeRegistry::default_preset().to_string()));
+    let prefers_dark = RwSignal::new(false);
 
-    provide_context(ThemeContext { mode, preset });
+    provide_context(ThemeContext { mode, preset, prefers_dark });
 
     #[cfg(feature = "hydrate")]
     {
         use leptos::leptos_dom::helpers::document;
+        use leptos::wasm_bindgen::JsCast;
+        use super::theme_types::theme_cookie;
+
+        Effect::new(move |_| prefers_dark.set(system_prefers_dark()));
 
         Effect::new(move |_| {
+            let current_mode = mode.get();
+            let current_preset = preset.get();
             if let Some(html) = document().document_element() {
-                match mode.get() {
-                    ThemeMode::Dark => {
-                        let _ = html.class_list().add_1("dark");
-                    }
-                    ThemeMode::Light | ThemeMode::System => {
-                        let _ = html.class_list().remove_1("dark");
-                    }
-                }
+                let _ = html.set_attribute("data-theme", &current_preset);
+                let _ = html.class_list().toggle_with_force("dark", current_mode.resolve(prefers_dark.get()) == ThemeMode::Dark);
             }
+            let html_document = document().unchecked_into::<web_sys::HtmlDocument>();
+            let _ = html_document.set_cookie(&theme_cookie(THEME_MODE_COOKIE, current_mode.as_str()));
+            let _ = html_document.set_cookie(&theme_cookie(THEME_PRESET_COOKIE, &current_preset));
         });
     }
 
     children()
 }
 
+#[cfg(feature = "hydrate")]
+fn document_cookie() -> String {
+    use leptos::wasm_bindgen::JsCast;
+    leptos::leptos_dom::helpers::document()
+        .unchecked_into::<web_sys::HtmlDocument>()
+        .cookie()
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "hydrate")]
+pub(crate) fn system_prefers_dark() -> bool {
+    leptos::leptos_dom::helpers::window()
+        .match_media("(prefers-color-scheme: dark)")
+        .ok()
+        .flatten()
+        .map(|query| query.matches())
+        .unwrap_or(false)
+}
+
+/// `theme` (antes `_theme`, ignorado) e `mode` são os iniciais do ThemeProvider — no SSR, os cookies do request
 #[component]
 pub fn CanonRSRoot(
-    #[prop(default = "canonrs".to_string())] _theme: String,
+    /// Preset inicial (`data-theme`) — default do ThemeRegistry
+    #[prop(into, default = None)] theme: Option<String>,
+    /// Modo inicial — default Dark
+    #[prop(into, default = None)] mode: Option<ThemeMode>,
     children: Children,
 ) -> impl IntoView {
     view! {
-        <ThemeProvider>
+        <ThemeProvider mode=mode preset=theme>
             <UidRoot>
                 {children()}
             </UidRoot>
@@ -49,9 +90,15 @@ pub fn use_theme() -> ThemeContext {
         .expect("use_theme must be used within CanonRSRoot")
 }
 
+/// Script inline para o <head> — aplica os cookies `theme-mode`/`theme-preset` antes da hidratação (sem flash)
+/// `theme` vale quando não há cookie ou o preset salvo não existe mais no registry
 pub fn canonrs_theme_script(theme: &str) -> String {
+    let presets: Vec<&str> = ThemeRegistry::available_presets().iter().map(|p| p.id).collect();
     format!(
-        r#"(function(){{const r=document.documentElement;const s=localStorage.getItem("{}-theme");const m=s==="dark"||s==="light"?s:window.matchMedia("(prefers-color-scheme: dark)").matches?"dark":"light";r.setAttribute("data-theme","{}");if(m==="dark"){{r.classList.add("dark")}}else{{r.classList.remove("dark")}}}})();"#,
-        theme, theme
+        r#"(function(){{const r=document.documentElement;const k={presets:?};const c=n=>{{const v=("; "+document.cookie).split("; "+n+"=");return v.length===2?v.pop().split(";").shift():null}};const s=c("{mode_cookie}"),p=c("{preset_cookie}");const m=s==="dark"||s==="light"?s:window.matchMedia("(prefers-color-scheme: dark)").matches?"dark":"light";r.setAttribute("data-theme",k.includes(p)?p:"{theme}");if(m==="dark"){{r.classList.add("dark")}}else{{r.classList.remove("dark")}}}})();"#,
+        presets = presets,
+        mode_cookie = THEME_MODE_COOKIE,
+        preset_cookie = THEME_PRESET_COOKIE,
+        theme = theme,
     )
 }
@@ -0,0 +1,67 @@
+use crate::generated::theme_presets::{DEFAULT_THEME_PRESET, THEME_PRESETS};
+
+/// Cores representativas de um modo — CSS (`hsl(...)`), vazio quando o tema não define
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThemeSwatches {
+    pub background: &'static str,
+    pub foreground: &'static str,
+    pub primary: &'static str,
+    pub secondary: &'static str,
+    pub accent: &'static str,
+}
+
+/// Preset de tema — gerado pelo tokens-engine a partir de canonrs-tokens/themes/ingest
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThemePreset {
+    /// Valor de `data-theme`
+    pub id: &'static str,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub has_light: bool,
+    pub has_dark: bool,
+    /// Ingest sem `.dark` — o dark foi derivado do light pelo engine
+    pub dark_synthesized: bool,
+    pub light: ThemeSwatches,
+    pub dark: ThemeSwatches,
+}
+
+/// Registry de presets — SSR e client leem a mesma lista
+pub struct ThemeRegistry;
+
+impl ThemeRegistry {
+    pub fn available_presets() -> Vec<ThemePreset> {
+        THEME_PRESETS.to_vec()
+    }
+
+    pub fn default_preset() -> &'static str {
+        DEFAULT_THEME_PRESET
+    }
+
+    pub fn get_preset(id: &str) -> Option<ThemePreset> {
+        THEME_PRESETS.iter().find(|p| p.id == id).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn default_preset_is_registered() {
+        assert!(ThemeRegistry::get_preset(ThemeRegistry::default_preset()).is_some());
+    }
+
+    #[test]
+    fn preset_ids_are_unique() {
+        let ids: HashSet<_> = ThemeRegistry::available_presets().iter().map(|p| p.id).collect();
+        assert_eq!(ids.len(), ThemeRegistry::available_presets().len());
+    }
+
+    #[test]
+    fn every_preset_has_a_primary_swatch() {
+        for preset in ThemeRegistry::available_presets() {
+            assert!(!preset.light.primary.is_empty(), "{} has no light primary", preset.id);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use leptos::prelude::*;
+use super::theme_registry::ThemeRegistry;
+use super::theme_types::{ThemeContext, ThemeMode};
+
+/// Preset e/ou modo próprios numa subárvore — `data-theme` + `.dark` no wrapper
+/// Semantic, families e root são declarados em `[data-theme]`: resolvem de novo no wrapper, sem CSS extra
+/// O que não for passado segue o ThemeContext de cima (mesmo signal: trocar dentro do scope troca lá)
+#[component]
+pub fn ThemeScope(
+    #[prop(into, default = None)] preset: Option<String>,
+    #[prop(into, default = None)] mode: Option<ThemeMode>,
+    children: Children,
+) -> impl IntoView {
+    let parent = use_context::<ThemeContext>();
+
+    let preset = match (preset, parent) {
+        (Some(id), _)        => RwSignal::new(id),
+        (None, Some(parent)) => parent.preset,
+        (None, None)         => RwSignal::new(ThemeRegistry::default_preset().to_string()),
+    };
+    let mode = match (mode, parent) {
+        (Some(mode), _)      => RwSignal::new(mode),
+        (None, Some(parent)) => parent.mode,
+        (None, None)         => RwSignal::new(ThemeMode::Dark),
+    };
+    let prefers_dark = match parent {
+        Some(parent) => parent.prefers_dark,
+        None => {
+            let prefers_dark = RwSignal::new(false);
+            #[cfg(feature = "hydrate")]
+            Effect::new(move |_| prefers_dark.set(super::theme_provider::system_prefers_dark()));
+            prefers_dark
+        }
+    };
+
+    let theme = ThemeContext { mode, preset, prefers_dark };
+    provide_context(theme);
+
+    view! {
+        <div
+            data-theme=move || preset.get()
+            class:dark=move || theme.is_dark()
+            style="display: contents"
+        >
+            {children()}
+        </div>
+    }
+}
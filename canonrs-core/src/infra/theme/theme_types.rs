@@ -1,4 +1,31 @@
 use leptos::prelude::*;
+use super::theme_registry::ThemeRegistry;
+
+/// Cookies do tema — Canon rule 32: lidos no SSR e pelo script do <head>, gravados pelo ThemeProvider
+pub const THEME_MODE_COOKIE: &str = "theme-mode";
+pub const THEME_PRESET_COOKIE: &str = "theme-preset";
+pub const THEME_COOKIE_ATTRS: &str = "Path=/; Max-Age=31536000; SameSite=Lax";
+
+/// Modo e preset de um header `Cookie` (SSR) ou de `document.cookie`
+/// Valores inválidos viram None — preset fora do ThemeRegistry também
+pub fn theme_from_cookies(cookies: &str) -> (Option<ThemeMode>, Option<String>) {
+    let mut mode = None;
+    let mut preset = None;
+    for pair in cookies.split(';') {
+        let Some((name, value)) = pair.trim().split_once('=') else { continue };
+        match name {
+            THEME_MODE_COOKIE => mode = ThemeMode::parse(value),
+            THEME_PRESET_COOKIE if ThemeRegistry::get_preset(value).is_some() => preset = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    (mode, preset)
+}
+
+/// `theme-mode=dark; Path=/; ...` — valor para `document.cookie` ou `Set-Cookie`
+pub fn theme_cookie(name: &str, value: &str) -> String {
+    format!("{}={}; {}", name, value, THEME_COOKIE_ATTRS)
+}
 
 /// Theme mode enum
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -8,24 +35,97 @@ pub enum ThemeMode {
     System,
 }
 
-/// Theme context
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [Self::Light, Self::Dark, Self::System];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Light  => "light",
+            Self::Dark   => "dark",
+            Self::System => "system",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "light"  => Some(Self::Light),
+            "dark"   => Some(Self::Dark),
+            "system" => Some(Self::System),
+            _        => None,
+        }
+    }
+
+    /// System → Light/Dark conforme `prefers-color-scheme`
+    pub fn resolve(self, prefers_dark: bool) -> Self {
+        match self {
+            Self::System if prefers_dark => Self::Dark,
+            Self::System => Self::Light,
+            other => other,
+        }
+    }
+}
+
+/// Theme context — o mais próximo vence (ThemeScope aninhado)
 #[derive(Clone, Copy)]
 pub struct ThemeContext {
     pub mode: RwSignal<ThemeMode>,
+    /// Id do preset (`data-theme`) — ver ThemeRegistry
     pub preset: RwSignal<String>,
+    /// `prefers-color-scheme: dark` — lido na hidratação, false no SSR
+    pub prefers_dark: RwSignal<bool>,
 }
 
 impl ThemeContext {
     pub fn is_dark(&self) -> bool {
-        self.mode.get() == ThemeMode::Dark
+        self.mode.get().resolve(self.prefers_dark.get()) == ThemeMode::Dark
     }
 
     pub fn toggle(&self) {
-        self.mode.update(|mode| {
-            *mode = match *mode {
-                ThemeMode::Dark => ThemeMode::Light,
-                _ => ThemeMode::Dark,
-            };
-        });
+        let mode = if self.is_dark() { ThemeMode::Light } else { ThemeMode::Dark };
+        self.mode.set(mode);
+    }
+
+    pub fn set_mode(&self, mode: ThemeMode) {
+        self.mode.set(mode);
+    }
+
+    /// Troca o preset — ids fora do registry são ignorados (sem CSS, a página ficaria sem tokens)
+    pub fn set_preset(&self, id: &str) -> bool {
+        if ThemeRegistry::get_preset(id).is_none() {
+            return false;
+        }
+        self.preset.set(id.to_string());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_mode() {
+        for mode in ThemeMode::ALL {
+            assert_eq!(ThemeMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(ThemeMode::parse("sepia"), None);
+    }
+
+    #[test]
+    fn reads_theme_cookies() {
+        let preset = ThemeRegistry::default_preset();
+        let header = format!("session=abc; {}=dark; {}={}", THEME_MODE_COOKIE, THEME_PRESET_COOKIE, preset);
+        assert_eq!(theme_from_cookies(&header), (Some(ThemeMode::Dark), Some(preset.to_string())));
+        assert_eq!(theme_from_cookies("theme-mode=sepia; theme-preset=missing"), (None, None));
+        assert_eq!(theme_from_cookies(""), (None, None));
+        assert_eq!(theme_cookie(THEME_MODE_COOKIE, "light"), "theme-mode=light; Path=/; Max-Age=31536000; SameSite=Lax");
+    }
+
+    #[test]
+    fn system_follows_preference() {
+        assert_eq!(ThemeMode::System.resolve(true), ThemeMode::Dark);
+        assert_eq!(ThemeMode::System.resolve(false), ThemeMode::Light);
+        assert_eq!(ThemeMode::Light.resolve(true), ThemeMode::Light);
+        assert_eq!(ThemeMode::Dark.resolve(false), ThemeMode::Dark);
     }
 }
@@ -2,31 +2,297 @@
 //! CR-414: determinístico entre SSR e hydrate via Leptos context
 //! O UidRoot provê um contador por árvore de renderização
 //! Mesmo ordem SSR e WASM → mesmo UID
+//! Em debug a sequência gerada é registrada — ver infra::hydration
+//! UidScope isola subárvores (Suspense, islands, fragmentos em streaming): namespace derivado de uma key,
+//! contador próprio — a ordem dos irmãos deixa de deslocar os UIDs de dentro
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(debug_assertions)]
+use std::collections::{HashMap, HashSet};
+#[cfg(debug_assertions)]
+use std::sync::Mutex;
+use leptos::context::Provider;
 use leptos::prelude::*;
+#[cfg(debug_assertions)]
+use crate::infra::hydration;
 
-/// Contexto de UID — um contador por árvore Leptos
-#[derive(Clone)]
-pub struct UidContext(Arc<AtomicU64>);
+/// Contexto de UID — um contador por árvore Leptos (ou por UidScope)
+#[derive(Clone, Default)]
+pub struct UidContext {
+    counter:   Arc<AtomicU64>,
+    /// Caminho de keys dos UidScope ancestrais — vazio no root
+    path:      Arc<str>,
+    /// Hash curto do `path` — entra no UID entre prefixo e contador
+    namespace: Option<Arc<str>>,
+    #[cfg(debug_assertions)]
+    log:       Arc<Mutex<Vec<String>>>,
+    #[cfg(debug_assertions)]
+    registry:  Arc<Mutex<UidRegistry>>,
+}
+
+/// Debug: namespaces e UIDs vivos na árvore — detecta colisões
+#[cfg(debug_assertions)]
+#[derive(Default)]
+struct UidRegistry {
+    /// namespace → path que o gerou
+    scopes: HashMap<String, String>,
+    uids:   HashSet<String>,
+}
+
+impl UidContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Contexto filho com contador próprio — mesmo key sob o mesmo pai → mesmo namespace
+    pub fn scoped(&self, key: &str) -> Self {
+        let path: Arc<str> = if self.path.is_empty() { key.into() } else { format!("{}/{}", self.path, key).into() };
+        Self {
+            counter:   Arc::default(),
+            namespace: Some(scope_namespace(&path).into()),
+            path,
+            #[cfg(debug_assertions)]
+            log:       self.log.clone(),
+            #[cfg(debug_assertions)]
+            registry:  self.registry.clone(),
+        }
+    }
+
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    fn next(&self, prefix: &str) -> String {
+        let ctr = self.counter.fetch_add(1, Ordering::SeqCst);
+        let uid = match &self.namespace {
+            Some(ns) => format!("{}-{}.{:08x}", prefix, ns, ctr),
+            None     => format!("{}-{:08x}", prefix, ctr),
+        };
+        #[cfg(debug_assertions)]
+        {
+            if let Ok(mut log) = self.log.lock() {
+                log.push(uid.clone());
+            }
+            if let Ok(mut registry) = self.registry.lock() {
+                if !registry.uids.insert(uid.clone()) {
+                    leptos::logging::warn!("[CanonRS][Uid] collision: '{}' generated twice in scope '{}'", uid, self.path);
+                }
+            }
+        }
+        uid
+    }
+
+    /// Debug: registra o namespace — key repetida entre irmãos ou colisão de hash gera warning
+    #[cfg(debug_assertions)]
+    fn register_scope(&self) {
+        let (Some(ns), Ok(mut registry)) = (&self.namespace, self.registry.lock()) else { return };
+        match registry.scopes.get(ns.as_ref()) {
+            Some(path) if path.as_str() == self.path.as_ref() =>
+                leptos::logging::warn!("[CanonRS][Uid] UidScope key '{}' is mounted twice — UIDs inside will collide", self.path),
+            Some(path) =>
+                leptos::logging::warn!("[CanonRS][Uid] UidScope '{}' and '{}' hash to the same namespace '{}'", self.path, path, ns),
+            None => {}
+        }
+        registry.scopes.insert(ns.to_string(), self.path.to_string());
+    }
+
+    /// Debug: libera namespace e UIDs quando o scope desmonta (remount não é colisão)
+    #[cfg(debug_assertions)]
+    fn unregister_scope(&self) {
+        let (Some(ns), Ok(mut registry)) = (&self.namespace, self.registry.lock()) else { return };
+        registry.scopes.remove(ns.as_ref());
+        let marker = format!("-{}.", ns);
+        registry.uids.retain(|uid| !uid.contains(&marker));
+    }
+
+    /// UIDs gerados nesta árvore, em ordem — vazio em release
+    pub fn recorded(&self) -> Vec<String> {
+        #[cfg(debug_assertions)]
+        if let Ok(log) = self.log.lock() {
+            return log.clone();
+        }
+        vec![]
+    }
+}
+
+/// FNV-1a 32 bits do path, em base 36 — curto e estável entre SSR e WASM
+fn scope_namespace(path: &str) -> String {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in path.bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    let mut out = Vec::new();
+    loop {
+        out.push(char::from_digit(hash % 36, 36).unwrap_or('0'));
+        hash /= 36;
+        if hash == 0 { break; }
+    }
+    out.iter().rev().collect()
+}
 
 /// Componente raiz que provê o contexto de UID
 /// Deve ser usado uma vez no topo da árvore (ex: CanonRSRoot)
+/// Em debug emite o manifest de hydration após os children
 #[component]
 pub fn UidRoot(children: Children) -> impl IntoView {
-    provide_context(UidContext(Arc::new(AtomicU64::new(0))));
-    children()
+    provide_context(UidContext::new());
+    let children = children();
+    #[cfg(debug_assertions)]
+    {
+        view! { {children} <HydrationCheck /> }.into_any()
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        children.into_any()
+    }
+}
+
+/// Namespace de UID para uma subárvore — UIDs dentro independem da ordem dos irmãos
+/// A key deve ser estável entre SSR e hydrate (id de rota, de registro, nome do island)
+/// Uso: <UidScope key=format!("row-{}", row.id)>...</UidScope>
+#[component]
+pub fn UidScope(#[prop(into)] key: String, children: Children) -> impl IntoView {
+    let scope = use_context::<UidContext>().unwrap_or_default().scoped(&key);
+    #[cfg(debug_assertions)]
+    {
+        scope.register_scope();
+        let registered = scope.clone();
+        on_cleanup(move || registered.unregister_scope());
+    }
+    view! { <Provider value=scope>{children()}</Provider> }
 }
 
 /// Gera UID determinístico via contexto da árvore
 /// Se não houver contexto (ex: testes), usa fallback global
 pub fn generate(prefix: &str) -> String {
-    let ctr = if let Some(ctx) = use_context::<UidContext>() {
-        ctx.0.fetch_add(1, Ordering::SeqCst)
-    } else {
-        static FALLBACK: AtomicU64 = AtomicU64::new(0);
-        FALLBACK.fetch_add(1, Ordering::SeqCst)
+    if let Some(ctx) = use_context::<UidContext>() {
+        return ctx.next(prefix);
+    }
+    static FALLBACK: AtomicU64 = AtomicU64::new(0);
+    format!("{}-{:08x}", prefix, FALLBACK.fetch_add(1, Ordering::SeqCst))
+}
+
+/// Sequência registrada no contexto atual — vazio sem UidRoot ou em release
+pub fn recorded() -> Vec<String> {
+    use_context::<UidContext>().map(|ctx| ctx.recorded()).unwrap_or_default()
+}
+
+/// Emite o manifest no SSR e compara no hydrate — montado pelo UidRoot em debug
+/// O SSR só conhece os UIDs; o estado é gravado pelo servidor sobre o HTML final (hydration::stamp_ssr_state)
+/// O do client é lido do DOM no frame seguinte ao hydrate
+#[cfg(debug_assertions)]
+#[component]
+fn HydrationCheck() -> impl IntoView {
+    #[cfg(feature = "ssr")]
+    let manifest = hydration::HydrationManifest::from_uids(recorded()).encode();
+
+    #[cfg(all(feature = "hydrate", not(feature = "ssr")))]
+    let manifest = {
+        use leptos::leptos_dom::helpers::{document, request_animation_frame};
+
+        fn dom_state(uid: &str, attr: &str) -> Option<String> {
+            document()
+                .query_selector(&format!("[data-rs-uid=\"{}\"]", uid))
+                .ok()
+                .flatten()?
+                .get_attribute(attr)
+        }
+
+        let script = document().get_element_by_id(hydration::MANIFEST_ID);
+        let raw = script.as_ref().and_then(|el| el.text_content()).unwrap_or_default();
+        let ctx = use_context::<UidContext>();
+        if let (Ok(ssr), Some(ctx)) = (hydration::HydrationManifest::decode(&raw), ctx) {
+            // Sem estado gravado no servidor (middleware de dev ausente) compara só a sequência — e avisa
+            let compare_state = script.is_some_and(|el| el.has_attribute(hydration::STAMPED_ATTR));
+            if !compare_state {
+                leptos::logging::warn!(
+                    "[CanonRS][Hydration] SSR state was not recorded — comparing UIDs only; wrap the router with canonrs::with_dom_verifier to check data-rs-* state"
+                );
+            }
+            request_animation_frame(move || {
+                let client = hydration::HydrationManifest::from_uids(ctx.recorded());
+                let client = if compare_state { client.with_state(dom_state) } else { client };
+                if let Some(mismatch) = ssr.first_mismatch(&client) {
+                    leptos::logging::warn!("[CanonRS][Hydration] {}", mismatch);
+                }
+            });
+        }
+        raw
     };
-    format!("{}-{:08x}", prefix, ctr)
+
+    #[cfg(not(any(feature = "ssr", feature = "hydrate")))]
+    let manifest = String::new();
+
+    view! { <script type="application/json" id=hydration::MANIFEST_ID inner_html=manifest></script> }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::hydration::{uid_namespace, uid_prefix};
+
+    #[test]
+    fn test_root_uids_are_prefix_and_counter() {
+        let ctx = UidContext::new();
+        assert_eq!(ctx.next("btn"), "btn-00000000");
+        assert_eq!(ctx.next("dlg"), "dlg-00000001");
+        assert_eq!(ctx.namespace(), None);
+        assert_eq!(uid_namespace("dlg-00000001"), None);
+        assert_eq!(uid_prefix("dlg-00000001"), "dlg");
+    }
+
+    #[test]
+    fn test_scoped_uids_are_prefix_namespace_counter() {
+        let root = UidContext::new();
+        root.next("nav");
+        let scope = root.scoped("row-42");
+        let ns = scope.namespace().unwrap().to_string();
+        // contador próprio — os UIDs do root antes do scope não deslocam os de dentro
+        let uid = scope.next("tree-item");
+        assert_eq!(uid, format!("tree-item-{}.00000000", ns));
+        assert_eq!(uid_namespace(&uid), Some(ns.as_str()));
+        assert_eq!(uid_prefix(&uid), "tree-item");
+        assert_eq!(scope.next("btn"), format!("btn-{}.00000001", ns));
+        // root segue a própria sequência
+        assert_eq!(root.next("ft"), "ft-00000001");
+    }
+
+    #[test]
+    fn test_namespace_is_stable_and_parse_safe() {
+        // SSR e hydrate montam contextos separados — mesma key → mesmo namespace
+        let ssr = UidContext::new().scoped("route:/docs");
+        let client = UidContext::new().scoped("route:/docs");
+        assert_eq!(ssr.namespace(), client.namespace());
+        assert_eq!(ssr.namespace(), Some(scope_namespace("route:/docs").as_str()));
+        assert_ne!(ssr.namespace(), UidContext::new().scoped("route:/blog").namespace());
+        // sem `-` nem `.` — uid_prefix/uid_namespace dependem disso
+        for key in ["", "a", "row-1", "island.Counter", "ção/ü"] {
+            let ns = scope_namespace(key);
+            assert!(!ns.is_empty() && ns.chars().all(|c| c.is_ascii_digit() || c.is_ascii_lowercase()), "{key:?} → {ns}");
+        }
+    }
+
+    #[test]
+    fn test_nested_scopes_hash_the_full_path() {
+        let root = UidContext::new();
+        let inner = root.scoped("page").scoped("row-1");
+        assert_eq!(inner.namespace(), Some(scope_namespace("page/row-1").as_str()));
+        // mesma key sob pais diferentes → namespaces diferentes
+        assert_ne!(inner.namespace(), root.scoped("row-1").namespace());
+        assert_ne!(inner.namespace(), root.scoped("other").scoped("row-1").namespace());
+        let uid = inner.next("cb");
+        assert_eq!(uid_namespace(&uid), inner.namespace());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_recorded_keeps_render_order_across_scopes() {
+        let root = UidContext::new();
+        let a = root.next("nav");
+        let b = root.scoped("x").next("btn");
+        let c = root.next("ft");
+        assert_eq!(root.recorded(), vec![a, b, c]);
+    }
 }
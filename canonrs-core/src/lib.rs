@@ -9,10 +9,21 @@ pub mod primitives;
 pub use primitives::*;
 pub mod meta;
 pub mod meta_types;
+pub mod state_engine;
 pub mod catalog_types;
 pub use catalog_types::*;
+pub mod catalog_query;
+pub use catalog_query::*;
 pub mod block_types;
 pub use block_types::*;
+pub mod block_props;
+pub use block_props::*;
+pub mod page_document;
+pub use page_document::*;
+pub mod page_ops;
+pub use page_ops::*;
+pub mod block_migrations;
+pub use block_migrations::*;
 pub use meta::*;
 pub use canonrs_style::style_contract;
 pub use canonrs_style::*;
@@ -247,6 +247,112 @@ impl ToDataAttr for LoadingState {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum InvalidState {
+    #[default] Valid,
+    Invalid,
+}
+impl InvalidState {
+    pub fn as_str(&self) -> &'static str {
+        match self { Self::Valid => "valid", Self::Invalid => "invalid" }
+    }
+}
+impl From<bool> for InvalidState {
+    fn from(b: bool) -> Self { if b { Self::Invalid } else { Self::Valid } }
+}
+impl ToDataAttr for InvalidState {
+    fn to_data_attr(&self) -> (&'static str, &'static str) {
+        ("data-rs-invalid", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ReadonlyState {
+    #[default] Editable,
+    Readonly,
+}
+impl ReadonlyState {
+    pub fn as_str(&self) -> &'static str {
+        match self { Self::Editable => "editable", Self::Readonly => "readonly" }
+    }
+}
+impl From<bool> for ReadonlyState {
+    fn from(b: bool) -> Self { if b { Self::Readonly } else { Self::Editable } }
+}
+impl ToDataAttr for ReadonlyState {
+    fn to_data_attr(&self) -> (&'static str, &'static str) {
+        ("data-rs-readonly", self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum RequiredState {
+    #[default] Optional,
+    Required,
+}
+impl RequiredState {
+    pub fn as_str(&self) -> &'static str {
+        match self { Self::Optional => "optional", Self::Required => "required" }
+    }
+}
+impl From<bool> for RequiredState {
+    fn from(b: bool) -> Self { if b { Self::Required } else { Self::Optional } }
+}
+impl ToDataAttr for RequiredState {
+    fn to_data_attr(&self) -> (&'static str, &'static str) {
+        ("data-rs-required", self.as_str())
+    }
+}
+
+/// Disclosure de um item (tree item, accordion, nav group) — distinto de VisibilityState do overlay
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ExpandedState {
+    #[default] Collapsed,
+    Expanded,
+}
+impl ExpandedState {
+    pub fn as_str(&self) -> &'static str {
+        match self { Self::Collapsed => "collapsed", Self::Expanded => "expanded" }
+    }
+}
+impl From<bool> for ExpandedState {
+    fn from(b: bool) -> Self { if b { Self::Expanded } else { Self::Collapsed } }
+}
+impl ToDataAttr for ExpandedState {
+    fn to_data_attr(&self) -> (&'static str, &'static str) {
+        ("data-rs-expanded", self.as_str())
+    }
+}
+
+/// Tri-state — `Mixed` quando só parte dos filhos está marcada
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum CheckedState {
+    #[default] Unchecked,
+    Checked,
+    Mixed,
+}
+impl CheckedState {
+    pub fn as_str(&self) -> &'static str {
+        match self { Self::Unchecked => "unchecked", Self::Checked => "checked", Self::Mixed => "mixed" }
+    }
+    /// Agrega filhos: todos → Checked, nenhum → Unchecked, parte → Mixed
+    pub fn from_children(checked: usize, total: usize) -> Self {
+        match checked {
+            0                    => Self::Unchecked,
+            n if n >= total      => Self::Checked,
+            _                    => Self::Mixed,
+        }
+    }
+}
+impl From<bool> for CheckedState {
+    fn from(b: bool) -> Self { if b { Self::Checked } else { Self::Unchecked } }
+}
+impl ToDataAttr for CheckedState {
+    fn to_data_attr(&self) -> (&'static str, &'static str) {
+        ("data-rs-checked", self.as_str())
+    }
+}
+
 
 // ── ARIA + HTML HELPERS ──────────────────────────────────────────────────────
 // Métodos diretos nos enums — elimina necessidade de state_engine structs
@@ -300,6 +406,42 @@ impl LoadingState {
     }
 }
 
+impl InvalidState {
+    pub fn aria_invalid(&self) -> Option<&'static str> {
+        if *self == Self::Invalid { Some("true") } else { None }
+    }
+    pub fn invalid(&self) -> bool { *self == Self::Invalid }
+}
+
+impl ReadonlyState {
+    pub fn aria_readonly(&self) -> Option<&'static str> {
+        if *self == Self::Readonly { Some("true") } else { None }
+    }
+    pub fn readonly(&self) -> bool { *self == Self::Readonly }
+}
+
+impl RequiredState {
+    pub fn aria_required(&self) -> Option<&'static str> {
+        if *self == Self::Required { Some("true") } else { None }
+    }
+    pub fn required(&self) -> bool { *self == Self::Required }
+}
+
+impl ExpandedState {
+    pub fn aria_expanded(&self) -> &'static str {
+        if *self == Self::Expanded { "true" } else { "false" }
+    }
+    pub fn expanded(&self) -> bool { *self == Self::Expanded }
+}
+
+impl CheckedState {
+    pub fn aria_checked(&self) -> &'static str {
+        match self { Self::Unchecked => "false", Self::Checked => "true", Self::Mixed => "mixed" }
+    }
+    pub fn checked(&self) -> bool { *self == Self::Checked }
+    pub fn indeterminate(&self) -> bool { *self == Self::Mixed }
+}
+
 // ── STATE KIND (unificador sem ambiguidade) ───────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -311,6 +453,11 @@ pub enum StateKind {
     Navigation(NavigationState),
     Disabled(DisabledState),
     Loading(LoadingState),
+    Invalid(InvalidState),
+    Readonly(ReadonlyState),
+    Required(RequiredState),
+    Expanded(ExpandedState),
+    Checked(CheckedState),
 }
 
 impl StateKind {
@@ -323,6 +470,11 @@ impl StateKind {
             Self::Navigation(s) => s.as_str(),
             Self::Disabled(s)   => s.as_str(),
             Self::Loading(s)    => s.as_str(),
+            Self::Invalid(s)    => s.as_str(),
+            Self::Readonly(s)   => s.as_str(),
+            Self::Required(s)   => s.as_str(),
+            Self::Expanded(s)   => s.as_str(),
+            Self::Checked(s)    => s.as_str(),
         }
     }
 
@@ -335,6 +487,11 @@ impl StateKind {
             Self::Navigation(s) => s.to_data_attr(),
             Self::Disabled(s)   => s.to_data_attr(),
             Self::Loading(s)    => s.to_data_attr(),
+            Self::Invalid(s)    => s.to_data_attr(),
+            Self::Readonly(s)   => s.to_data_attr(),
+            Self::Required(s)   => s.to_data_attr(),
+            Self::Expanded(s)   => s.to_data_attr(),
+            Self::Checked(s)    => s.to_data_attr(),
         }
     }
 }
@@ -416,3 +573,25 @@ pub fn derive_props_from_capabilities(caps: &[Capability]) -> Vec<CapabilityProp
 pub fn derive_props_from_meta(meta: &ComponentMeta) -> Vec<CapabilityPropDef> {
     derive_props_from_capabilities(meta.capabilities)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_form_state_attrs() {
+        assert_eq!(StateKind::Invalid(true.into()).to_data_attr(), ("data-rs-invalid", "invalid"));
+        assert_eq!(StateKind::Expanded(ExpandedState::Collapsed).to_data_attr(), ("data-rs-expanded", "collapsed"));
+        assert_eq!(RequiredState::Required.aria_required(), Some("true"));
+        assert_eq!(ReadonlyState::Editable.aria_readonly(), None);
+    }
+
+    #[test]
+    fn test_checked_mixed_from_children() {
+        assert_eq!(CheckedState::from_children(0, 3), CheckedState::Unchecked);
+        assert_eq!(CheckedState::from_children(2, 3), CheckedState::Mixed);
+        assert_eq!(CheckedState::from_children(3, 3), CheckedState::Checked);
+        assert_eq!(CheckedState::Mixed.aria_checked(), "mixed");
+        assert_eq!(StateKind::Checked(CheckedState::Mixed).to_data_attr(), ("data-rs-checked", "mixed"));
+    }
+}
@@ -0,0 +1,439 @@
+//! PageDocument — serializable tree of a page composed from the catalog
+//! Nodes reference catalog ids — `<id>` (UI components), `block.<id>`, `layout.<id>`
+//! Children are keyed by region — `CatalogEntry::regions` (Children slots for UI components)
+//! Validation delegates to ConstraintEngine — no composition rules live here
+//! `report()` is the JSON entry point for CI and the builder (WASM)
+
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+
+use crate::block_types::BlockDefinition;
+use crate::catalog_types::{CatalogEntry, CatalogKind};
+use crate::infra::constraint_engine::{ConstraintEngine, ConstraintViolation, Severity, ValidationResult};
+
+/// Stable node identifier — same space as `CanvasState::selected`
+pub type NodeId = u64;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PageNode {
+    pub id:         NodeId,
+    pub catalog_id: String,
+    /// `BlockDefinition::version` do schema em que o node foi salvo — ausente = 1
+    /// Nodes novos (`new`, `PageOp::Insert`) nascem com a versão atual
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version:    Option<u32>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub props:      BTreeMap<String, Value>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub regions:    BTreeMap<String, Vec<PageNode>>,
+}
+
+impl PageNode {
+    pub fn new(id: NodeId, catalog_id: impl Into<String>) -> Self {
+        let catalog_id = catalog_id.into();
+        Self {
+            id,
+            version:    schema_version(&catalog_id),
+            catalog_id,
+            props:      BTreeMap::new(),
+            regions:    BTreeMap::new(),
+        }
+    }
+
+    pub fn with_prop(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.props.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    pub fn with_child(mut self, region: impl Into<String>, child: PageNode) -> Self {
+        self.regions.entry(region.into()).or_default().push(child);
+        self
+    }
+
+    /// Regions that currently hold at least one child
+    pub fn filled_regions(&self) -> Vec<&str> {
+        self.regions
+            .iter()
+            .filter(|(_, children)| !children.is_empty())
+            .map(|(region, _)| region.as_str())
+            .collect()
+    }
+
+    /// Depth-first search by node id (self included)
+    pub fn find(&self, id: NodeId) -> Option<&PageNode> {
+        if self.id == id { return Some(self); }
+        self.regions.values().flatten().find_map(|c| c.find(id))
+    }
+
+    pub fn find_mut(&mut self, id: NodeId) -> Option<&mut PageNode> {
+        if self.id == id { return Some(self); }
+        self.regions.values_mut().flatten().find_map(|c| c.find_mut(id))
+    }
+
+    /// Todos os ids da subárvore (self incluído)
+    pub fn ids(&self) -> Vec<NodeId> {
+        let mut out = vec![self.id];
+        for child in self.regions.values().flatten() {
+            out.extend(child.ids());
+        }
+        out
+    }
+
+    /// Carimba a versão atual do schema nos nodes sem `version` — nodes recém-criados, não legado
+    pub fn stamp_versions(&mut self) {
+        self.stamp_with(schema_version);
+    }
+
+    pub(crate) fn stamp_with(&mut self, current: impl Fn(&str) -> Option<u32> + Copy) {
+        if self.version.is_none() {
+            self.version = current(&self.catalog_id);
+        }
+        for child in self.regions.values_mut().flatten() {
+            child.stamp_with(current);
+        }
+    }
+
+    /// Valida a subárvore — `path` é o JSON pointer deste node no documento
+    pub fn validate(&self, path: &str) -> Vec<NodeViolation> {
+        let mut out = vec![];
+        validate_node(self, path, &mut out);
+        out
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PageDocument {
+    pub root: PageNode,
+}
+
+/// Uma violação localizada na árvore
+/// `path` é um JSON pointer para o node no documento serializado (ex: `/root/regions/content/0`)
+#[derive(Debug, PartialEq, Serialize)]
+pub struct NodeViolation {
+    pub path:      String,
+    pub node_id:   NodeId,
+    pub severity:  Severity,
+    pub message:   String,
+    pub violation: ConstraintViolation,
+}
+
+impl NodeViolation {
+    pub fn new(path: &str, node_id: NodeId, violation: ConstraintViolation) -> Self {
+        Self {
+            path: path.to_string(),
+            node_id,
+            severity: violation.severity(),
+            message: violation.message(),
+            violation,
+        }
+    }
+}
+
+/// Resultado completo da validação — serializável para CI e para o builder
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ValidationReport {
+    pub errors:   usize,
+    pub warnings: usize,
+    pub issues:   Vec<NodeViolation>,
+}
+
+impl ValidationReport {
+    pub fn new(issues: Vec<NodeViolation>) -> Self {
+        let errors = issues.iter().filter(|v| v.severity == Severity::Error).count();
+        Self { errors, warnings: issues.len() - errors, issues }
+    }
+
+    /// Válido quando não há erros — warnings não bloqueiam
+    pub fn is_valid(&self) -> bool {
+        self.errors == 0
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+impl PageDocument {
+    pub fn new(root: PageNode) -> Self {
+        Self { root }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn find(&self, id: NodeId) -> Option<&PageNode> {
+        self.root.find(id)
+    }
+
+    pub fn find_mut(&mut self, id: NodeId) -> Option<&mut PageNode> {
+        self.root.find_mut(id)
+    }
+
+    /// Próximo id livre — maior id da árvore + 1
+    pub fn next_id(&self) -> NodeId {
+        self.root.ids().into_iter().max().unwrap_or(0) + 1
+    }
+
+    /// Valida a árvore inteira — coleta todas as violações, nunca para na primeira
+    pub fn validate(&self) -> Vec<NodeViolation> {
+        self.root.validate("/root")
+    }
+
+    /// Mesmo critério do ValidationReport — warnings não invalidam
+    pub fn is_valid(&self) -> bool {
+        self.report().is_valid()
+    }
+
+    pub fn report(&self) -> ValidationReport {
+        ValidationReport::new(self.validate())
+    }
+}
+
+fn schema_version(catalog_id: &str) -> Option<u32> {
+    BlockDefinition::for_catalog_id(catalog_id).map(|d| d.version)
+}
+
+/// Escapa um segmento de JSON pointer (RFC 6901)
+pub fn pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Id do catalog — único namespace aceito em `PageNode::catalog_id`
+pub fn is_known_catalog_id(catalog_id: &str) -> bool {
+    CatalogEntry::find(catalog_id).is_some()
+}
+
+/// Checagem parent/child compartilhada pela validação e pelas operações
+/// A region precisa existir no catalog; BlockDefinition com regions tipadas decide a aceitação,
+/// senão accepts/region_rules do catalog — slots de UI components aceitam qualquer node
+pub fn check_insert(parent_id: &str, region: &str, child_id: &str, current_children: usize) -> ValidationResult {
+    let Some(parent) = CatalogEntry::find(parent_id) else {
+        return ValidationResult::Valid;
+    };
+    if parent.regions.is_empty() {
+        return ValidationResult::Invalid(vec![ConstraintViolation::NotAContainer { block_id: parent_id.to_string() }]);
+    }
+    if !parent.has_region(region) {
+        return ValidationResult::Invalid(vec![ConstraintViolation::UnknownRegion {
+            block_id: parent_id.to_string(),
+            region:   region.to_string(),
+        }]);
+    }
+    if let Some(def) = BlockDefinition::for_catalog_id(parent_id).filter(|d| d.find_region(region).is_some()) {
+        let child = BlockDefinition::for_catalog_id(child_id).map_or(child_id, |d| d.id);
+        return ConstraintEngine::can_insert(def.id, region, child, current_children);
+    }
+    match CatalogEntry::find(child_id) {
+        Some(child) if parent.kind != CatalogKind::Component => {
+            ConstraintEngine::catalog_check_nest_in_region(parent, region, child)
+        }
+        _ => ValidationResult::Valid,
+    }
+}
+
+fn push_all(out: &mut Vec<NodeViolation>, path: &str, node_id: NodeId, result: ValidationResult) {
+    if let ValidationResult::Invalid(violations) = result {
+        out.extend(violations.into_iter().map(|v| NodeViolation::new(path, node_id, v)));
+    }
+}
+
+fn validate_node(node: &PageNode, path: &str, out: &mut Vec<NodeViolation>) {
+    if !is_known_catalog_id(&node.catalog_id) {
+        out.push(NodeViolation::new(
+            path,
+            node.id,
+            ConstraintViolation::UnknownBlock { block_id: node.catalog_id.clone() },
+        ));
+    }
+
+    if let Some(def) = BlockDefinition::for_catalog_id(&node.catalog_id) {
+        // Blocks/layouts — required regions
+        let provided = node.filled_regions();
+        if def.validate_regions(&provided).is_err() {
+            for region in def.regions_required.iter().copied().filter(|r| !provided.contains(r)) {
+                out.push(NodeViolation::new(
+                    path,
+                    node.id,
+                    ConstraintViolation::MissingRegion { block_id: node.catalog_id.clone(), region },
+                ));
+            }
+        }
+    } else if ConstraintEngine::resolve_meta(&node.catalog_id).is_some() {
+        // UI components — required parts vs regions preenchidas
+        push_all(out, path, node.id, ConstraintEngine::validate_parts(&node.catalog_id, &node.filled_regions()));
+    }
+
+    for (region, children) in &node.regions {
+        for (index, child) in children.iter().enumerate() {
+            let child_path = format!("{}/regions/{}/{}", path, pointer_segment(region), index);
+            push_all(
+                out,
+                &child_path,
+                child.id,
+                check_insert(&node.catalog_id, region, &child.catalog_id, index),
+            );
+            validate_node(child, &child_path, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let doc = PageDocument::new(
+            PageNode::new(1, "block.card")
+                .with_prop("variant", "outlined")
+                .with_child("content", PageNode::new(2, "button").with_prop("disabled", true)),
+        );
+        let json = doc.to_json().unwrap();
+        assert_eq!(PageDocument::from_json(&json).unwrap(), doc);
+    }
+
+    #[test]
+    fn test_empty_maps_are_optional() {
+        let doc = PageDocument::from_json(r#"{"root":{"id":1,"catalog_id":"button"}}"#).unwrap();
+        assert!(doc.root.props.is_empty());
+        assert!(doc.root.regions.is_empty());
+    }
+
+    #[test]
+    fn test_collects_every_violation_with_path() {
+        let doc = PageDocument::new(
+            PageNode::new(1, "block.card")
+                .with_child("content", PageNode::new(2, "nonexistent-a"))
+                .with_child("content", PageNode::new(3, "nonexistent-b")),
+        );
+        let unknown: Vec<_> = doc.validate()
+            .into_iter()
+            .filter(|v| matches!(v.violation, ConstraintViolation::UnknownBlock { .. }))
+            .collect();
+        assert_eq!(unknown.len(), 2);
+        assert_eq!(unknown[0].path, "/root/regions/content/0");
+        assert_eq!(unknown[1].path, "/root/regions/content/1");
+        assert_eq!(unknown[1].node_id, 3);
+    }
+
+    #[test]
+    fn test_report_counts_and_serializes() {
+        let doc = PageDocument::new(
+            PageNode::new(1, "form").with_child("children", PageNode::new(2, "nonexistent")),
+        );
+        let report = doc.report();
+        assert!(!report.is_valid());
+        assert_eq!(report.errors, 1);
+        let json: Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        let issue = &json["issues"][0];
+        assert_eq!(issue["path"], "/root/regions/children/0");
+        assert_eq!(issue["severity"], "error");
+        assert_eq!(issue["violation"]["code"], "unknown_block");
+        assert_eq!(issue["message"], "'nonexistent' does not exist in the catalog");
+    }
+
+    #[test]
+    fn test_warnings_do_not_invalidate() {
+        let missing = ConstraintViolation::MissingRegion { block_id: "block.card".into(), region: "content" };
+        let report = ValidationReport::new(vec![NodeViolation::new("/root", 1, missing)]);
+        assert_eq!((report.errors, report.warnings), (0, 1));
+        assert!(report.is_valid());
+
+        let valid = PageDocument::new(PageNode::new(1, "button"));
+        let invalid = PageDocument::new(PageNode::new(1, "nonexistent"));
+        assert!(valid.is_valid());
+        assert!(!invalid.is_valid());
+        assert_eq!(invalid.is_valid(), invalid.report().is_valid());
+    }
+
+    #[test]
+    fn test_missing_parts_is_reported_as_warning() {
+        let doc = PageDocument::new(PageNode::new(1, "accordion"));
+        let report = doc.report();
+        assert_eq!(report.errors, 0);
+        assert!(report.warnings > 0);
+        assert!(doc.is_valid());
+        assert!(matches!(
+            &doc.validate()[0],
+            NodeViolation { path, node_id: 1, violation: ConstraintViolation::MissingParts { component, missing }, .. }
+                if path == "/root" && component == "accordion" && missing.contains(&"AccordionItem")
+        ));
+    }
+
+    #[test]
+    fn test_block_and_component_ids_are_distinct() {
+        // `card` é o UI Card (slot `children`); `block.card` é o block (header/content/footer)
+        let component = PageDocument::new(PageNode::new(1, "card").with_child("children", PageNode::new(2, "button")));
+        let block = PageDocument::new(PageNode::new(1, "block.card").with_child("content", PageNode::new(2, "button")));
+        assert!(component.validate().is_empty());
+        assert!(block.validate().is_empty());
+
+        let wrong = PageDocument::new(PageNode::new(1, "card").with_child("content", PageNode::new(2, "button")));
+        assert!(matches!(
+            wrong.validate()[0].violation,
+            ConstraintViolation::UnknownRegion { ref region, .. } if region == "content"
+        ));
+    }
+
+    #[test]
+    fn test_bare_block_id_is_unknown() {
+        // Ids sem prefixo são components — `hero` só existe como `block.hero`
+        let doc = PageDocument::new(PageNode::new(1, "hero"));
+        assert!(matches!(doc.validate()[0].violation, ConstraintViolation::UnknownBlock { .. }));
+        assert!(PageDocument::new(PageNode::new(1, "block.hero")).validate().is_empty());
+    }
+
+    #[test]
+    fn test_leaf_component_rejects_children() {
+        let doc = PageDocument::new(PageNode::new(1, "separator").with_child("children", PageNode::new(2, "button")));
+        assert!(matches!(doc.validate()[0].violation, ConstraintViolation::NotAContainer { .. }));
+    }
+
+    #[test]
+    fn test_pointer_segment_escapes() {
+        assert_eq!(pointer_segment("a/b~c"), "a~1b~0c");
+    }
+
+    #[test]
+    fn test_json_schema_follows_catalog_model() {
+        let bundle: Value = serde_json::from_str(crate::generated::JSON_SCHEMA_BUNDLE).unwrap();
+        let defs = bundle["$defs"].as_object().unwrap();
+        assert_eq!(bundle["oneOf"].as_array().unwrap().len(), defs.len());
+        let mut seen = std::collections::BTreeSet::new();
+        for schema in defs.values() {
+            let ids = schema["properties"]["catalog_id"]["enum"].as_array().unwrap();
+            assert_eq!(ids.len(), 1, "{}", schema["$id"]);
+            let id = ids[0].as_str().unwrap();
+            assert!(seen.insert(id), "catalog_id '{}' in more than one branch", id);
+            let entry = CatalogEntry::find(id).unwrap_or_else(|| panic!("'{}' is not a catalog id", id));
+            let mut regions: Vec<&str> = schema["properties"]["regions"]["properties"]
+                .as_object().unwrap().keys().map(String::as_str).collect();
+            let mut expected = entry.regions.to_vec();
+            regions.sort();
+            expected.sort();
+            assert_eq!(regions, expected, "regions of '{}'", id);
+            // Filhos aninhados passam pelo oneOf do bundle, não só pela forma do node
+            assert_eq!(schema["$defs"]["node"]["$ref"], bundle["$id"], "nested children of '{}'", id);
+            for (key, prop) in schema["properties"]["props"]["properties"].as_object().unwrap() {
+                if prop["type"] == "number" {
+                    assert!(prop.get("default").is_none_or(Value::is_number), "default of '{}.{}'", id, key);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! PageOps — edições do builder como comandos serializáveis
+//! Toda operação é validada (ConstraintEngine via check_insert) antes de aplicar
+//! e devolve a operação inversa — base para undo/redo, replay colaborativo e audit trail
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+
+use crate::block_props::{coerce_preset, BlockPropError};
+use crate::block_types::BlockDefinition;
+use crate::infra::constraint_engine::{ConstraintViolation, Severity, ValidationResult};
+use crate::page_document::{check_insert, is_known_catalog_id, NodeId, PageDocument, PageNode};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PageOp {
+    /// Insere uma subárvore numa region do parent
+    Insert { parent: NodeId, region: String, index: usize, node: PageNode },
+    /// Move um node (com filhos) para outra posição
+    Move { node: NodeId, parent: NodeId, region: String, index: usize },
+    /// Remove um node (com filhos)
+    Remove { node: NodeId },
+    /// Define (Some) ou remove (None) uma prop
+    SetProp { node: NodeId, key: String, value: Option<Value> },
+    /// Várias props de uma vez — inversa de ApplyPreset
+    SetProps { node: NodeId, props: BTreeMap<String, Option<Value>> },
+    /// Aplica um BlockPreset pelo label
+    ApplyPreset { node: NodeId, preset: String },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum OpError {
+    NodeNotFound(NodeId),
+    DuplicateId(NodeId),
+    CannotRemoveRoot,
+    /// Destino dentro da própria subárvore
+    MoveIntoSelf(NodeId),
+    IndexOutOfRange { region: String, index: usize, len: usize },
+    PresetNotFound { catalog_id: String, preset: String },
+    /// Valores do preset não passam pelo schema do block
+    InvalidProps(Vec<BlockPropError>),
+    /// ConstraintEngine recusou a operação
+    Rejected(Vec<ConstraintViolation>),
+}
+
+impl fmt::Display for OpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NodeNotFound(id)  => write!(f, "node {} not found", id),
+            Self::DuplicateId(id)   => write!(f, "node id {} is already in use", id),
+            Self::CannotRemoveRoot  => write!(f, "the root node cannot be moved or removed"),
+            Self::MoveIntoSelf(id)  => write!(f, "cannot move a node into its own subtree (target {})", id),
+            Self::IndexOutOfRange { region, index, len } =>
+                write!(f, "index {} out of range for region '{}' ({} children)", index, region, len),
+            Self::PresetNotFound { catalog_id, preset } => write!(f, "'{}' has no preset '{}'", catalog_id, preset),
+            Self::InvalidProps(errors) => {
+                let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                write!(f, "invalid preset props: {}", errors.join("; "))
+            }
+            Self::Rejected(violations) => {
+                let messages: Vec<String> = violations.iter().map(ConstraintViolation::message).collect();
+                write!(f, "rejected: {}", messages.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for OpError {}
+
+/// Posição de um node dentro do parent
+struct Location {
+    parent: NodeId,
+    region: String,
+    index:  usize,
+}
+
+fn locate(node: &PageNode, id: NodeId) -> Option<Location> {
+    for (region, children) in &node.regions {
+        for (index, child) in children.iter().enumerate() {
+            if child.id == id {
+                return Some(Location { parent: node.id, region: region.clone(), index });
+            }
+            if let Some(found) = locate(child, id) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+fn detach(doc: &mut PageDocument, id: NodeId) -> Option<(PageNode, Location)> {
+    let loc = locate(&doc.root, id)?;
+    let parent = doc.find_mut(loc.parent)?;
+    let children = parent.regions.get_mut(&loc.region)?;
+    let node = children.remove(loc.index);
+    if children.is_empty() {
+        parent.regions.remove(&loc.region);
+    }
+    Some((node, loc))
+}
+
+fn region_len(node: &PageNode, region: &str) -> usize {
+    node.regions.get(region).map_or(0, Vec::len)
+}
+
+fn check(parent: &PageNode, region: &str, child_id: &str, current_children: usize) -> Result<(), OpError> {
+    if !is_known_catalog_id(child_id) {
+        return Err(OpError::Rejected(vec![
+            ConstraintViolation::UnknownBlock { block_id: child_id.to_string() }
+        ]));
+    }
+    match check_insert(&parent.catalog_id, region, child_id, current_children) {
+        ValidationResult::Valid          => Ok(()),
+        ValidationResult::Invalid(v)     => Err(OpError::Rejected(v)),
+    }
+}
+
+/// Mesma validação do documento aplicada à subárvore inteira — só erros bloqueiam
+fn check_subtree(node: &PageNode) -> Result<(), OpError> {
+    let errors: Vec<ConstraintViolation> = node.validate("")
+        .into_iter()
+        .filter(|v| v.severity == Severity::Error)
+        .map(|v| v.violation)
+        .collect();
+    if errors.is_empty() { Ok(()) } else { Err(OpError::Rejected(errors)) }
+}
+
+/// Props do preset coeridas pelo schema do block — mesmos tipos que o inspector grava
+fn preset_props(catalog_id: &str, def: Option<&BlockDefinition>, preset: &str) -> Result<BTreeMap<String, Option<Value>>, OpError> {
+    let found = def.and_then(|d| d.presets.iter().find(|p| p.label == preset).map(|p| (d, p)));
+    let Some((def, found)) = found else {
+        return Err(OpError::PresetNotFound { catalog_id: catalog_id.to_string(), preset: preset.to_string() });
+    };
+    let props = coerce_preset(&def.final_props(), found).map_err(OpError::InvalidProps)?;
+    Ok(props.into_iter().map(|(k, v)| (k, Some(v))).collect())
+}
+
+fn set_props(doc: &mut PageDocument, id: NodeId, props: BTreeMap<String, Option<Value>>) -> Result<PageOp, OpError> {
+    let node = doc.find_mut(id).ok_or(OpError::NodeNotFound(id))?;
+    let previous = props
+        .into_iter()
+        .map(|(key, value)| {
+            let old = match value {
+                Some(v) => node.props.insert(key.clone(), v),
+                None    => node.props.remove(&key),
+            };
+            (key, old)
+        })
+        .collect();
+    Ok(PageOp::SetProps { node: id, props: previous })
+}
+
+impl PageOp {
+    /// Valida e aplica — retorna a operação inversa
+    /// Em caso de erro o documento não é alterado
+    pub fn apply(self, doc: &mut PageDocument) -> Result<PageOp, OpError> {
+        match self {
+            PageOp::Insert { parent, region, index, mut node } => {
+                // Ids repetidos dentro da própria subárvore também quebram find/undo
+                let mut seen = std::collections::BTreeSet::new();
+                if let Some(dup) = node.ids().into_iter().find(|id| !seen.insert(*id) || doc.find(*id).is_some()) {
+                    return Err(OpError::DuplicateId(dup));
+                }
+                let target = doc.find(parent).ok_or(OpError::NodeNotFound(parent))?;
+                let len = region_len(target, &region);
+                if index > len {
+                    return Err(OpError::IndexOutOfRange { region, index, len });
+                }
+                check(target, &region, &node.catalog_id, len)?;
+                check_subtree(&node)?;
+                // Subárvore vinda de fora (JSON, outro cliente) sem versão é nova — não legado v1
+                node.stamp_versions();
+                let inverse = PageOp::Remove { node: node.id };
+                let target = doc.find_mut(parent).ok_or(OpError::NodeNotFound(parent))?;
+                target.regions.entry(region).or_default().insert(index, node);
+                Ok(inverse)
+            }
+
+            PageOp::Move { node, parent, region, index } => {
+                if node == doc.root.id {
+                    return Err(OpError::CannotRemoveRoot);
+                }
+                let moving = doc.find(node).ok_or(OpError::NodeNotFound(node))?;
+                if moving.find(parent).is_some() {
+                    return Err(OpError::MoveIntoSelf(parent));
+                }
+                let from = locate(&doc.root, node).ok_or(OpError::NodeNotFound(node))?;
+                let target = doc.find(parent).ok_or(OpError::NodeNotFound(parent))?;
+                let same_region = from.parent == parent && from.region == region;
+                let len = region_len(target, &region) - usize::from(same_region);
+                if index > len {
+                    return Err(OpError::IndexOutOfRange { region, index, len });
+                }
+                check(target, &region, &moving.catalog_id, len)?;
+                let (subtree, _) = detach(doc, node).ok_or(OpError::NodeNotFound(node))?;
+                let target = doc.find_mut(parent).ok_or(OpError::NodeNotFound(parent))?;
+                target.regions.entry(region).or_default().insert(index, subtree);
+                Ok(PageOp::Move { node, parent: from.parent, region: from.region, index: from.index })
+            }
+
+            PageOp::Remove { node } => {
+                if node == doc.root.id {
+                    return Err(OpError::CannotRemoveRoot);
+                }
+                let (subtree, loc) = detach(doc, node).ok_or(OpError::NodeNotFound(node))?;
+                Ok(PageOp::Insert { parent: loc.parent, region: loc.region, index: loc.index, node: subtree })
+            }
+
+            PageOp::SetProp { node, key, value } => {
+                let target = doc.find_mut(node).ok_or(OpError::NodeNotFound(node))?;
+                let previous = match value {
+                    Some(v) => target.props.insert(key.clone(), v),
+                    None    => target.props.remove(&key),
+                };
+                Ok(PageOp::SetProp { node, key, value: previous })
+            }
+
+            PageOp::SetProps { node, props } => set_props(doc, node, props),
+
+            PageOp::ApplyPreset { node, preset } => {
+                let target = doc.find(node).ok_or(OpError::NodeNotFound(node))?;
+                let props = preset_props(&target.catalog_id, BlockDefinition::for_catalog_id(&target.catalog_id), &preset)?;
+                set_props(doc, node, props)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LoggedOp {
+    pub op:      PageOp,
+    pub inverse: PageOp,
+}
+
+/// Histórico linear de operações — apply limpa a pilha de redo
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OperationLog {
+    done:   Vec<LoggedOp>,
+    undone: Vec<LoggedOp>,
+}
+
+impl OperationLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn apply(&mut self, doc: &mut PageDocument, op: PageOp) -> Result<(), OpError> {
+        let inverse = op.clone().apply(doc)?;
+        self.done.push(LoggedOp { op, inverse });
+        self.undone.clear();
+        Ok(())
+    }
+
+    /// Desfaz a última operação — Ok(false) quando não há o que desfazer
+    pub fn undo(&mut self, doc: &mut PageDocument) -> Result<bool, OpError> {
+        let Some(entry) = self.done.pop() else { return Ok(false) };
+        match entry.inverse.clone().apply(doc) {
+            Ok(_)  => { self.undone.push(entry); Ok(true) }
+            Err(e) => { self.done.push(entry); Err(e) }
+        }
+    }
+
+    /// Refaz a última operação desfeita — Ok(false) quando não há o que refazer
+    pub fn redo(&mut self, doc: &mut PageDocument) -> Result<bool, OpError> {
+        let Some(entry) = self.undone.pop() else { return Ok(false) };
+        match entry.op.clone().apply(doc) {
+            Ok(inverse) => { self.done.push(LoggedOp { op: entry.op, inverse }); Ok(true) }
+            Err(e)      => { self.undone.push(entry); Err(e) }
+        }
+    }
+
+    pub fn can_undo(&self) -> bool { !self.done.is_empty() }
+    pub fn can_redo(&self) -> bool { !self.undone.is_empty() }
+
+    /// Operações aplicadas, em ordem — para replay e auditoria
+    pub fn history(&self) -> &[LoggedOp] {
+        &self.done
+    }
+
+    /// Reaplica uma sequência de operações (ex: recebidas de outro cliente)
+    pub fn replay(&mut self, doc: &mut PageDocument, ops: impl IntoIterator<Item = PageOp>) -> Result<(), OpError> {
+        for op in ops {
+            self.apply(doc, op)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc() -> PageDocument {
+        PageDocument::new(
+            PageNode::new(1, "form")
+                .with_child("children", PageNode::new(2, "button").with_prop("variant", "primary")),
+        )
+    }
+
+    #[test]
+    fn test_set_prop_undo_redo() {
+        let mut doc = doc();
+        let original = doc.clone();
+        let mut log = OperationLog::new();
+        log.apply(&mut doc, PageOp::SetProp { node: 2, key: "variant".into(), value: Some("ghost".into()) }).unwrap();
+        assert_eq!(doc.find(2).unwrap().props["variant"], "ghost");
+        assert!(log.undo(&mut doc).unwrap());
+        assert_eq!(doc, original);
+        assert!(log.redo(&mut doc).unwrap());
+        assert_eq!(doc.find(2).unwrap().props["variant"], "ghost");
+    }
+
+    #[test]
+    fn test_remove_inverse_restores_position() {
+        let mut doc = doc();
+        let original = doc.clone();
+        let inverse = PageOp::Remove { node: 2 }.apply(&mut doc).unwrap();
+        assert!(doc.find(2).is_none());
+        inverse.apply(&mut doc).unwrap();
+        assert_eq!(doc, original);
+    }
+
+    #[test]
+    fn test_rejects_unknown_and_duplicate() {
+        let mut doc = doc();
+        let unknown = PageOp::Insert { parent: 1, region: "children".into(), index: 0, node: PageNode::new(9, "nonexistent") };
+        assert!(matches!(unknown.apply(&mut doc), Err(OpError::Rejected(_))));
+        let dup = PageOp::Insert { parent: 1, region: "children".into(), index: 0, node: PageNode::new(2, "button") };
+        assert_eq!(dup.apply(&mut doc), Err(OpError::DuplicateId(2)));
+        let self_dup = PageOp::Insert {
+            parent: 1,
+            region: "children".into(),
+            index: 0,
+            node: PageNode::new(7, "block.card").with_child("content", PageNode::new(7, "button")),
+        };
+        assert_eq!(self_dup.apply(&mut doc), Err(OpError::DuplicateId(7)));
+        assert!(doc.find(7).is_none());
+        assert_eq!(PageOp::Remove { node: 1 }.apply(&mut doc), Err(OpError::CannotRemoveRoot));
+    }
+
+    #[test]
+    fn test_insert_places_node_and_inverse_removes_it() {
+        let mut doc = doc();
+        let original = doc.clone();
+        let op = PageOp::Insert { parent: 1, region: "children".into(), index: 0, node: PageNode::new(3, "button") };
+        let inverse = op.apply(&mut doc).unwrap();
+        assert_eq!(inverse, PageOp::Remove { node: 3 });
+        let order: Vec<NodeId> = doc.root.regions["children"].iter().map(|n| n.id).collect();
+        assert_eq!(order, vec![3, 2]);
+        inverse.apply(&mut doc).unwrap();
+        assert_eq!(doc, original);
+    }
+
+    #[test]
+    fn test_insert_validates_whole_subtree() {
+        let mut doc = doc();
+        let original = doc.clone();
+        // Raiz válida, filho desconhecido dois níveis abaixo
+        let nested = PageNode::new(3, "form")
+            .with_child("children", PageNode::new(4, "form").with_child("children", PageNode::new(5, "nonexistent")));
+        let op = PageOp::Insert { parent: 1, region: "children".into(), index: 1, node: nested };
+        let Err(OpError::Rejected(violations)) = op.apply(&mut doc) else { panic!("nested child accepted") };
+        assert!(violations.contains(&ConstraintViolation::UnknownBlock { block_id: "nonexistent".into() }));
+        assert_eq!(doc, original);
+    }
+
+    #[test]
+    fn test_move_and_undo() {
+        let mut doc = doc();
+        let mut log = OperationLog::new();
+        log.apply(&mut doc, PageOp::Insert {
+            parent: 1,
+            region: "children".into(),
+            index: 1,
+            node: PageNode::new(3, "form"),
+        }).unwrap();
+        let before = doc.clone();
+
+        log.apply(&mut doc, PageOp::Move { node: 2, parent: 3, region: "children".into(), index: 0 }).unwrap();
+        assert_eq!(doc.find(3).unwrap().regions["children"][0].id, 2);
+        assert_eq!(doc.root.regions["children"].len(), 1);
+
+        assert!(log.undo(&mut doc).unwrap());
+        assert_eq!(doc, before);
+
+        assert_eq!(
+            PageOp::Move { node: 3, parent: 3, region: "children".into(), index: 0 }.apply(&mut doc),
+            Err(OpError::MoveIntoSelf(3))
+        );
+        assert_eq!(
+            PageOp::Move { node: 2, parent: 3, region: "children".into(), index: 5 }.apply(&mut doc),
+            Err(OpError::IndexOutOfRange { region: "children".into(), index: 5, len: 0 })
+        );
+        assert_eq!(doc, before);
+    }
+
+    #[test]
+    fn test_apply_preset_writes_typed_props() {
+        use crate::block_types::{BlockPreset, BlockPropDef, PropFieldType, PropScope};
+        let base = crate::generated::block_definitions::BLOCK_DEFINITIONS_GENERATED.first().unwrap();
+        let def = BlockDefinition {
+            props_schema: &[
+                BlockPropDef { key: "columns", label: "Columns", field: PropFieldType::Number, default: None, scope: PropScope::Structural, css: None },
+                BlockPropDef { key: "sticky", label: "Sticky", field: PropFieldType::Toggle, default: None, scope: PropScope::Structural, css: None },
+            ],
+            presets: &[
+                BlockPreset { label: "Sticky three", props: &[("columns", "3"), ("sticky", "true")] },
+                BlockPreset { label: "Broken", props: &[("columns", "three")] },
+            ],
+            ..base.clone()
+        };
+
+        let props = preset_props("block.test", Some(&def), "Sticky three").unwrap();
+        assert_eq!(props["columns"], Some(serde_json::json!(3.0)));
+        assert_eq!(props["sticky"], Some(Value::Bool(true)));
+        assert!(matches!(preset_props("block.test", Some(&def), "Broken"), Err(OpError::InvalidProps(_))));
+
+        // Inversa restaura o valor anterior e remove as keys novas
+        let mut doc = doc();
+        let original = doc.clone();
+        let inverse = PageOp::SetProps { node: 2, props }.apply(&mut doc).unwrap();
+        assert_eq!(doc.find(2).unwrap().props["columns"], serde_json::json!(3.0));
+        inverse.apply(&mut doc).unwrap();
+        assert_eq!(doc, original);
+
+        let missing = PageOp::ApplyPreset { node: 2, preset: "Nope".into() }.apply(&mut doc);
+        assert_eq!(missing, Err(OpError::PresetNotFound { catalog_id: "button".into(), preset: "Nope".into() }));
+        assert_eq!(missing.unwrap_err().to_string(), "'button' has no preset 'Nope'");
+    }
+
+    #[test]
+    fn test_ops_serialize_tagged() {
+        let op = PageOp::Remove { node: 7 };
+        let json = serde_json::to_string(&op).unwrap();
+        assert_eq!(json, r#"{"op":"remove","node":7}"#);
+        assert_eq!(serde_json::from_str::<PageOp>(&json).unwrap(), op);
+    }
+}
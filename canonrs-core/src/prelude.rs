@@ -1,3 +1,5 @@
 //! Prelude module - controlled exports
 
-pub use crate::infra::theme::{ThemeProvider, ThemeContext, ThemeMode, CanonRSRoot, use_theme};
+pub use crate::infra::theme::{ThemeProvider, ThemeScope, ThemeContext, ThemeMode, CanonRSRoot, use_theme};
+pub use crate::infra::density::{DensityProvider, DensityContext, Density, use_density};
+pub use crate::infra::uid::UidScope;
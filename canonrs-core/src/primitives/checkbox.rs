@@ -3,7 +3,7 @@
 //! Checkbox Primitive - HTML puro + ARIA
 
 use leptos::prelude::*;
-use crate::meta::DisabledState;
+use crate::meta::{CheckedState, DisabledState, RequiredState};
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Default, Debug)]
 pub enum CheckboxState {
@@ -33,21 +33,34 @@ impl CheckboxState {
     }
 }
 
+impl From<CheckboxState> for CheckedState {
+    fn from(state: CheckboxState) -> Self {
+        match state {
+            CheckboxState::Unchecked     => Self::Unchecked,
+            CheckboxState::Checked       => Self::Checked,
+            CheckboxState::Indeterminate => Self::Mixed,
+        }
+    }
+}
+
 #[component]
 pub fn CheckboxPrimitive(
     children: Children,
     #[prop(default = CheckboxState::Unchecked)] checked: CheckboxState,
     #[prop(default = DisabledState::Enabled)] disabled: DisabledState,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
     #[prop(into, default = String::new())] name: String,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
     let uid_cb = crate::infra::uid::generate("cb");
+    let state = CheckedState::from(checked);
     view! {
         <label
             data-rs-checkbox=""
             data-rs-uid=uid_cb
             data-rs-interaction="init"
-            data-rs-selection=checked.as_str()
+            data-rs-checked=state.as_str()
+            data-rs-required=if required.required() { Some(required.as_str()) } else { None }
             data-rs-disabled=if disabled.disabled() { Some("disabled") } else { None }
             aria-disabled=disabled.aria_disabled()
             class=class
@@ -55,9 +68,11 @@ pub fn CheckboxPrimitive(
             <input
                 type="checkbox"
                 data-rs-checkbox-input=""
-                checked=checked.is_checked()
+                checked=state.checked()
+                prop:indeterminate=state.indeterminate()
                 disabled=disabled.disabled()
-                aria-checked=checked.aria_checked()
+                required=required.required()
+                aria-checked=state.aria_checked()
                 name={if name.is_empty() { None } else { Some(name) }}
             />
             {children()}
@@ -6,23 +6,8 @@ use leptos::prelude::*;
 use crate::primitives::table::SortDirection;
 use crate::meta::SelectionState;
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
-pub enum DataTableDensity {
-    Compact,
-    #[default]
-    Comfortable,
-    Spacious,
-}
-
-impl DataTableDensity {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Self::Compact     => "compact",
-            Self::Comfortable => "comfortable",
-            Self::Spacious    => "spacious",
-        }
-    }
-}
+/// Densidade global (`data-density`) — sem escala própria da tabela
+pub type DataTableDensity = crate::infra::density::Density;
 
 
 
@@ -36,7 +21,8 @@ impl DataTableDensity {
 #[component]
 pub fn DataTablePrimitive(
     children: Children,
-    #[prop(default = DataTableDensity::Comfortable)] density: DataTableDensity,
+    /// None: herda a densidade do ancestral (DensityProvider / `<html data-density>`)
+    #[prop(into, default = None)] density: Option<DataTableDensity>,
     #[prop(into, default = String::new())] class: String,
     #[prop(into, default = String::new())] name: String,
 ) -> impl IntoView {
@@ -46,7 +32,7 @@ pub fn DataTablePrimitive(
             data-rs-datatable=""
             data-rs-uid=uid_dt
             data-rs-interaction="data"
-            data-rs-density=density.as_str()
+            data-density=density.map(|d| d.as_str())
             data-rs-name=name
             class=class
         >
@@ -3,7 +3,7 @@
 //! Field Primitive - HTML puro + ARIA
 
 use leptos::prelude::*;
-use crate::meta::DisabledState;
+use crate::meta::{DisabledState, InvalidState, RequiredState};
 
 #[derive(Clone, Copy, PartialEq, Default, Debug)]
 pub enum FieldValidationState {
@@ -29,22 +29,32 @@ impl FieldValidationState {
     }
 }
 
+impl From<FieldValidationState> for InvalidState {
+    fn from(state: FieldValidationState) -> Self {
+        (state == FieldValidationState::Invalid).into()
+    }
+}
+
 #[component]
 pub fn FieldPrimitive(
     children: Children,
     #[prop(default = FieldValidationState::Idle)] validation: FieldValidationState,
     #[prop(default = DisabledState::Enabled)] disabled: DisabledState,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
     let uid_fi = crate::infra::uid::generate("fi");
+    let invalid = InvalidState::from(validation);
     view! {
         <div
             data-rs-field=""
             data-rs-uid=uid_fi
             data-rs-interaction="init"
             data-rs-validation=validation.as_str()
+            data-rs-invalid=if invalid.invalid() { Some(invalid.as_str()) } else { None }
+            data-rs-required=if required.required() { Some(required.as_str()) } else { None }
             data-rs-disabled=if disabled.disabled() { Some("disabled") } else { None }
-            aria-invalid=validation.aria_invalid()
+            aria-invalid=invalid.aria_invalid()
             aria-disabled=disabled.aria_disabled()
             class=class
         >
@@ -57,15 +67,15 @@ pub fn FieldPrimitive(
 pub fn FieldLabelPrimitive(
     children: Children,
     #[prop(into, default = String::new())] html_for: String,
-    #[prop(default = false)] required: bool,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
+    // aria-required pertence ao controle, não ao label — o label só recebe o marcador visual
     view! {
         <label
             data-rs-field-label=""
             for={if html_for.is_empty() { None } else { Some(html_for) }}
-            data-rs-required={if required { Some("") } else { None }}
-            aria-required={if required { Some("true") } else { None }}
+            data-rs-required=if required.required() { Some(required.as_str()) } else { None }
             class=class
         >
             {children()}
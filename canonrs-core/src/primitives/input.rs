@@ -3,7 +3,7 @@
 //! Input Primitive - HTML puro
 
 use leptos::prelude::*;
-use crate::meta::DisabledState;
+use crate::meta::{DisabledState, InvalidState, ReadonlyState, RequiredState};
 
 #[derive(Clone, PartialEq, Default, Debug)]
 pub enum InputVariant {
@@ -49,6 +49,9 @@ pub fn InputPrimitive(
     #[prop(into, default = String::new())] value: String,
     #[prop(into, default = String::new())] rs_value: String,
     #[prop(default = DisabledState::Enabled)] disabled: DisabledState,
+    #[prop(default = ReadonlyState::Editable)] readonly: ReadonlyState,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
+    #[prop(default = InvalidState::Valid)] invalid: InvalidState,
     #[prop(into, default = String::new())] placeholder: String,
     #[prop(into, default = String::new())] aria_label: String,
     #[prop(default = InputVariant::Default)] variant: InputVariant,
@@ -56,6 +59,8 @@ pub fn InputPrimitive(
 ) -> impl IntoView {
     let uid_inp = crate::infra::uid::generate("inp");
     let aria_disabled = if disabled == DisabledState::Disabled { "true" } else { "false" };
+    // variant="error" já sinaliza erro visual — aria-invalid acompanha
+    let invalid = if variant == InputVariant::Error { InvalidState::Invalid } else { invalid };
     view! {
         <input
             data-rs-input=""
@@ -69,8 +74,14 @@ pub fn InputPrimitive(
             prop:value=value
             data-rs-value=rs_value
             placeholder=placeholder
+            data-rs-readonly=if readonly.readonly() { Some(readonly.as_str()) } else { None }
+            data-rs-required=if required.required() { Some(required.as_str()) } else { None }
+            data-rs-invalid=if invalid.invalid() { Some(invalid.as_str()) } else { None }
             disabled=disabled.as_bool()
+            readonly=readonly.readonly()
+            required=required.required()
             aria-disabled=aria_disabled
+            aria-invalid=invalid.aria_invalid()
             aria-label=aria_label
         />
     }
@@ -3,7 +3,7 @@
 //! Tree Primitive - HTML puro + ARIA
 
 use leptos::prelude::*;
-use crate::meta::{SelectionState, DisabledState, ActivityState};
+use crate::meta::{SelectionState, DisabledState, ActivityState, ExpandedState, CheckedState};
 
 #[derive(Clone, Copy, PartialEq, Default, Debug)]
 pub enum TreeSelectionMode {
@@ -58,7 +58,9 @@ pub fn TreeItemPrimitive(
     children: Children,
     #[prop(default = SelectionState::Unselected)] selected: SelectionState,
     #[prop(default = ActivityState::Inactive)] focused: ActivityState,
-    #[prop(default = false)] expanded: bool,
+    #[prop(default = ExpandedState::Collapsed)] expanded: ExpandedState,
+    /// Só em trees com checkboxes — `Mixed` quando parte dos filhos está marcada
+    #[prop(optional_no_strip)] checked: Option<CheckedState>,
     #[prop(default = DisabledState::Enabled)] disabled: DisabledState,
     #[prop(default = false)] has_children: bool,
     #[prop(default = 0u8)] depth: u8,
@@ -71,13 +73,15 @@ pub fn TreeItemPrimitive(
             data-rs-tree-item=""
             data-rs-selection=if selected == SelectionState::Selected { Some("selected") } else { None }
             data-rs-focused=focused.as_str()
-            data-rs-expanded={if has_children { Some(if expanded { "true" } else { "false" }) } else { None }}
+            data-rs-expanded={if has_children { Some(expanded.as_str()) } else { None }}
+            data-rs-checked=checked.map(|c| c.as_str())
             data-rs-disabled=if disabled.disabled() { Some("disabled") } else { None }
             data-rs-depth=depth_str
             role="treeitem"
             tabindex=tabindex
             aria-selected=if selected == SelectionState::Selected { Some("true") } else { None }
-            aria-expanded={if has_children { Some(if expanded { "true" } else { "false" }) } else { None }}
+            aria-expanded={if has_children { Some(expanded.aria_expanded()) } else { None }}
+            aria-checked=checked.map(|c| c.aria_checked())
             aria-disabled=disabled.aria_disabled()
             class=class
         >
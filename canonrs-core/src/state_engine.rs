@@ -6,6 +6,7 @@
 use crate::meta::{
     VisibilityState, ActivityState, SelectionState,
     ToggleState, StateKind, DisabledState, LoadingState,
+    InvalidState, ReadonlyState, RequiredState, ExpandedState, CheckedState,
     ToDataAttr,
 };
 
@@ -131,6 +132,89 @@ pub fn loading_attrs(state: LoadingState) -> LoadingAttrs {
     }
 }
 
+// ── Invalid ──────────────────────────────────────────────────────────────────
+
+pub struct InvalidAttrs {
+    pub data_rs_invalid: Option<&'static str>,
+    pub aria_invalid:    Option<&'static str>,
+}
+
+pub fn invalid_attrs(state: InvalidState) -> InvalidAttrs {
+    let (_, value) = state.to_data_attr();
+    InvalidAttrs {
+        data_rs_invalid: if state.invalid() { Some(value) } else { None },
+        aria_invalid:    state.aria_invalid(),
+    }
+}
+
+// ── Readonly ─────────────────────────────────────────────────────────────────
+
+pub struct ReadonlyAttrs {
+    pub data_rs_readonly: Option<&'static str>,
+    pub aria_readonly:    Option<&'static str>,
+    pub readonly:         bool,
+}
+
+pub fn readonly_attrs(state: ReadonlyState) -> ReadonlyAttrs {
+    let (_, value) = state.to_data_attr();
+    ReadonlyAttrs {
+        data_rs_readonly: if state.readonly() { Some(value) } else { None },
+        aria_readonly:    state.aria_readonly(),
+        readonly:         state.readonly(),
+    }
+}
+
+// ── Required ─────────────────────────────────────────────────────────────────
+
+pub struct RequiredAttrs {
+    pub data_rs_required: Option<&'static str>,
+    pub aria_required:    Option<&'static str>,
+    pub required:         bool,
+}
+
+pub fn required_attrs(state: RequiredState) -> RequiredAttrs {
+    let (_, value) = state.to_data_attr();
+    RequiredAttrs {
+        data_rs_required: if state.required() { Some(value) } else { None },
+        aria_required:    state.aria_required(),
+        required:         state.required(),
+    }
+}
+
+// ── Expanded ─────────────────────────────────────────────────────────────────
+
+pub struct ExpandedAttrs {
+    pub data_rs_expanded: &'static str,
+    pub aria_expanded:    &'static str,
+}
+
+pub fn expanded_attrs(state: ExpandedState) -> ExpandedAttrs {
+    let (_, value) = state.to_data_attr();
+    ExpandedAttrs {
+        data_rs_expanded: value,
+        aria_expanded:    state.aria_expanded(),
+    }
+}
+
+// ── Checked ──────────────────────────────────────────────────────────────────
+
+pub struct CheckedAttrs {
+    pub data_rs_checked: &'static str,
+    pub aria_checked:    &'static str,
+    pub checked:         bool,
+    pub indeterminate:   bool,
+}
+
+pub fn checked_attrs(state: CheckedState) -> CheckedAttrs {
+    let (_, value) = state.to_data_attr();
+    CheckedAttrs {
+        data_rs_checked: value,
+        aria_checked:    state.aria_checked(),
+        checked:         state.checked(),
+        indeterminate:   state.indeterminate(),
+    }
+}
+
 // ── resolve_state ────────────────────────────────────────────────────────────
 
 pub struct ResolvedState {
@@ -65,7 +65,10 @@ fn sync_col_toggle_state(table: &HtmlElement) {
 }
 
 fn sync_density_state(table: &HtmlElement) {
-    let current = table.get_attribute("data-rs-density").unwrap_or_else(|| "comfortable".to_string());
+    // data-density global — a tabela herda do ancestral mais próximo quando não define a sua
+    let current = table.closest("[data-density]").ok().flatten()
+        .and_then(|el| el.get_attribute("data-density"))
+        .unwrap_or_else(|| "comfortable".to_string());
     let root: web_sys::Element = table.clone().into();
     if let Ok(btns) = root.query_selector_all("[data-rs-density-btn]") {
         for i in 0..btns.length() {
@@ -301,7 +304,7 @@ fn bind_density(table: &HtmlElement) {
                         let Some(btn) = t.closest("[data-rs-density-btn]").ok().flatten() else { return };
                         let Some(rc) = context::find_root(&btn, "[data-rs-datatable]") else { return };
                         let Some(d) = btn.get_attribute("data-rs-density-btn") else { return };
-                        let _ = rc.set_attribute("data-rs-density", &d);
+                        let _ = rc.set_attribute("data-density", &d);
                         if let Ok(all) = rc.query_selector_all("[data-rs-density-btn]") {
                             for j in 0..all.length() {
                                 if let Some(b) = all.item(j).and_then(|n| n.dyn_into::<web_sys::Element>().ok()) {
@@ -3,7 +3,7 @@
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::Element;
-use crate::runtime::{lifecycle, state, query, focus};
+use crate::runtime::{lifecycle, state, query, focus, aria};
 
 pub fn init(root: Element) {
     if !lifecycle::init_guard(&root) { return; }
@@ -14,10 +14,12 @@ pub fn init(root: Element) {
     {
         let root_cb = root.clone();
         let cb = Closure::<dyn Fn(web_sys::Event)>::new(move |e: web_sys::Event| {
-            let is_checked = e.target()
-                .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
-                .map(|i| i.checked())
-                .unwrap_or(false);
+            let Some(input) = e.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) else { return };
+            let is_checked = input.checked();
+            // Toggle resolve o indeterminate — mesmos attrs que o CheckboxPrimitive renderiza
+            input.set_indeterminate(false);
+            aria::set_checked(&input, is_checked);
+            let _ = root_cb.set_attribute("data-rs-checked", checked_attr(is_checked));
             if is_checked {
                 state::remove_state(&root_cb, "unchecked");
                 state::add_state(&root_cb, "checked");
@@ -32,3 +34,19 @@ pub fn init(root: Element) {
 
     focus::init_within(&root);
 }
+
+/// `data-rs-checked` depois de um change — nunca "mixed"
+fn checked_attr(checked: bool) -> &'static str {
+    if checked { "checked" } else { "unchecked" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_resolves_to_checked_or_unchecked() {
+        assert_eq!(checked_attr(true), "checked");
+        assert_eq!(checked_attr(false), "unchecked");
+    }
+}
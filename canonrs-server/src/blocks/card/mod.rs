@@ -1,2 +1,3 @@
 pub mod card_block;
+pub mod api;
 pub use card_block::{CardBlock, CardVariant};
@@ -1,2 +1,3 @@
 pub mod data_table_block;
+pub mod api;
 pub use data_table_block::DataTableBlock;
@@ -1,2 +1,3 @@
 pub mod form_field_block;
+pub mod api;
 pub use form_field_block::FormFieldBlock;
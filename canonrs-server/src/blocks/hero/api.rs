@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 pub const HERO_API: ComponentApi = ComponentApi {
     id: "hero",
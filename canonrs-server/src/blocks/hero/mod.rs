@@ -1,2 +1,3 @@
 pub mod hero_block;
+pub mod api;
 pub use hero_block::{Hero, HeroVariant};
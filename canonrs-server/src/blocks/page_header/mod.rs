@@ -1,4 +1,5 @@
 pub mod page_header_block;
+pub mod api;
 pub use page_header_block::PageHeader;
 
 #[cfg(feature = "examples")]
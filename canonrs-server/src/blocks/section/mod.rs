@@ -1,2 +1,3 @@
 pub mod section_block;
+pub mod api;
 pub use section_block::SectionBlock;
@@ -1,4 +1,5 @@
 pub mod sidebar_layout_block;
+pub mod api;
 pub use sidebar_layout_block::SidebarLayout;
 
 #[cfg(feature = "examples")]
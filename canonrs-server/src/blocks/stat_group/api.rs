@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 // imports: use canonrs::primitives::{GridPrimitive as Grid, GridCols}; 
 
@@ -9,7 +9,7 @@ pub const STATGROUPBLOCK_API: ComponentApi = ComponentApi {
     description: "Dashboard stat group block for displaying metric collections",
     props: &[
         PropDef { name: "stats", kind: PropType::Children, required: false, default: None, description: "Stats slot" },
-        PropDef { name: "cols", kind: PropType::String, required: false, default: Some("three"), description: "Number of grid columns" },
+        PropDef { name: "cols", kind: PropType::Enum(&["1", "2", "3", "4", "6", "12", "auto"]), required: false, default: Some("3"), description: "Number of grid columns" },
         PropDef { name: "class", kind: PropType::String, required: false, default: Some(""), description: "Additional CSS class names" },
     ],
 };
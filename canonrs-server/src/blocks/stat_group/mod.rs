@@ -1,2 +1,3 @@
 pub mod stat_group_block;
+pub mod api;
 pub use stat_group_block::StatGroupBlock;
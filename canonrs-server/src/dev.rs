@@ -1,8 +1,8 @@
-//! CanonRS Dev Runtime — SSE reload route
-//! Transparente para o app — injeta via with_dev_reload(router)
-//! Ativo apenas em debug builds
+//! CanonRS Dev Runtime — SSE reload route + DOM contract verifier
+//! Transparente para o app — injeta via with_dev_reload(router) / with_dom_verifier(router)
+//! Ativo apenas em debug builds — os módulos nem compilam em release
 
-#[cfg(feature = "ssr")]
+#[cfg(all(feature = "ssr", debug_assertions))]
 pub mod reload {
     use axum::{Router, response::sse::{Event, Sse, KeepAlive}};
     use std::sync::Arc;
@@ -56,3 +56,92 @@ pub mod reload {
         }))
     }
 }
+
+#[cfg(all(feature = "ssr", debug_assertions))]
+pub mod verify {
+    use axum::{Router, body::{Body, Bytes}, extract::Request, http::{header, HeaderValue}, middleware::{self, Next}, response::Response};
+    use futures_util::{stream, StreamExt};
+    use canonrs_core::infra::{aria_audit::AriaAudit, dom_verify::DomVerifier, html_tree::HtmlTree, hydration};
+
+    /// Acima disso a resposta passa sem verificação — o verifier não segura páginas enormes em memória
+    pub const MAX_VERIFY_BYTES: usize = 4 * 1024 * 1024;
+
+    /// Verifica cada resposta text/html contra o DOM contract e a auditoria ARIA
+    /// Uso: let app = canonrs::with_dom_verifier(app);
+    /// Loga cada issue e expõe os totais em `x-canonrs-dom-errors` / `x-canonrs-aria-errors`
+    /// Também grava o estado `data-rs-*` do HTML no manifest de hydration — o client compara contra ele
+    pub fn with_dom_verifier(router: Router) -> Router {
+        router.layer(middleware::from_fn(verify_response))
+    }
+
+    async fn verify_response(req: Request, next: Next) -> Response {
+        let route = req.uri().path().to_string();
+        let response = next.run(req).await;
+        let headers = response.headers();
+        let is_html = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("text/html"));
+        // Body comprimido não é HTML legível — verificar exige a resposta sem compressão
+        let is_encoded = headers
+            .get(header::CONTENT_ENCODING)
+            .is_some_and(|v| v != "identity");
+        let too_large = headers
+            .get(header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok()?.parse::<usize>().ok())
+            .is_some_and(|len| len > MAX_VERIFY_BYTES);
+        if !is_html || is_encoded || too_large {
+            return response;
+        }
+
+        // Leptos faz streaming — bufferiza até MAX_VERIFY_BYTES; passou disso, repassa o stream intacto
+        let (mut parts, body) = response.into_parts();
+        let mut body = body.into_data_stream();
+        let mut chunks: Vec<Bytes> = vec![];
+        let mut size = 0;
+        while let Some(chunk) = body.next().await {
+            match chunk {
+                Ok(chunk) => {
+                    size += chunk.len();
+                    chunks.push(chunk);
+                }
+                Err(e) => {
+                    leptos::logging::warn!("[CanonRS][DOM] {} — could not read body: {}", route, e);
+                    return Response::from_parts(parts, Body::empty());
+                }
+            }
+            if size > MAX_VERIFY_BYTES {
+                leptos::logging::warn!("[CanonRS][DOM] {} — body over {} bytes, skipping verification", route, MAX_VERIFY_BYTES);
+                let head = stream::iter(chunks.into_iter().map(Ok::<_, axum::Error>));
+                return Response::from_parts(parts, Body::from_stream(head.chain(body)));
+            }
+        }
+        let bytes = Bytes::from(chunks.concat());
+
+        let html = String::from_utf8_lossy(&bytes).into_owned();
+        let tree = HtmlTree::parse(&html);
+        let report = DomVerifier::new().verify_tree(&tree);
+        for issue in &report.issues {
+            leptos::logging::warn!("[CanonRS][DOM][{:?}] {} {} — {}", issue.severity, route, issue.path, issue.message);
+        }
+        let aria = AriaAudit::new().audit_tree(&tree);
+        for issue in &aria.issues {
+            leptos::logging::warn!("[CanonRS][ARIA][{:?}] {} {} — {}", issue.severity, route, issue.path, issue.message);
+        }
+        if let Ok(value) = HeaderValue::from_str(&report.errors.to_string()) {
+            parts.headers.insert("x-canonrs-dom-errors", value);
+        }
+        if let Ok(value) = HeaderValue::from_str(&aria.errors.to_string()) {
+            parts.headers.insert("x-canonrs-aria-errors", value);
+        }
+        let body = match hydration::stamp_ssr_state(&html, &tree) {
+            Some(stamped) => {
+                // o manifest cresceu — o tamanho sai do body
+                parts.headers.remove(header::CONTENT_LENGTH);
+                Body::from(stamped)
+            }
+            None => Body::from(bytes),
+        };
+        Response::from_parts(parts, body)
+    }
+}
@@ -0,0 +1,220 @@
+//! CanonRS Inspector — property panel generated from block/component schemas
+//! Input for Text/Number, Select for Select/Enum, Switch for Toggle/Bool, Input (tokens) + ColorPicker (hex) for Color
+//! Presets go through `PageOp::ApplyPreset` — same coercion and value types as the builder
+//! Two-way: edits write `PageNode::props`-shaped JSON into the signal; external writes re-render the field
+//! DOM → signal via native input/change events and `rs-change` from the interaction engines
+
+pub mod schema;
+
+pub use schema::{picker_color, picker_seed, scope_name, scope_title, FieldKind, InspectorField, InspectorSchema, NEUTRAL_PICKER_COLOR, SCOPE_ORDER};
+
+use std::collections::BTreeMap;
+use leptos::prelude::*;
+use serde_json::Value;
+use canonrs_core::meta::SelectionState;
+use canonrs_core::page_document::{PageDocument, PageNode};
+use canonrs_core::page_ops::PageOp;
+use crate::ui::button::{Button, ButtonSize, ButtonVariant};
+use crate::ui::color_picker::ColorPicker;
+use crate::ui::field::{Field, FieldDescription, FieldLabel, FieldSet};
+use crate::ui::input::Input;
+use crate::ui::select::{Select, SelectContent, SelectItem, SelectTrigger, SelectValue};
+use crate::ui::switch::Switch;
+
+pub type PropsSignal = RwSignal<BTreeMap<String, Value>>;
+
+#[component]
+pub fn PropInspector(
+    schema: InspectorSchema,
+    props: PropsSignal,
+    #[prop(into, default = String::new())] class: String,
+) -> impl IntoView {
+    let presets = (!schema.presets.is_empty()).then(|| {
+        let buttons = schema.presets
+            .iter()
+            .map(|preset| {
+                let label = preset.label;
+                let catalog_id = schema.catalog_id.clone();
+                view! {
+                    <Button
+                        variant=ButtonVariant::Outline
+                        size=ButtonSize::Sm
+                        on:click=move |_| apply_preset(&catalog_id, label, props)
+                    >
+                        {preset.label}
+                    </Button>
+                }
+            })
+            .collect_view();
+        view! { <div data-rs-prop-inspector-presets="">{buttons}</div> }
+    });
+
+    let groups = schema.grouped()
+        .into_iter()
+        .map(|(scope, fields)| view! {
+            <FieldSet>
+                <div data-rs-prop-inspector-group=scope_name(scope)>
+                    <span data-rs-prop-inspector-group-title="">{scope_title(scope)}</span>
+                    {fields.into_iter().map(|field| view! { <PropField field=field props=props /> }).collect_view()}
+                </div>
+            </FieldSet>
+        })
+        .collect_view();
+
+    view! {
+        <div data-rs-prop-inspector="" class=class>
+            {presets}
+            {groups}
+        </div>
+    }
+}
+
+/// Um campo — re-renderiza só quando o valor muda fora dele (preset, undo, outro painel)
+#[component]
+pub fn PropField(field: InspectorField, props: PropsSignal) -> impl IntoView {
+    let key = field.key;
+    let echo = StoredValue::new(props.with_untracked(|p| p.get(key).cloned()));
+    let revision = RwSignal::new(0u32);
+
+    Effect::new(move |_| {
+        let value = props.with(|p| p.get(key).cloned());
+        if echo.with_value(|last| *last != value) {
+            echo.set_value(value);
+            revision.update(|r| *r += 1);
+        }
+    });
+
+    let write = move |value: Option<Value>| {
+        echo.set_value(value.clone());
+        props.update(|p| match value {
+            Some(v) => { p.insert(key.to_string(), v); }
+            None    => { p.remove(key); }
+        });
+    };
+
+    let label = field.label.clone();
+    let description = (!field.description.is_empty()).then(|| view! {
+        <FieldDescription>{field.description}</FieldDescription>
+    });
+
+    let control = move || {
+        revision.track();
+        let current = props.with_untracked(|p| p.get(key).map(display_value))
+            .or_else(|| field.default.map(str::to_string))
+            .unwrap_or_default();
+        render_control(&field, current, write)
+    };
+
+    view! {
+        <Field>
+            <FieldLabel>{label}</FieldLabel>
+            {control}
+            {description}
+        </Field>
+    }
+}
+
+fn render_control(field: &InspectorField, current: String, write: impl Fn(Option<Value>) + Copy + Send + Sync + 'static) -> AnyView {
+    let key = field.key;
+    let label = field.label.clone();
+    match &field.kind {
+        FieldKind::Text => view! {
+            <div data-rs-prop-field=key on:input=move |ev| write(Some(Value::String(event_target_value(&ev))))>
+                <Input name=key value=current aria_label=label />
+            </div>
+        }.into_any(),
+
+        FieldKind::Number => {
+            let field = field.clone();
+            view! {
+                <div
+                    data-rs-prop-field=key
+                    on:input=move |ev| {
+                        let raw = event_target_value(&ev);
+                        write((!raw.trim().is_empty()).then(|| field.to_value(&raw)));
+                    }
+                >
+                    <Input name=key input_type="number" value=current aria_label=label />
+                </div>
+            }.into_any()
+        }
+
+        FieldKind::Select(options) => {
+            let field = field.clone();
+            let selected_label = options.iter()
+                .find(|(v, _)| *v == current)
+                .map(|(_, l)| l.to_string())
+                .unwrap_or_default();
+            let items = options.iter()
+                .map(|(value, text)| {
+                    let selected = if *value == current { SelectionState::Selected } else { SelectionState::Unselected };
+                    view! { <SelectItem value=*value selected=selected>{*text}</SelectItem> }
+                })
+                .collect_view();
+            view! {
+                <div
+                    data-rs-prop-field=key
+                    on:rs-change=move |ev| {
+                        if let Some(v) = event_target::<leptos::web_sys::Element>(&ev).get_attribute("data-rs-value") {
+                            write(Some(field.to_value(&v)));
+                        }
+                    }
+                >
+                    <Select>
+                        <SelectTrigger>
+                            <SelectValue placeholder=label>{selected_label}</SelectValue>
+                        </SelectTrigger>
+                        <SelectContent>{items}</SelectContent>
+                    </Select>
+                </div>
+            }.into_any()
+        }
+
+        FieldKind::Toggle => view! {
+            <div data-rs-prop-field=key on:change=move |ev| write(Some(Value::Bool(event_target_checked(&ev))))>
+                <Switch name=key checked=current == "true" />
+            </div>
+        }.into_any(),
+
+        FieldKind::Color => {
+            // Input aceita tokens (`var(--…)`); o picker sempre aparece, semeado com hex do valor, do default ou neutro
+            let seed = picker_seed(&current, field.default).to_string();
+            view! {
+                <div
+                    data-rs-prop-field=key
+                    on:input=move |ev| {
+                        let raw = event_target_value(&ev);
+                        write((!raw.trim().is_empty()).then(|| Value::String(raw)));
+                    }
+                    on:rs-change=move |ev| {
+                        if let Some(v) = event_target::<leptos::web_sys::Element>(&ev).get_attribute("data-rs-value") {
+                            write(Some(Value::String(v)));
+                        }
+                    }
+                >
+                    <Input name=key value=current placeholder="var(--token) or #rrggbb" aria_label=label />
+                    <ColorPicker value=seed />
+                </div>
+            }.into_any()
+        }
+    }
+}
+
+/// Roda `PageOp::ApplyPreset` sobre um documento de um node só com as props atuais
+fn apply_preset(catalog_id: &str, preset: &str, props: PropsSignal) {
+    props.update(|p| {
+        let mut doc = PageDocument::new(PageNode { props: std::mem::take(p), ..PageNode::new(0, catalog_id) });
+        if let Err(e) = (PageOp::ApplyPreset { node: 0, preset: preset.to_string() }).apply(&mut doc) {
+            leptos::logging::warn!("[CanonRS][Inspector] {}", e);
+        }
+        *p = doc.root.props;
+    });
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null      => String::new(),
+        other            => other.to_string(),
+    }
+}
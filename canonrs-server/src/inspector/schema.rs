@@ -0,0 +1,256 @@
+//! Inspector schema — BlockPropDef and ComponentApi normalized to one field list
+//! Blocks/layouts: `final_props()` + presets
+//! UI components: ComponentApi props (Children excluded — those are regions)
+
+use serde_json::Value;
+use canonrs_core::block_types::{BlockDefinition, BlockPreset, PropFieldType, PropScope};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldKind {
+    Text,
+    Number,
+    /// (value, label)
+    Select(Vec<(&'static str, &'static str)>),
+    Toggle,
+    Color,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InspectorField {
+    pub key:         &'static str,
+    pub label:       String,
+    pub description: &'static str,
+    pub kind:        FieldKind,
+    pub default:     Option<&'static str>,
+    pub scope:       PropScope,
+}
+
+impl InspectorField {
+    /// Converte o texto vindo do DOM / preset no Value guardado em `PageNode::props`
+    pub fn to_value(&self, raw: &str) -> Value {
+        match self.kind {
+            FieldKind::Toggle => Value::Bool(raw == "true"),
+            FieldKind::Number => raw.trim().parse::<f64>().ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .unwrap_or_else(|| Value::String(raw.to_string())),
+            _ => Value::String(raw.to_string()),
+        }
+    }
+}
+
+/// Ordem fixa dos grupos no painel
+pub const SCOPE_ORDER: [PropScope; 4] = [
+    PropScope::Structural,
+    PropScope::Visual,
+    PropScope::Typography,
+    PropScope::Capability,
+];
+
+pub fn scope_name(scope: PropScope) -> &'static str {
+    match scope {
+        PropScope::Structural => "structural",
+        PropScope::Visual     => "visual",
+        PropScope::Typography => "typography",
+        PropScope::Capability => "capability",
+    }
+}
+
+pub fn scope_title(scope: PropScope) -> &'static str {
+    match scope {
+        PropScope::Structural => "Structure",
+        PropScope::Visual     => "Appearance",
+        PropScope::Typography => "Typography",
+        PropScope::Capability => "Behavior",
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InspectorSchema {
+    /// Id do catalog — presets são aplicados via `PageOp::ApplyPreset` contra ele
+    pub catalog_id: String,
+    pub fields:     Vec<InspectorField>,
+    pub presets:    &'static [BlockPreset],
+}
+
+impl InspectorSchema {
+    pub fn from_block(def: &BlockDefinition) -> Self {
+        let fields = def.final_props()
+            .into_iter()
+            .map(|p| InspectorField {
+                key:         p.key,
+                label:       p.label.to_string(),
+                description: "",
+                kind: match p.field {
+                    PropFieldType::Text        => FieldKind::Text,
+                    PropFieldType::Number      => number_kind(p.default),
+                    PropFieldType::Select(o)   => FieldKind::Select(o.to_vec()),
+                    PropFieldType::Toggle      => FieldKind::Toggle,
+                    PropFieldType::Color       => FieldKind::Color,
+                },
+                default:     p.default,
+                scope:       p.scope,
+            })
+            .collect();
+        Self { catalog_id: def.catalog_id(), fields, presets: def.presets }
+    }
+
+    /// ComponentApi não declara scope — Enum = Visual, Bool = Capability, resto Structural
+    pub fn from_api(api: &ComponentApi) -> Self {
+        let fields = api.props
+            .iter()
+            .filter_map(|p: &PropDef| {
+                let (kind, scope) = match p.kind {
+                    PropType::String     => (FieldKind::Text, PropScope::Structural),
+                    PropType::Number     => (number_kind(p.default), PropScope::Structural),
+                    PropType::Bool       => (FieldKind::Toggle, PropScope::Capability),
+                    PropType::Enum(opts) => (FieldKind::Select(opts.iter().map(|o| (*o, *o)).collect()), PropScope::Visual),
+                    PropType::Children   => return None,
+                };
+                Some(InspectorField {
+                    key: p.name,
+                    label: humanize(p.name),
+                    description: p.description,
+                    kind,
+                    default: p.default,
+                    scope,
+                })
+            })
+            .collect();
+        Self { catalog_id: api.id.to_string(), fields, presets: &[] }
+    }
+
+    /// Campos agrupados por PropScope, na ordem de SCOPE_ORDER — grupos vazios omitidos
+    pub fn grouped(&self) -> Vec<(PropScope, Vec<InspectorField>)> {
+        SCOPE_ORDER
+            .iter()
+            .map(|scope| (*scope, self.fields.iter().filter(|f| f.scope == *scope).cloned().collect::<Vec<_>>()))
+            .filter(|(_, fields)| !fields.is_empty())
+            .collect()
+    }
+
+    pub fn field(&self, key: &str) -> Option<&InspectorField> {
+        self.fields.iter().find(|f| f.key == key)
+    }
+}
+
+/// Default com unidade (`1rem`, `240px`) é comprimento CSS — `input type="number"` descartaria o valor
+fn number_kind(default: Option<&str>) -> FieldKind {
+    match default {
+        Some(d) if d.trim().parse::<f64>().is_err() => FieldKind::Text,
+        _                                           => FieldKind::Number,
+    }
+}
+
+/// Cor inicial do ColorPicker quando nem o valor nem o default são hex
+pub const NEUTRAL_PICKER_COLOR: &str = "#808080";
+
+/// Hex para semear o ColorPicker — valor atual, senão default, senão neutro
+pub fn picker_seed<'a>(current: &'a str, default: Option<&'a str>) -> &'a str {
+    picker_color(current)
+        .or_else(|| default.and_then(picker_color))
+        .unwrap_or(NEUTRAL_PICKER_COLOR)
+}
+
+/// Valor que o ColorPicker aceita (`#rrggbb`) — tokens, nomes e vazio retornam None
+pub fn picker_color(raw: &str) -> Option<&str> {
+    let raw = raw.trim();
+    let hex = raw.strip_prefix('#')?;
+    (hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit())).then_some(raw)
+}
+
+/// `aria_label` → `Aria label`
+fn humanize(name: &str) -> String {
+    let spaced = name.replace(['_', '-'], " ");
+    let mut chars = spaced.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None        => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_API: ComponentApi = ComponentApi {
+        id: "test",
+        description: "",
+        props: &[
+            PropDef { name: "children",   kind: PropType::Children, required: true,  default: None,            description: "" },
+            PropDef { name: "variant",    kind: PropType::Enum(&["default", "ghost"]), required: false, default: Some("default"), description: "Variant" },
+            PropDef { name: "disabled",   kind: PropType::Bool,     required: false, default: Some("false"), description: "" },
+            PropDef { name: "aria_label", kind: PropType::String,   required: false, default: None,          description: "" },
+            PropDef { name: "value",      kind: PropType::Number,   required: false, default: Some("0"),     description: "" },
+            PropDef { name: "gap",        kind: PropType::Number,   required: false, default: Some("1rem"),  description: "" },
+        ],
+    };
+
+    fn field(kind: FieldKind) -> InspectorField {
+        InspectorField { key: "k", label: "K".into(), description: "", kind, default: None, scope: PropScope::Structural }
+    }
+
+    #[test]
+    fn test_to_value() {
+        assert_eq!(field(FieldKind::Toggle).to_value("true"), Value::Bool(true));
+        assert_eq!(field(FieldKind::Toggle).to_value("yes"), Value::Bool(false));
+        assert_eq!(field(FieldKind::Number).to_value(" 1.5 "), serde_json::json!(1.5));
+        // Número inválido não se perde — fica como texto
+        assert_eq!(field(FieldKind::Number).to_value("abc"), Value::String("abc".into()));
+        assert_eq!(field(FieldKind::Color).to_value("var(--color-primary)"), Value::String("var(--color-primary)".into()));
+    }
+
+    #[test]
+    fn test_from_api_skips_children_and_assigns_scopes() {
+        let schema = InspectorSchema::from_api(&TEST_API);
+        assert!(schema.field("children").is_none());
+        assert_eq!(schema.catalog_id, "test");
+        assert_eq!(schema.fields.len(), 5);
+        let variant = schema.field("variant").unwrap();
+        assert_eq!(variant.kind, FieldKind::Select(vec![("default", "default"), ("ghost", "ghost")]));
+        assert_eq!(variant.scope, PropScope::Visual);
+        assert_eq!(variant.default, Some("default"));
+        assert_eq!(schema.field("disabled").unwrap().scope, PropScope::Capability);
+        assert_eq!(schema.field("aria_label").unwrap().label, "Aria label");
+        assert_eq!(schema.field("value").unwrap().kind, FieldKind::Number);
+        // Comprimento CSS não vai para input numérico
+        assert_eq!(schema.field("gap").unwrap().kind, FieldKind::Text);
+    }
+
+    #[test]
+    fn test_grouped_follows_scope_order_and_omits_empty() {
+        let groups = InspectorSchema::from_api(&TEST_API).grouped();
+        let scopes: Vec<PropScope> = groups.iter().map(|(s, _)| *s).collect();
+        assert_eq!(scopes, vec![PropScope::Structural, PropScope::Visual, PropScope::Capability]);
+        let structural: Vec<&str> = groups[0].1.iter().map(|f| f.key).collect();
+        assert_eq!(structural, vec!["aria_label", "value", "gap"]);
+    }
+
+    #[test]
+    fn test_humanize() {
+        assert_eq!(humanize("aria_label"), "Aria label");
+        assert_eq!(humanize("max-width"), "Max width");
+        assert_eq!(humanize("x"), "X");
+        assert_eq!(humanize(""), "");
+    }
+
+    #[test]
+    fn test_picker_color_only_accepts_hex() {
+        assert_eq!(picker_color("#1a2B3c"), Some("#1a2B3c"));
+        assert_eq!(picker_color(" #ffffff "), Some("#ffffff"));
+        assert_eq!(picker_color(""), None);
+        assert_eq!(picker_color("var(--color-primary)"), None);
+        assert_eq!(picker_color("#fff"), None);
+        assert_eq!(picker_color("#gggggg"), None);
+        assert_eq!(picker_color("red"), None);
+    }
+
+    #[test]
+    fn test_picker_seed_falls_back_to_default_then_neutral() {
+        assert_eq!(picker_seed("#112233", Some("#445566")), "#112233");
+        assert_eq!(picker_seed("var(--color-primary)", Some("#445566")), "#445566");
+        assert_eq!(picker_seed("", Some("var(--color-muted)")), NEUTRAL_PICKER_COLOR);
+        assert_eq!(picker_seed("", None), NEUTRAL_PICKER_COLOR);
+    }
+}
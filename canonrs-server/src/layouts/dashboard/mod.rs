@@ -1,4 +1,5 @@
 pub mod dashboard_layout;
+pub mod api;
 pub use dashboard_layout::*;
 
 #[cfg(feature = "examples")]
@@ -1,4 +1,5 @@
 pub mod fullscreen_layout;
+pub mod api;
 pub use fullscreen_layout::*;
 
 #[cfg(feature = "examples")]
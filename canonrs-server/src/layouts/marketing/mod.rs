@@ -1,4 +1,5 @@
 pub mod marketing_layout;
+pub mod api;
 pub use marketing_layout::*;
 
 #[cfg(feature = "examples")]
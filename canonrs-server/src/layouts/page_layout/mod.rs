@@ -1,4 +1,5 @@
 pub mod page_layout_layout;
+pub mod api;
 pub use page_layout_layout::{PageLayout, PageLayoutVariant};
 
 
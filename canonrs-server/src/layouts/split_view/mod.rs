@@ -1,4 +1,5 @@
 pub mod split_view_layout;
+pub mod api;
 pub use split_view_layout::*;
 
 #[cfg(feature = "examples")]
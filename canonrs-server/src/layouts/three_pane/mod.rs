@@ -1,2 +1,3 @@
 pub mod three_pane_layout;
+pub mod api;
 pub use three_pane_layout::*;
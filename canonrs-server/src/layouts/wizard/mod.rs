@@ -1,4 +1,5 @@
 pub mod wizard_layout;
+pub mod api;
 pub use wizard_layout::*;
 
 #[cfg(feature = "examples")]
@@ -9,6 +9,8 @@ pub mod layouts;
 pub mod pages;
 pub mod providers;
 pub mod interactions;
+pub mod render;
+pub mod inspector;
 pub use canonrs_core::primitives;
 pub use canonrs_core::meta::VisibilityState;
 
@@ -1,6 +1,7 @@
 //! Provider re-exports from canonrs-providers
 
-pub use canonrs_core::infra::theme::{ThemeProvider, ThemeContext, ThemeMode, CanonRSRoot, use_theme};
+pub use canonrs_core::infra::theme::{ThemeProvider, ThemeScope, ThemeContext, ThemeMode, CanonRSRoot, use_theme};
+pub use canonrs_core::infra::density::{DensityProvider, DensityContext, Density, use_density};
 
 mod layout_provider;
 mod layout_types;
@@ -0,0 +1,387 @@
+//! Builtin renderers — layouts, blocks and core UI components
+//! Keys são ids do catalog (`layout.*`, `block.*`, ids de UI)
+//! Apps estendem com RenderRegistry::register para ids próprios
+
+use leptos::prelude::*;
+use canonrs_core::primitives::{
+    ButtonVariant, ButtonSize, ButtonType,
+    BadgeVariant, BadgeInteractivity, Orientation,
+};
+use canonrs_core::primitives::progress::ProgressState;
+use canonrs_core::primitives::layout::grid::GridCols;
+use crate::ui::button::{Button, api::BUTTON_API};
+use crate::ui::badge::{Badge, api::BADGE_API};
+use crate::ui::separator::{Separator, api::SEPARATOR_API};
+use crate::ui::card::{Card, api::CARD_API};
+use crate::ui::alert::{AlertVariant, api::ALERT_API, alert_boundary::{Alert, AlertProps}};
+use crate::ui::callout::{CalloutVariant, api::CALLOUT_API, callout_boundary::{Callout, CalloutProps}};
+use crate::ui::inline_notice::{InlineNoticeVariant, api::INLINENOTICE_API, inline_notice_boundary::{InlineNotice, InlineNoticeProps}};
+use crate::ui::label::{Label, api::LABEL_API};
+use crate::ui::link::{Link, LinkVariant, api::LINK_API};
+use crate::ui::skeleton::{Skeleton, SkeletonVariant, api::SKELETON_API};
+use crate::ui::spinner::{Spinner, SpinnerSize, api::SPINNER_API};
+use crate::ui::progress::{Progress, api::PROGRESS_API};
+use crate::ui::switch::{Switch, api::SWITCH_API};
+use crate::blocks::card::{CardVariant, api::CARDBLOCK_API, card_block::{CardBlock, CardBlockProps}};
+use crate::blocks::hero::{HeroVariant, api::HERO_API, hero_block::{Hero, HeroProps}};
+use crate::blocks::section::{api::SECTIONBLOCK_API, section_block::{SectionBlock, SectionBlockProps}};
+use crate::blocks::data_table::{api::DATATABLEBLOCK_API, data_table_block::{DataTableBlock, DataTableBlockProps}};
+use crate::blocks::form_field::{api::FORMFIELDBLOCK_API, form_field_block::{FormFieldBlock, FormFieldBlockProps}};
+use crate::blocks::page_header::{api::PAGEHEADER_API, page_header_block::{PageHeader, PageHeaderProps}};
+use crate::blocks::sidebar_layout::{api::SIDEBARLAYOUT_API, sidebar_layout_block::{SidebarLayout, SidebarLayoutProps, SidebarSide}};
+use crate::blocks::stat_group::{api::STATGROUPBLOCK_API, stat_group_block::{StatGroupBlock, StatGroupBlockProps}};
+use crate::layouts::dashboard::{DashboardLayout, DashboardLayoutProps, api::DASHBOARDLAYOUT_API};
+use crate::layouts::marketing::{MarketingLayout, MarketingLayoutProps, api::MARKETINGLAYOUT_API};
+use crate::layouts::fullscreen::{FullscreenLayout, FullscreenLayoutProps, api::FULLSCREENLAYOUT_API};
+use crate::layouts::wizard::{WizardLayout, WizardLayoutProps, api::WIZARDLAYOUT_API};
+use crate::layouts::three_pane::{ThreePaneLayout, ThreePaneLayoutProps, api::THREEPANELAYOUT_API};
+use crate::layouts::page_layout::{PageLayoutVariant, api::PAGELAYOUT_API, page_layout_layout::{PageLayout, PageLayoutProps}};
+use crate::layouts::split_view::{SplitRatio, SplitViewLayout, SplitViewLayoutProps, api::SPLITVIEWLAYOUT_API};
+use super::registry::{RenderContext, RenderRegistry};
+
+pub fn builtin_registry() -> RenderRegistry {
+    RenderRegistry::new()
+        // ── UI ──
+        .with("button",    &BUTTON_API,    render_button)
+        .with("badge",     &BADGE_API,     render_badge)
+        .with("separator", &SEPARATOR_API, render_separator)
+        .with("card",      &CARD_API,      render_card)
+        .with("alert",         &ALERT_API,        render_alert)
+        .with("callout",       &CALLOUT_API,      render_callout)
+        .with("inline-notice", &INLINENOTICE_API, render_inline_notice)
+        .with("label",         &LABEL_API,        render_label)
+        .with("link",          &LINK_API,         render_link)
+        .with("skeleton",      &SKELETON_API,     render_skeleton)
+        .with("spinner",       &SPINNER_API,      render_spinner)
+        .with("progress",      &PROGRESS_API,     render_progress)
+        .with("switch",        &SWITCH_API,       render_switch)
+        // ── Blocks ──
+        .with("block.card",           &CARDBLOCK_API,      render_card_block)
+        .with("block.hero",           &HERO_API,           render_hero)
+        .with("block.section",        &SECTIONBLOCK_API,   render_section)
+        .with("block.data-table",     &DATATABLEBLOCK_API, render_data_table)
+        .with("block.form-field",     &FORMFIELDBLOCK_API, render_form_field)
+        .with("block.page-header",    &PAGEHEADER_API,     render_page_header)
+        .with("block.sidebar-layout", &SIDEBARLAYOUT_API,  render_sidebar_layout)
+        .with("block.stat-group",     &STATGROUPBLOCK_API, render_stat_group)
+        // ── Layouts ──
+        .with("layout.dashboard-layout",  &DASHBOARDLAYOUT_API,  render_dashboard_layout)
+        .with("layout.marketing-layout",  &MARKETINGLAYOUT_API,  render_marketing_layout)
+        .with("layout.fullscreen-layout", &FULLSCREENLAYOUT_API, render_fullscreen_layout)
+        .with("layout.wizard-layout",     &WIZARDLAYOUT_API,     render_wizard_layout)
+        .with("layout.three-pane-layout", &THREEPANELAYOUT_API,  render_three_pane_layout)
+        .with("layout.page-layout",       &PAGELAYOUT_API,       render_page_layout)
+        .with("layout.split-view-layout", &SPLITVIEWLAYOUT_API,  render_split_view_layout)
+}
+
+// ── UI ───────────────────────────────────────────────────────────────────────
+
+fn render_button(ctx: RenderContext) -> AnyView {
+    use ButtonVariant as V;
+    use ButtonSize as S;
+    let p = &ctx.props;
+    let variant = p.pick("variant", &[V::Default, V::Destructive, V::Outline, V::Secondary, V::Ghost, V::Link, V::Primary], V::as_str).unwrap_or_default();
+    let size = p.pick("size", &[S::Xs, S::Sm, S::Md, S::Lg, S::Xl, S::Icon], S::as_str).unwrap_or_default();
+    let button_type = p.pick("button_type", &[ButtonType::Button, ButtonType::Submit, ButtonType::Reset], ButtonType::as_str).unwrap_or_default();
+    let disabled = p.bool("disabled");
+    let loading = p.bool("loading");
+    let class = p.string_or_default("class");
+    let aria_label = p.string("aria_label").unwrap_or_default();
+    let children = ctx.children();
+    view! {
+        <Button variant=variant size=size button_type=button_type disabled=disabled loading=loading class=class aria_label=aria_label>
+            {children()}
+        </Button>
+    }.into_any()
+}
+
+fn render_badge(ctx: RenderContext) -> AnyView {
+    use BadgeVariant as V;
+    let p = &ctx.props;
+    let variant = p.pick("variant", &[V::Default, V::Primary, V::Success, V::Warning, V::Destructive, V::Outline], V::as_str).unwrap_or_default();
+    let interactivity = p.pick("interactivity", &[BadgeInteractivity::Static, BadgeInteractivity::Interactive], BadgeInteractivity::as_str).unwrap_or_default();
+    let class = p.string_or_default("class");
+    let children = ctx.children();
+    view! {
+        <Badge variant=variant interactivity=interactivity class=class>{children()}</Badge>
+    }.into_any()
+}
+
+fn render_separator(ctx: RenderContext) -> AnyView {
+    let p = &ctx.props;
+    let orientation = p.pick("orientation", &[Orientation::Horizontal, Orientation::Vertical], Orientation::as_str).unwrap_or(Orientation::Horizontal);
+    let decorative = p.bool("decorative");
+    let aria_label = p.string_or_default("aria_label");
+    let class = p.string_or_default("class");
+    match p.string("id") {
+        Some(id) => view! { <Separator orientation=orientation decorative=decorative aria_label=aria_label class=class id=id /> }.into_any(),
+        None     => view! { <Separator orientation=orientation decorative=decorative aria_label=aria_label class=class /> }.into_any(),
+    }
+}
+
+fn render_card(ctx: RenderContext) -> AnyView {
+    let variant = ctx.props.string_or_default("variant");
+    let class = ctx.props.string_or_default("class");
+    let children = ctx.children();
+    view! { <Card variant=variant class=class>{children()}</Card> }.into_any()
+}
+
+fn render_alert(ctx: RenderContext) -> AnyView {
+    use AlertVariant as V;
+    let p = &ctx.props;
+    Alert(AlertProps {
+        title:       p.string("title"),
+        description: p.string("description"),
+        variant:     p.pick("variant", &[V::Default, V::Destructive, V::Warning, V::Success], V::as_str).unwrap_or_default(),
+        dismissible: p.bool("dismissible"),
+        class:       p.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_callout(ctx: RenderContext) -> AnyView {
+    use CalloutVariant as V;
+    let p = &ctx.props;
+    Callout(CalloutProps {
+        title:       p.string("title"),
+        description: p.string("description"),
+        icon:        p.string("icon"),
+        variant:     p.pick("variant", &[V::Default, V::Info, V::Success, V::Warning, V::Error], V::as_str).unwrap_or_default(),
+        class:       p.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_inline_notice(ctx: RenderContext) -> AnyView {
+    use InlineNoticeVariant as V;
+    let p = &ctx.props;
+    InlineNotice(InlineNoticeProps {
+        content: p.string("content"),
+        icon:    p.string("icon"),
+        variant: p.pick("variant", &[V::Default, V::Info, V::Success, V::Warning, V::Error], V::as_str).unwrap_or_default(),
+        class:   p.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_label(ctx: RenderContext) -> AnyView {
+    let for_id = ctx.props.string_or_default("for_id");
+    let class = ctx.props.string_or_default("class");
+    let children = ctx.children();
+    view! { <Label for_id=for_id class=class>{children()}</Label> }.into_any()
+}
+
+fn render_link(ctx: RenderContext) -> AnyView {
+    use LinkVariant as V;
+    let p = &ctx.props;
+    let href = p.string_or_default("href");
+    let variant = p.pick("variant", &[V::Default, V::Muted, V::Underline], V::as_str).unwrap_or_default();
+    let disabled = p.bool("disabled");
+    let external = p.bool("external");
+    let class = p.string_or_default("class");
+    let children = ctx.children();
+    view! {
+        <Link href=href variant=variant disabled=disabled external=external class=class>{children()}</Link>
+    }.into_any()
+}
+
+fn render_skeleton(ctx: RenderContext) -> AnyView {
+    use SkeletonVariant as V;
+    let variant = ctx.props.pick("variant", &[V::Rectangle, V::Text, V::Circle], V::as_str).unwrap_or_default();
+    let class = ctx.props.string_or_default("class");
+    view! { <Skeleton variant=variant class=class /> }.into_any()
+}
+
+fn render_spinner(ctx: RenderContext) -> AnyView {
+    use SpinnerSize as S;
+    let p = &ctx.props;
+    let size = p.pick("size", &[S::Small, S::Medium, S::Large], S::as_str).unwrap_or_default();
+    let paused = p.bool("paused");
+    let aria_label = p.string("aria_label").unwrap_or_else(|| "Loading".to_string());
+    let class = p.string_or_default("class");
+    view! { <Spinner size=size paused=paused aria_label=aria_label class=class /> }.into_any()
+}
+
+fn render_progress(ctx: RenderContext) -> AnyView {
+    use ProgressState as S;
+    let p = &ctx.props;
+    let value = p.number("value").unwrap_or(0.0);
+    // ProgressState::as_str devolve None para Default — o catalog usa "default"
+    let state = p.pick("state", &[S::Default, S::Indeterminate, S::Loading], |s| s.as_str().unwrap_or("default")).unwrap_or_default();
+    let class = p.string_or_default("class");
+    view! { <Progress value=value state=state class=class /> }.into_any()
+}
+
+fn render_switch(ctx: RenderContext) -> AnyView {
+    let p = &ctx.props;
+    let checked = p.bool("checked");
+    let disabled = p.bool("disabled");
+    let name = p.string_or_default("name");
+    let value = p.string_or_default("value");
+    let class = p.string_or_default("class");
+    view! { <Switch checked=checked disabled=disabled name=name value=value class=class /> }.into_any()
+}
+
+// ── Blocks ───────────────────────────────────────────────────────────────────
+
+fn render_card_block(ctx: RenderContext) -> AnyView {
+    use CardVariant as V;
+    let variant = ctx.props.pick("variant", &[V::Default, V::Interactive, V::Outlined, V::Elevated], V::as_str).unwrap_or_default();
+    CardBlock(CardBlockProps {
+        header:  ctx.slot("header"),
+        content: ctx.slot("content"),
+        footer:  ctx.slot("footer"),
+        variant,
+        class:   ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_hero(ctx: RenderContext) -> AnyView {
+    use HeroVariant as V;
+    let variant = ctx.props.pick("variant", &[V::Centered, V::Split, V::MediaTop], V::as_str).unwrap_or_default();
+    Hero(HeroProps {
+        header:  ctx.slot("header"),
+        media:   ctx.slot("media"),
+        content: ctx.slot("content"),
+        actions: ctx.slot("actions"),
+        footer:  ctx.slot("footer"),
+        variant,
+        class:   ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_section(ctx: RenderContext) -> AnyView {
+    SectionBlock(SectionBlockProps {
+        header: ctx.slot("header"),
+        body:   ctx.slot("body"),
+        footer: ctx.slot("footer"),
+        class:  ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_data_table(ctx: RenderContext) -> AnyView {
+    DataTableBlock(DataTableBlockProps {
+        toolbar:    ctx.slot("toolbar"),
+        header:     ctx.slot("header"),
+        body:       ctx.slot("body"),
+        empty:      ctx.slot("empty"),
+        pagination: ctx.slot("pagination"),
+        class:      ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_form_field(ctx: RenderContext) -> AnyView {
+    FormFieldBlock(FormFieldBlockProps {
+        label: ctx.slot("label"),
+        input: ctx.slot("input"),
+        hint:  ctx.slot("hint"),
+        error: ctx.slot("error"),
+        class: ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_page_header(ctx: RenderContext) -> AnyView {
+    PageHeader(PageHeaderProps {
+        breadcrumb: ctx.slot("breadcrumb"),
+        title:      ctx.slot("title"),
+        subtitle:   ctx.slot("subtitle"),
+        actions:    ctx.slot("actions"),
+        class:      ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_sidebar_layout(ctx: RenderContext) -> AnyView {
+    use SidebarSide as S;
+    let side = ctx.props.pick("side", &[S::Left, S::Right], S::as_str).unwrap_or_default();
+    SidebarLayout(SidebarLayoutProps {
+        side,
+        nav:   ctx.slot("nav"),
+        main:  ctx.slot("main"),
+        class: ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_stat_group(ctx: RenderContext) -> AnyView {
+    use GridCols as C;
+    let cols = ctx.props
+        .pick("cols", &[C::One, C::Two, C::Three, C::Four, C::Six, C::Twelve, C::Auto], C::as_str)
+        .unwrap_or(C::Three);
+    StatGroupBlock(StatGroupBlockProps {
+        stats: ctx.slot("stats"),
+        cols,
+        class: ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+// ── Layouts ──────────────────────────────────────────────────────────────────
+
+fn render_dashboard_layout(ctx: RenderContext) -> AnyView {
+    DashboardLayout(DashboardLayoutProps {
+        header:  ctx.slot("header"),
+        sidebar: ctx.slot("sidebar"),
+        content: ctx.slot("content"),
+        class:   ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_marketing_layout(ctx: RenderContext) -> AnyView {
+    MarketingLayout(MarketingLayoutProps {
+        header:  ctx.slot("header"),
+        hero:    ctx.slot("hero"),
+        content: ctx.slot("content"),
+        footer:  ctx.slot("footer"),
+        class:   ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_fullscreen_layout(ctx: RenderContext) -> AnyView {
+    FullscreenLayout(FullscreenLayoutProps {
+        header:  ctx.slot("header"),
+        content: ctx.slot("content"),
+        class:   ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_wizard_layout(ctx: RenderContext) -> AnyView {
+    WizardLayout(WizardLayoutProps {
+        header:  ctx.slot("header"),
+        stepper: ctx.slot("stepper"),
+        content: ctx.slot("content"),
+        footer:  ctx.slot("footer"),
+        class:   ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_three_pane_layout(ctx: RenderContext) -> AnyView {
+    ThreePaneLayout(ThreePaneLayoutProps {
+        left:   ctx.slot("left"),
+        center: ctx.slot("center"),
+        right:  ctx.slot("right"),
+        class:  ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_page_layout(ctx: RenderContext) -> AnyView {
+    use PageLayoutVariant as V;
+    let variant = ctx.props.pick("variant", &[V::Single, V::WithSidebar, V::WithAside, V::SidebarAndAside], V::as_str).unwrap_or_default();
+    PageLayout(PageLayoutProps {
+        variant,
+        sidebar: ctx.slot("sidebar"),
+        content: ctx.slot("content"),
+        aside:   ctx.slot("aside"),
+        class:   ctx.props.string_or_default("class"),
+    }).into_any()
+}
+
+fn render_split_view_layout(ctx: RenderContext) -> AnyView {
+    use SplitRatio as R;
+    // SplitRatio::as_str devolve a proporção ("50-50") — o catalog usa o nome da variante
+    let ratio = ctx.props.pick("ratio", &[R::Equal, R::FormFocused, R::ContextFocused], |r| match r {
+        R::Equal          => "equal",
+        R::FormFocused    => "form-focused",
+        R::ContextFocused => "context-focused",
+    }).unwrap_or_default();
+    SplitViewLayout(SplitViewLayoutProps {
+        ratio,
+        left:  ctx.slot("left"),
+        right: ctx.slot("right"),
+        class: ctx.props.string_or_default("class"),
+    }).into_any()
+}
@@ -0,0 +1,26 @@
+//! CanonRS Render — PageDocument JSON → views via registry
+//! Sem match gigante de ids: cada catalog id mapeia para um RenderFn
+//! Props validadas contra ComponentApi; regions viram ChildrenFn slots
+//! Ids desconhecidos e props inválidas renderizam ErrorState em debug builds
+
+pub mod props;
+pub mod registry;
+pub mod builtin;
+
+pub use props::{parse_props, PropError, PropValue, RenderProps};
+pub use registry::{RenderContext, RenderEntry, RenderFn, RenderRegistry};
+pub use builtin::builtin_registry;
+
+use std::sync::Arc;
+use leptos::prelude::*;
+use canonrs_core::page_document::PageDocument;
+
+/// Renderiza um PageDocument — usa a registry builtin quando nenhuma é fornecida
+#[component]
+pub fn PageDocumentView(
+    document: PageDocument,
+    #[prop(optional)] registry: Option<Arc<RenderRegistry>>,
+) -> impl IntoView {
+    let registry = registry.unwrap_or_else(|| Arc::new(builtin_registry()));
+    registry.render_document(&document)
+}
@@ -0,0 +1,239 @@
+//! Props parsing — PageNode JSON props → typed values validated against ComponentApi
+//! Document keys may be kebab-case (`aria-label`); PropDef names are snake_case
+
+use std::collections::BTreeMap;
+use std::fmt;
+use serde_json::Value;
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropValue {
+    String(String),
+    Bool(bool),
+    Number(f64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropError {
+    /// Prop não declarada no ComponentApi
+    Unknown { name: String },
+    /// Valor incompatível com o PropType
+    Invalid { name: &'static str, expected: String, got: String },
+    /// Prop required ausente
+    MissingRequired { name: &'static str },
+}
+
+impl fmt::Display for PropError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown { name } => write!(f, "unknown prop '{}'", name),
+            Self::Invalid { name, expected, got } => write!(f, "prop '{}' expects {}, got {}", name, expected, got),
+            Self::MissingRequired { name } => write!(f, "required prop '{}' is missing", name),
+        }
+    }
+}
+
+/// Props já validadas — defaults do PropDef aplicados
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderProps {
+    values: BTreeMap<&'static str, PropValue>,
+}
+
+impl RenderProps {
+    pub fn get(&self, name: &str) -> Option<&PropValue> {
+        self.values.get(name)
+    }
+
+    pub fn string(&self, name: &str) -> Option<String> {
+        match self.values.get(name)? {
+            PropValue::String(s) => Some(s.clone()),
+            PropValue::Bool(b)   => Some(b.to_string()),
+            PropValue::Number(n) => Some(n.to_string()),
+        }
+    }
+
+    pub fn string_or_default(&self, name: &str) -> String {
+        self.string(name).unwrap_or_default()
+    }
+
+    pub fn bool(&self, name: &str) -> bool {
+        matches!(self.values.get(name), Some(PropValue::Bool(true)))
+    }
+
+    pub fn number(&self, name: &str) -> Option<f64> {
+        match self.values.get(name)? {
+            PropValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Resolve um enum tipado comparando com `as_str` de cada opção
+    pub fn pick<T: Clone>(&self, name: &str, options: &[T], as_str: impl Fn(&T) -> &str) -> Option<T> {
+        let value = self.string(name)?;
+        options.iter().find(|o| as_str(o) == value).cloned()
+    }
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Null      => "null".into(),
+        Value::Bool(b)   => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => format!("\"{}\"", s),
+        Value::Array(_)  => "array".into(),
+        Value::Object(_) => "object".into(),
+    }
+}
+
+fn parse_value(def: &PropDef, value: &Value) -> Result<PropValue, PropError> {
+    let invalid = |expected: String| PropError::Invalid {
+        name: def.name,
+        expected,
+        got: describe(value),
+    };
+    match (&def.kind, value) {
+        (PropType::String, Value::String(s))   => Ok(PropValue::String(s.clone())),
+        (PropType::String, Value::Number(n))   => Ok(PropValue::String(n.to_string())),
+        (PropType::String, Value::Bool(b))     => Ok(PropValue::String(b.to_string())),
+        (PropType::String, _)                  => Err(invalid("string".into())),
+
+        (PropType::Bool, Value::Bool(b))       => Ok(PropValue::Bool(*b)),
+        (PropType::Bool, Value::String(s)) if s == "true" || s == "false" => Ok(PropValue::Bool(s == "true")),
+        (PropType::Bool, _)                    => Err(invalid("bool".into())),
+
+        (PropType::Number, Value::Number(n))   => n.as_f64().map(PropValue::Number).ok_or_else(|| invalid("number".into())),
+        (PropType::Number, Value::String(s))   => s.trim().parse().map(PropValue::Number).map_err(|_| invalid("number".into())),
+        (PropType::Number, _)                  => Err(invalid("number".into())),
+
+        (PropType::Enum(opts), Value::String(s)) if opts.iter().any(|o| *o == s) => Ok(PropValue::String(s.clone())),
+        (PropType::Enum(opts), _)              => Err(invalid(format!("one of {}", opts.join("|")))),
+
+        // Children como prop = conteúdo textual; nodes filhos vêm das regions
+        (PropType::Children, Value::String(s)) => Ok(PropValue::String(s.clone())),
+        (PropType::Children, _)                => Err(invalid("text".into())),
+    }
+}
+
+fn parse_default(def: &PropDef, raw: &str) -> PropValue {
+    match def.kind {
+        PropType::Bool   => PropValue::Bool(raw == "true"),
+        PropType::Number => raw.parse().map(PropValue::Number).unwrap_or_else(|_| PropValue::String(raw.to_string())),
+        _                => PropValue::String(raw.to_string()),
+    }
+}
+
+/// Valida as props de um node contra o ComponentApi — coleta todos os erros
+pub fn parse_props(api: &ComponentApi, raw: &BTreeMap<String, Value>) -> Result<RenderProps, Vec<PropError>> {
+    let mut values = BTreeMap::new();
+    let mut errors = vec![];
+
+    for (key, value) in raw {
+        let name = key.replace('-', "_");
+        match api.props.iter().find(|p| p.name == name) {
+            None      => errors.push(PropError::Unknown { name: key.clone() }),
+            Some(def) => match parse_value(def, value) {
+                Ok(v)  => { values.insert(def.name, v); }
+                Err(e) => errors.push(e),
+            },
+        }
+    }
+
+    for def in api.props {
+        if values.contains_key(def.name) { continue; }
+        if let Some(raw) = def.default {
+            values.insert(def.name, parse_default(def, raw));
+        } else if def.required && !matches!(def.kind, PropType::Children) {
+            errors.push(PropError::MissingRequired { name: def.name });
+        }
+    }
+
+    if errors.is_empty() { Ok(RenderProps { values }) } else { Err(errors) }
+}
+
+/// Regions aceitas por um ComponentApi — props do tipo Children
+pub fn slot_names(api: &ComponentApi) -> impl Iterator<Item = &'static str> + '_ {
+    api.props.iter().filter(|p| matches!(p.kind, PropType::Children)).map(|p| p.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TEST_API: ComponentApi = ComponentApi {
+        id: "test",
+        description: "",
+        props: &[
+            PropDef { name: "variant",    kind: PropType::Enum(&["default", "outline"]), required: false, default: Some("default"), description: "" },
+            PropDef { name: "disabled",   kind: PropType::Bool,     required: false, default: Some("false"), description: "" },
+            PropDef { name: "value",      kind: PropType::Number,   required: false, default: Some("0.0"),   description: "" },
+            PropDef { name: "aria_label", kind: PropType::String,   required: false, default: None,          description: "" },
+            PropDef { name: "href",       kind: PropType::String,   required: true,  default: None,          description: "" },
+            PropDef { name: "children",   kind: PropType::Children, required: true,  default: None,          description: "" },
+            PropDef { name: "footer",     kind: PropType::Children, required: false, default: None,          description: "" },
+        ],
+    };
+
+    fn raw(value: Value) -> BTreeMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_defaults_applied() {
+        let props = parse_props(&TEST_API, &raw(json!({ "href": "/" }))).unwrap();
+        assert_eq!(props.string("variant").as_deref(), Some("default"));
+        assert!(!props.bool("disabled"));
+        assert_eq!(props.number("value"), Some(0.0));
+        assert_eq!(props.string("aria_label"), None);
+        // Children required não é prop — vem das regions
+        assert_eq!(props.get("children"), None);
+    }
+
+    #[test]
+    fn test_kebab_keys_and_coercion() {
+        let props = parse_props(&TEST_API, &raw(json!({
+            "href": "/docs",
+            "aria-label": "Docs",
+            "disabled": "true",
+            "value": "42",
+            "variant": "outline",
+        }))).unwrap();
+        assert_eq!(props.string("aria_label").as_deref(), Some("Docs"));
+        assert!(props.bool("disabled"));
+        assert_eq!(props.number("value"), Some(42.0));
+        assert_eq!(props.pick("variant", &["default", "outline"], |v| *v), Some("outline"));
+    }
+
+    #[test]
+    fn test_collects_all_errors() {
+        let errors = parse_props(&TEST_API, &raw(json!({
+            "variant": "ghost",
+            "disabled": "yes",
+            "value": [1],
+            "unknown-prop": 1,
+        }))).unwrap_err();
+        assert_eq!(errors.len(), 5);
+        assert!(errors.contains(&PropError::Unknown { name: "unknown-prop".into() }));
+        assert!(errors.contains(&PropError::MissingRequired { name: "href" }));
+        assert!(errors.contains(&PropError::Invalid {
+            name: "variant",
+            expected: "one of default|outline".into(),
+            got: "\"ghost\"".into(),
+        }));
+        assert!(errors.iter().any(|e| matches!(e, PropError::Invalid { name: "disabled", .. })));
+        assert!(errors.iter().any(|e| matches!(e, PropError::Invalid { name: "value", got, .. } if got == "array")));
+    }
+
+    #[test]
+    fn test_children_prop_accepts_text_only() {
+        let props = parse_props(&TEST_API, &raw(json!({ "href": "/", "children": "Hello" }))).unwrap();
+        assert_eq!(props.string("children").as_deref(), Some("Hello"));
+        let errors = parse_props(&TEST_API, &raw(json!({ "href": "/", "children": {} }))).unwrap_err();
+        assert!(matches!(errors[0], PropError::Invalid { name: "children", .. }));
+    }
+
+    #[test]
+    fn test_slot_names() {
+        assert_eq!(slot_names(&TEST_API).collect::<Vec<_>>(), vec!["children", "footer"]);
+    }
+}
@@ -0,0 +1,197 @@
+//! RenderRegistry — catalog id → constructor
+//! Cada entry declara o ComponentApi usado para validar props e regions
+//! Mesma registry + mesmo documento → mesma árvore em SSR e hydrate
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use leptos::prelude::*;
+use canonrs_core::catalog_types::ComponentApi;
+use canonrs_core::page_document::{PageDocument, PageNode};
+use super::props::{parse_props, slot_names, RenderProps};
+
+/// Props validadas + regions já montadas como slots
+pub struct RenderContext {
+    pub props: RenderProps,
+    slots:     BTreeMap<String, ChildrenFn>,
+}
+
+impl RenderContext {
+    /// Slot de uma region — None quando o documento não fornece filhos
+    pub fn slot(&self, region: &str) -> Option<ChildrenFn> {
+        self.slots.get(region).cloned()
+    }
+
+    /// Conteúdo de `children`: texto da prop + nodes da region `children`
+    pub fn children(&self) -> Children {
+        let text = self.props.string("children");
+        let slot = self.slot("children");
+        Box::new(move || view! { {text} {slot.map(|s| s())} }.into_any())
+    }
+}
+
+pub type RenderFn = fn(RenderContext) -> AnyView;
+
+#[derive(Clone, Copy)]
+pub struct RenderEntry {
+    pub api:    &'static ComponentApi,
+    pub render: RenderFn,
+}
+
+#[derive(Clone, Default)]
+pub struct RenderRegistry {
+    entries: BTreeMap<&'static str, RenderEntry>,
+}
+
+impl RenderRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, catalog_id: &'static str, api: &'static ComponentApi, render: RenderFn) -> &mut Self {
+        self.entries.insert(catalog_id, RenderEntry { api, render });
+        self
+    }
+
+    pub fn with(mut self, catalog_id: &'static str, api: &'static ComponentApi, render: RenderFn) -> Self {
+        self.register(catalog_id, api, render);
+        self
+    }
+
+    pub fn get(&self, catalog_id: &str) -> Option<&RenderEntry> {
+        self.entries.get(catalog_id)
+    }
+
+    pub fn contains(&self, catalog_id: &str) -> bool {
+        self.entries.contains_key(catalog_id)
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.entries.keys().copied()
+    }
+
+    pub fn render_document(self: &Arc<Self>, document: &PageDocument) -> AnyView {
+        self.render_node(&document.root)
+    }
+
+    /// Renderiza um node — id desconhecido ou props inválidas viram ErrorState em dev
+    pub fn render_node(self: &Arc<Self>, node: &PageNode) -> AnyView {
+        let Some(entry) = self.get(&node.catalog_id) else {
+            return render_error(&node.catalog_id, vec!["no renderer registered for this catalog id".into()]);
+        };
+
+        let mut errors: Vec<String> = node.regions
+            .keys()
+            .filter(|region| !slot_names(entry.api).any(|s| s == region.as_str()))
+            .map(|region| format!("region '{}' is not a slot of '{}'", region, entry.api.id))
+            .collect();
+
+        let props = match parse_props(entry.api, &node.props) {
+            Ok(props) => Some(props),
+            Err(prop_errors) => {
+                errors.extend(prop_errors.iter().map(ToString::to_string));
+                None
+            }
+        };
+
+        let Some(props) = props.filter(|_| errors.is_empty()) else {
+            return render_error(&node.catalog_id, errors);
+        };
+
+        let slots = node.regions
+            .iter()
+            .filter(|(_, children)| !children.is_empty())
+            .map(|(region, children)| {
+                let registry = Arc::clone(self);
+                let children = children.clone();
+                let slot: ChildrenFn = Arc::new(move || {
+                    children.iter().map(|c| registry.render_node(c)).collect_view().into_any()
+                });
+                (region.clone(), slot)
+            })
+            .collect();
+
+        (entry.render)(RenderContext { props, slots })
+    }
+}
+
+#[cfg(debug_assertions)]
+fn render_error(catalog_id: &str, errors: Vec<String>) -> AnyView {
+    use crate::ui::error_state::{ErrorState, ErrorStateTitle, ErrorStateDescription};
+    leptos::logging::warn!("[CanonRS][Render:'{}'] {}", catalog_id, errors.join("; "));
+    let title = format!("Cannot render '{}'", catalog_id);
+    view! {
+        <ErrorState>
+            <ErrorStateTitle>{title}</ErrorStateTitle>
+            <ErrorStateDescription>
+                <ul>{errors.into_iter().map(|e| view! { <li>{e}</li> }).collect_view()}</ul>
+            </ErrorStateDescription>
+        </ErrorState>
+    }.into_any()
+}
+
+#[cfg(not(debug_assertions))]
+fn render_error(_catalog_id: &str, _errors: Vec<String>) -> AnyView {
+    ().into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use canonrs_core::catalog_types::CatalogEntry;
+    use crate::render::builtin_registry;
+
+    #[test]
+    fn test_builtin_ids_and_slots_match_catalog() {
+        let registry = builtin_registry();
+        for id in registry.ids() {
+            let entry = CatalogEntry::find(id).unwrap_or_else(|| panic!("'{}' is not a catalog id", id));
+            let mut actual: Vec<&str> = slot_names(registry.get(id).unwrap().api).collect();
+            let mut expected = entry.regions.to_vec();
+            actual.sort();
+            expected.sort();
+            assert_eq!(actual, expected, "slots of '{}' differ from its catalog regions", id);
+        }
+    }
+
+    #[test]
+    fn test_builtin_covers_every_block_and_layout() {
+        let registry = builtin_registry();
+        for entry in canonrs_core::generated::catalog::CATALOG_GENERATED {
+            if entry.id.starts_with("block.") || entry.id.starts_with("layout.") {
+                assert!(registry.contains(entry.id), "no builtin renderer for '{}'", entry.id);
+            }
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    fn render_html(node: PageNode) -> String {
+        let registry = Arc::new(builtin_registry());
+        Owner::new().with(|| registry.render_node(&node).to_html())
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn test_renders_nested_regions() {
+        let html = render_html(
+            PageNode::new(1, "block.card")
+                .with_prop("variant", "outlined")
+                .with_child("content", PageNode::new(2, "button").with_prop("children", "Save")),
+        );
+        assert!(html.contains("data-rs-button"), "{}", html);
+        assert!(html.contains("Save"), "{}", html);
+        assert!(!html.contains("Cannot render"), "{}", html);
+    }
+
+    #[cfg(all(feature = "ssr", debug_assertions))]
+    #[test]
+    fn test_invalid_nodes_render_error_state() {
+        let unknown = render_html(PageNode::new(1, "nonexistent"));
+        assert!(unknown.contains("no renderer registered"), "{}", unknown);
+
+        let bad_region = render_html(PageNode::new(1, "block.card").with_child("sidebar", PageNode::new(2, "button")));
+        assert!(bad_region.contains("is not a slot of"), "{}", bad_region);
+
+        let bad_prop = render_html(PageNode::new(1, "button").with_prop("variant", "nope"));
+        assert!(bad_prop.contains("expects one of"), "{}", bad_prop);
+    }
+}
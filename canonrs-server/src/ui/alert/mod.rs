@@ -1,6 +1,7 @@
 mod alert_ui;
 pub mod alert_boundary;
 pub mod preview;
+pub mod api;
 
 pub use alert_boundary::*;
 pub use alert_boundary::Alert;
@@ -1,6 +1,7 @@
 mod badge_ui;
 pub mod badge_boundary;
 pub mod preview;
+pub mod api;
 
 pub use badge_boundary::*;
 pub use badge_boundary::Badge;
@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 // imports: use canonrs::primitives::{ButtonVariant, ButtonSize, ButtonType, ButtonStateHint}; 
 
@@ -16,7 +16,7 @@ pub const BUTTON_API: ComponentApi = ComponentApi {
         PropDef { name: "class", kind: PropType::String, required: false, default: Some(""), description: "Additional CSS class names" },
         PropDef { name: "aria_label", kind: PropType::String, required: false, default: None, description: "Accessible label for screen readers" },
         PropDef { name: "validation", kind: PropType::String, required: false, default: None, description: "Prop value" },
-        PropDef { name: "state_hint", kind: PropType::Enum(&["first", "last", "hover", "focus"]), required: false, default: None, description: "Prop value" },
+        PropDef { name: "state_hint", kind: PropType::Enum(&["none", "first", "last", "hover", "focus"]), required: false, default: None, description: "Prop value" },
         PropDef { name: "button_type", kind: PropType::Enum(&["button", "submit", "reset"]), required: false, default: Some("button"), description: "Prop value" },
     ],
 };
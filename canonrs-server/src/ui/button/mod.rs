@@ -1,6 +1,7 @@
 mod button_ui;
 pub mod button_boundary;
 pub mod preview;
+pub mod api;
 
 pub use button_boundary::*;
 pub use button_boundary::Button;
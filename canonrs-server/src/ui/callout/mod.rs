@@ -1,6 +1,7 @@
 mod callout_ui;
 pub mod callout_boundary;
 pub mod preview;
+pub mod api;
 
 pub use callout_boundary::*;
 pub use callout_boundary::Callout;
@@ -1,6 +1,7 @@
 mod card_ui;
 pub mod card_boundary;
 pub mod preview;
+pub mod api;
 
 pub use card_boundary::*;
 pub use card_boundary::{Card, CardHeader, CardTitle, CardDescription, CardContent, CardFooter};
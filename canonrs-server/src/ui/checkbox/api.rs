@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 // imports: use canonrs::primitives::{CheckboxState}; 
 
@@ -9,8 +9,9 @@ pub const CHECKBOX_API: ComponentApi = ComponentApi {
     description: "Checkbox input",
     props: &[
         PropDef { name: "children", kind: PropType::Children, required: true, default: None, description: "Child elements" },
-        PropDef { name: "checked", kind: PropType::Bool, required: false, default: Some("false"), description: "Whether the component is checked" },
+        PropDef { name: "checked", kind: PropType::Enum(&["unchecked", "checked", "indeterminate"]), required: false, default: Some("unchecked"), description: "Whether the component is checked" },
         PropDef { name: "disabled", kind: PropType::Bool, required: false, default: Some("false"), description: "Whether the component is disabled" },
+        PropDef { name: "required", kind: PropType::String, required: false, default: Some("optional"), description: "Whether a value is required" },
         PropDef { name: "name", kind: PropType::String, required: false, default: Some(""), description: "Form field name" },
         PropDef { name: "class", kind: PropType::String, required: false, default: Some(""), description: "Additional CSS class names" },
     ],
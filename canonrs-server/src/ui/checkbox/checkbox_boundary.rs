@@ -4,20 +4,20 @@
 use leptos::prelude::*;
 use super::checkbox_ui::Checkbox as CheckboxUi;
 pub use canonrs_core::primitives::CheckboxState;
-use canonrs_core::meta::DisabledState;
+use canonrs_core::meta::{DisabledState, RequiredState};
 
 #[component]
 pub fn Checkbox(
     children: Children,
-    #[prop(default = false)] checked: bool,
+    #[prop(default = CheckboxState::Unchecked)] checked: CheckboxState,
     #[prop(default = false)] disabled: bool,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
     #[prop(into, default = String::new())] name: String,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
-    let checked_state = if checked { CheckboxState::Checked } else { CheckboxState::Unchecked };
     let disabled_state = if disabled { DisabledState::Disabled } else { DisabledState::Enabled };
     view! {
-        <CheckboxUi checked=checked_state disabled=disabled_state name=name class=class>
+        <CheckboxUi checked=checked disabled=disabled_state required=required name=name class=class>
             {children()}
         </CheckboxUi>
     }
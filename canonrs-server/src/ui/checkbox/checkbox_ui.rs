@@ -2,18 +2,19 @@
 use leptos::prelude::*;
 use canonrs_core::primitives::{CheckboxPrimitive, CheckboxIndicatorPrimitive};
 use canonrs_core::primitives::CheckboxState;
-use canonrs_core::meta::DisabledState;
+use canonrs_core::meta::{DisabledState, RequiredState};
 
 #[component]
 pub fn Checkbox(
     children: Children,
     #[prop(default = CheckboxState::Unchecked)] checked: CheckboxState,
     #[prop(default = DisabledState::Enabled)] disabled: DisabledState,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
     #[prop(into, default = String::new())] name: String,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
     view! {
-        <CheckboxPrimitive checked=checked disabled=disabled name=name class=class>
+        <CheckboxPrimitive checked=checked disabled=disabled required=required name=name class=class>
             <CheckboxIndicatorPrimitive>"✓"</CheckboxIndicatorPrimitive>
             {children()}
         </CheckboxPrimitive>
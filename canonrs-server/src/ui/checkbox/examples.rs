@@ -1,10 +1,10 @@
 use leptos::prelude::*;
-use super::Checkbox;
+use super::{Checkbox, CheckboxState};
 
 #[component]
 pub fn BasicExample() -> impl IntoView {
     view! {
-        <Checkbox checked=false>
+        <Checkbox checked=CheckboxState::Unchecked>
             "Accept terms and conditions"
         </Checkbox>
     }
@@ -14,8 +14,8 @@ pub fn BasicExample() -> impl IntoView {
 pub fn CheckedExample() -> impl IntoView {
     view! {
         <div style="display: flex; flex-direction: column; gap: 1rem;">
-            <Checkbox checked=false>"Unchecked"</Checkbox>
-            <Checkbox checked=true>"Checked"</Checkbox>
+            <Checkbox checked=CheckboxState::Unchecked>"Unchecked"</Checkbox>
+            <Checkbox checked=CheckboxState::Checked>"Checked"</Checkbox>
         </div>
     }
 }
@@ -24,8 +24,8 @@ pub fn CheckedExample() -> impl IntoView {
 pub fn DisabledExample() -> impl IntoView {
     view! {
         <div style="display: flex; flex-direction: column; gap: 1rem;">
-            <Checkbox checked=false disabled=true>"Disabled unchecked"</Checkbox>
-            <Checkbox checked=true disabled=true>"Disabled checked"</Checkbox>
+            <Checkbox checked=CheckboxState::Unchecked disabled=true>"Disabled unchecked"</Checkbox>
+            <Checkbox checked=CheckboxState::Checked disabled=true>"Disabled checked"</Checkbox>
         </div>
     }
 }
@@ -1,12 +1,12 @@
 use leptos::prelude::*;
-use super::checkbox_boundary::Checkbox;
+use super::checkbox_boundary::{Checkbox, CheckboxState};
 use canonrs_core::primitives::layout::stack::{StackPrimitive as Stack, StackDirection, StackGap};
 
 #[component]
 pub fn CheckboxShowcasePreview() -> impl IntoView {
     view! {
         <Stack direction=StackDirection::Vertical gap=StackGap::Lg>
-            <Checkbox checked=true>
+            <Checkbox checked=CheckboxState::Checked>
                 <span>"Remember me"</span>
             </Checkbox>
             <p data-rs-showcase-preview-anchor="">
@@ -16,9 +16,10 @@ pub fn CheckboxShowcasePreview() -> impl IntoView {
                 <span data-rs-showcase-preview-label="">"States"</span>
                 <Stack direction=StackDirection::Horizontal gap=StackGap::Md>
                     <Checkbox><span>"Unchecked"</span></Checkbox>
-                    <Checkbox checked=true><span>"Checked"</span></Checkbox>
+                    <Checkbox checked=CheckboxState::Checked><span>"Checked"</span></Checkbox>
+                    <Checkbox checked=CheckboxState::Indeterminate><span>"Indeterminate"</span></Checkbox>
                     <Checkbox disabled=true><span>"Disabled"</span></Checkbox>
-                    <Checkbox checked=true disabled=true><span>"Checked + Disabled"</span></Checkbox>
+                    <Checkbox checked=CheckboxState::Checked disabled=true><span>"Checked + Disabled"</span></Checkbox>
                 </Stack>
             </Stack>
         </Stack>
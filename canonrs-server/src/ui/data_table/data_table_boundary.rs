@@ -14,7 +14,7 @@ use super::data_table_ui::{
 pub fn DataTable<T>(
     data: Vec<T>,
     columns: Vec<DataTableColumn<T>>,
-    #[prop(default = DataTableDensity::default())] density: DataTableDensity,
+    #[prop(into, default = None)] density: Option<DataTableDensity>,
     #[prop(into, default = String::new())] class: String,
     #[prop(default = 10)] page_size: usize,
     #[prop(default = false)] selectable: bool,
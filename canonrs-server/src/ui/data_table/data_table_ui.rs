@@ -12,6 +12,7 @@ use canonrs_core::primitives::{
     DataTableCellPrimitive, DataTableEmptyPrimitive,
     DataTableDensity, SortDirection,
 };
+use canonrs_core::infra::density::DensityContext;
 use crate::ui::dropdown_menu::{
     DropdownMenu, DropdownMenuItem,
 };
@@ -74,7 +75,7 @@ impl RowAction {
 pub fn DataTableStatic<T>(
     data: Vec<T>,
     columns: Vec<DataTableColumn<T>>,
-    #[prop(default = DataTableDensity::default())] density: DataTableDensity,
+    #[prop(into, default = None)] density: Option<DataTableDensity>,
     #[prop(into, default = String::new())] class: String,
     #[prop(default = 10)] page_size: usize,
     #[prop(default = false)] selectable: bool,
@@ -103,7 +104,11 @@ where
     let bulk_actions = StoredValue::new(bulk_actions);
     let row_id_fn = StoredValue::new(row_id_fn);
     let row_label_fn = StoredValue::new(row_label_fn);
-    let initial_density = density.as_str();
+    // Toggle: explícito > DensityProvider > comfortable; o CSS herda sozinho quando None
+    let initial_density = density
+        .or_else(|| use_context::<DensityContext>().map(|ctx| ctx.density.get_untracked()))
+        .unwrap_or_default()
+        .as_str();
 
     view! {
         <DataTablePrimitive
@@ -446,8 +451,7 @@ pub fn DataTableCore(
     view! {
         <DataTablePrimitive
             class=class
-            density=DataTableDensity::Comfortable
-            attr:data-rs-density=density
+            density=DataTableDensity::parse(density).unwrap_or_default()
         >
             <DataTableToolbarPrimitive>
                 <input
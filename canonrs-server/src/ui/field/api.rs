@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 pub const FIELD_API: ComponentApi = ComponentApi {
     id: "field",
@@ -10,6 +10,7 @@ pub const FIELD_API: ComponentApi = ComponentApi {
         PropDef { name: "orientation", kind: PropType::String, required: false, default: Some("vertical"), description: "Horizontal or vertical orientation" },
         PropDef { name: "_validation", kind: PropType::String, required: false, default: Some("none"), description: "Prop value" },
         PropDef { name: "disabled", kind: PropType::Bool, required: false, default: Some("false"), description: "Whether the component is disabled" },
+        PropDef { name: "required", kind: PropType::String, required: false, default: Some("optional"), description: "Whether a value is required" },
         PropDef { name: "class", kind: PropType::String, required: false, default: Some(""), description: "Additional CSS class names" },
     ],
 };
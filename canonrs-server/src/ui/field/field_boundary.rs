@@ -4,6 +4,7 @@
 pub use super::field_ui::{FieldLabel, FieldDescription, FieldError, FieldSet, FieldGroup, FieldContent};
 pub use super::variants::{FieldOrientation, FieldValidation};
 use leptos::prelude::*;
+use canonrs_core::meta::RequiredState;
 use super::field_ui::Field as FieldUi;
 
 #[component]
@@ -12,10 +13,11 @@ pub fn Field(
     #[prop(default = FieldOrientation::Vertical)] orientation: FieldOrientation,
     #[prop(default = FieldValidation::None)] _validation: FieldValidation,
     #[prop(default = false)] disabled: bool,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
     view! {
-        <FieldUi orientation=orientation _validation=_validation disabled=disabled class=class>
+        <FieldUi orientation=orientation _validation=_validation disabled=disabled required=required class=class>
             {children()}
         </FieldUi>
     }
@@ -2,6 +2,7 @@
 
 use leptos::prelude::*;
 use canonrs_core::separator::SeparatorOrientation;
+use canonrs_core::meta::RequiredState;
 use canonrs_core::primitives::{
     FieldPrimitive, FieldSetPrimitive, FieldLabelPrimitive,
     FieldDescriptionPrimitive, FieldErrorPrimitive, FieldGroupPrimitive,
@@ -48,10 +49,12 @@ pub fn Field(
     #[prop(default = FieldOrientation::Vertical)] orientation: FieldOrientation,
     #[prop(default = FieldValidation::None)] _validation: FieldValidation,
     #[prop(default = false)] disabled: bool,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
     view! {
         <FieldPrimitive
+            required=required
             attr:data-rs-orientation={orientation.as_str()}
             attr:data-rs-validation={_validation.as_str()}
             attr:data-rs-disabled={if disabled { "true" } else { "false" }}
@@ -78,10 +81,11 @@ pub fn FieldContent(
 pub fn FieldLabel(
     children: Children,
     #[prop(into, default = String::new())] html_for: String,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
     view! {
-        <FieldLabelPrimitive html_for=html_for class=class>
+        <FieldLabelPrimitive html_for=html_for required=required class=class>
             {children()}
         </FieldLabelPrimitive>
     }
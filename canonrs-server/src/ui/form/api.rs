@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 // imports: use canonrs::primitives::{FormValidationState, FormMethod, FormEnctype}; 
 
@@ -52,7 +52,7 @@ pub const FORMLABEL_API: ComponentApi = ComponentApi {
     props: &[
         PropDef { name: "children", kind: PropType::Children, required: true, default: None, description: "Child elements" },
         PropDef { name: "html_for", kind: PropType::String, required: false, default: Some(""), description: "Prop value" },
-        PropDef { name: "required", kind: PropType::Bool, required: false, default: Some("false"), description: "Prop value" },
+        PropDef { name: "required", kind: PropType::Bool, required: false, default: Some("false"), description: "Whether a value is required" },
         PropDef { name: "class", kind: PropType::String, required: false, default: Some(""), description: "Additional CSS class names" },
     ],
 };
@@ -1,6 +1,7 @@
 mod inline_notice_ui;
 pub mod inline_notice_boundary;
 pub mod preview;
+pub mod api;
 
 pub use inline_notice_boundary::*;
 pub use inline_notice_boundary::InlineNotice;
@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 // imports: use canonrs::primitives::{InputVariant, InputSize}; 
 
@@ -17,6 +17,9 @@ pub const INPUT_API: ComponentApi = ComponentApi {
         PropDef { name: "variant", kind: PropType::Enum(&["default", "error", "success", "warning"]), required: false, default: Some("default"), description: "Visual variant of the component" },
         PropDef { name: "size", kind: PropType::Enum(&["md", "sm", "lg"]), required: false, default: Some("md"), description: "Size variant of the component" },
         PropDef { name: "disabled", kind: PropType::String, required: false, default: Some("enabled"), description: "Whether the component is disabled" },
+        PropDef { name: "readonly", kind: PropType::String, required: false, default: Some("editable"), description: "Whether the value is read-only" },
+        PropDef { name: "required", kind: PropType::String, required: false, default: Some("optional"), description: "Whether a value is required" },
+        PropDef { name: "invalid", kind: PropType::String, required: false, default: Some("valid"), description: "Whether the value failed validation" },
     ],
 };
 
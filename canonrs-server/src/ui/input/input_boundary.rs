@@ -7,7 +7,7 @@ use canonrs_core::primitives::{
     InputVariant,
     InputSize
 };
-use canonrs_core::meta::DisabledState;
+use canonrs_core::meta::{DisabledState, ReadonlyState, RequiredState, InvalidState};
 
 #[component]
 pub fn Input(
@@ -20,6 +20,9 @@ pub fn Input(
     #[prop(default = InputVariant::Default)] variant:   InputVariant,
     #[prop(default = InputSize::Md)] size:              InputSize,
     #[prop(default = DisabledState::Enabled)] disabled: DisabledState,
+    #[prop(default = ReadonlyState::Editable)] readonly: ReadonlyState,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
+    #[prop(default = InvalidState::Valid)] invalid:     InvalidState,
 ) -> impl IntoView {
     view! {
         <InputUi
@@ -33,6 +36,9 @@ pub fn Input(
             variant=variant
             size=size
             disabled=disabled
+            readonly=readonly
+            required=required
+            invalid=invalid
         />
     }
 }
@@ -1,7 +1,7 @@
 #![allow(unreachable_pub, dead_code)]
 use leptos::prelude::*;
 use canonrs_core::primitives::{InputPrimitive, InputVariant, InputSize};
-use canonrs_core::meta::DisabledState;
+use canonrs_core::meta::{DisabledState, ReadonlyState, RequiredState, InvalidState};
 
 #[component]
 pub fn Input(
@@ -11,6 +11,9 @@ pub fn Input(
     #[prop(into, default = String::new())] value: String,
     #[prop(into, default = String::new())] rs_value: String,
     #[prop(default = DisabledState::Enabled)] disabled: DisabledState,
+    #[prop(default = ReadonlyState::Editable)] readonly: ReadonlyState,
+    #[prop(default = RequiredState::Optional)] required: RequiredState,
+    #[prop(default = InvalidState::Valid)] invalid: InvalidState,
     #[prop(default = InputVariant::Default)] variant: InputVariant,
     #[prop(default = InputSize::Md)] size: InputSize,
     #[prop(into, default = String::new())] placeholder: String,
@@ -23,6 +26,9 @@ pub fn Input(
             value=value
             rs_value=rs_value
             disabled=disabled
+            readonly=readonly
+            required=required
+            invalid=invalid
             placeholder=placeholder
             aria_label=aria_label
             input_type=input_type
@@ -1,6 +1,7 @@
 mod label_ui;
 pub mod label_boundary;
 pub mod preview;
+pub mod api;
 
 pub use label_boundary::*;
 pub use label_boundary::Label;
@@ -1,6 +1,7 @@
 mod link_ui;
 pub mod link_boundary;
 pub mod preview;
+pub mod api;
 
 pub use link_boundary::*;
 pub use link_boundary::Link;
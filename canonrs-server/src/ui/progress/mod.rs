@@ -1,6 +1,7 @@
 mod progress_ui;
 pub mod progress_boundary;
 pub mod preview;
+pub mod api;
 
 pub use progress_boundary::*;
 pub use progress_boundary::Progress;
@@ -1,6 +1,7 @@
 mod separator_ui;
 pub mod separator_boundary;
 pub mod preview;
+pub mod api;
 
 pub use separator_boundary::*;
 pub use separator_boundary::Separator;
@@ -1,6 +1,7 @@
 mod skeleton_ui;
 pub mod skeleton_boundary;
 pub mod preview;
+pub mod api;
 
 pub use skeleton_boundary::*;
 pub use skeleton_boundary::Skeleton;
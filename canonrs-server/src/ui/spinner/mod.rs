@@ -1,6 +1,7 @@
 mod spinner_ui;
 pub mod spinner_boundary;
 pub mod preview;
+pub mod api;
 
 pub use spinner_boundary::*;
 pub use spinner_boundary::Spinner;
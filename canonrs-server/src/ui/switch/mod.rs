@@ -1,6 +1,7 @@
 mod switch_ui;
 pub mod switch_boundary;
 pub mod preview;
+pub mod api;
 
 pub use switch_boundary::*;
 pub use switch_boundary::Switch;
@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 pub const TEXTAREA_API: ComponentApi = ComponentApi {
     id: "textarea",
@@ -10,8 +10,8 @@ pub const TEXTAREA_API: ComponentApi = ComponentApi {
         PropDef { name: "placeholder", kind: PropType::String, required: false, default: Some(""), description: "Placeholder text" },
         PropDef { name: "name", kind: PropType::String, required: false, default: Some(""), description: "Form field name" },
         PropDef { name: "disabled", kind: PropType::String, required: false, default: Some("enabled"), description: "Whether the component is disabled" },
-        PropDef { name: "readonly", kind: PropType::Bool, required: false, default: Some("false"), description: "Prop value" },
-        PropDef { name: "required", kind: PropType::Bool, required: false, default: Some("false"), description: "Prop value" },
+        PropDef { name: "readonly", kind: PropType::Bool, required: false, default: Some("false"), description: "Whether the value is read-only" },
+        PropDef { name: "required", kind: PropType::Bool, required: false, default: Some("false"), description: "Whether a value is required" },
         PropDef { name: "aria_labelledby", kind: PropType::String, required: false, default: Some(""), description: "Prop value" },
         PropDef { name: "aria_describedby", kind: PropType::String, required: false, default: Some(""), description: "Prop value" },
         PropDef { name: "rows", kind: PropType::Number, required: false, default: Some("3u32"), description: "Prop value" },
@@ -1,6 +1,6 @@
 // AUTO-GENERATED by build.rs — do not edit manually.
 // Source: *_boundary.rs + builder.yaml
-use crate::catalog_types::{ComponentApi, PropDef, PropType};
+use canonrs_core::catalog_types::{ComponentApi, PropDef, PropType};
 
 pub const TREE_API: ComponentApi = ComponentApi {
     id: "tree",
@@ -17,6 +17,8 @@ pub const TREEITEM_API: ComponentApi = ComponentApi {
     props: &[
         PropDef { name: "children", kind: PropType::Children, required: true, default: None, description: "Child elements" },
         PropDef { name: "has_children", kind: PropType::Bool, required: false, default: Some("false"), description: "Prop value" },
+        PropDef { name: "expanded", kind: PropType::String, required: false, default: Some("collapsed"), description: "Whether the item is expanded" },
+        PropDef { name: "checked", kind: PropType::String, required: false, default: None, description: "Whether the component is checked" },
         PropDef { name: "depth", kind: PropType::String, required: false, default: Some("0u8"), description: "Prop value" },
         PropDef { name: "class", kind: PropType::String, required: false, default: Some(""), description: "Additional CSS class names" },
     ],
@@ -1,11 +1,11 @@
 use leptos::prelude::*;
-use super::{Tree, TreeItem, TreeGroup};
+use super::{Tree, TreeItem, TreeGroup, ExpandedState};
 
 #[component]
 pub fn BasicExample() -> impl IntoView {
     view! {
         <Tree>
-            <TreeItem has_children=true expanded=true>"📁 Documents"</TreeItem>
+            <TreeItem has_children=true expanded=ExpandedState::Expanded>"📁 Documents"</TreeItem>
             <TreeGroup>
                 <TreeItem depth=1 has_children=true>"📁 Work"</TreeItem>
                 <TreeGroup>
@@ -26,7 +26,7 @@ pub fn BasicExample() -> impl IntoView {
 pub fn WithIconsExample() -> impl IntoView {
     view! {
         <Tree>
-            <TreeItem has_children=true expanded=true>"📁 Folder"</TreeItem>
+            <TreeItem has_children=true expanded=ExpandedState::Expanded>"📁 Folder"</TreeItem>
             <TreeGroup>
                 <TreeItem depth=1>"📄 File.txt"</TreeItem>
             </TreeGroup>
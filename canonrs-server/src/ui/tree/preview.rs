@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use super::tree_boundary::{Tree, TreeItem, TreeGroup};
+use super::tree_boundary::{Tree, TreeItem, TreeGroup, ExpandedState};
 
 #[component]
 pub fn TreeShowcasePreview() -> impl IntoView {
@@ -8,7 +8,7 @@ pub fn TreeShowcasePreview() -> impl IntoView {
             <div data-rs-showcase-preview-stage="">
                 <Tree>
                     <TreeItem depth=0>"Documents"</TreeItem>
-                    <TreeItem has_children=true depth=0>"Projects"</TreeItem>
+                    <TreeItem has_children=true expanded=ExpandedState::Expanded depth=0>"Projects"</TreeItem>
                     <TreeGroup>
                         <TreeItem depth=1>"canonrs"</TreeItem>
                         <TreeItem depth=1>"monorepo"</TreeItem>
@@ -5,6 +5,7 @@ use super::tree_ui::{
     TreeItem as TreeItemUi,
     TreeGroup as TreeGroupUi
 };
+pub use canonrs_core::meta::{ExpandedState, CheckedState};
 
 #[component]
 pub fn Tree(
@@ -18,10 +19,16 @@ pub fn Tree(
 pub fn TreeItem(
     children: Children,
     #[prop(default = false)] has_children: bool,
+    #[prop(default = ExpandedState::Collapsed)] expanded: ExpandedState,
+    #[prop(optional)] checked: Option<CheckedState>,
     #[prop(default = 0u8)] depth: u8,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
-    view! { <TreeItemUi has_children=has_children depth=depth class=class>{children()}</TreeItemUi> }
+    view! {
+        <TreeItemUi has_children=has_children expanded=expanded checked=checked depth=depth class=class>
+            {children()}
+        </TreeItemUi>
+    }
 }
 
 #[component]
@@ -5,6 +5,7 @@ use canonrs_core::primitives::{
     TreeItemPrimitive,
     TreeGroupPrimitive,
 };
+use canonrs_core::meta::{ExpandedState, CheckedState};
 
 #[component]
 pub fn Tree(
@@ -22,12 +23,14 @@ pub fn Tree(
 pub fn TreeItem(
     children: Children,
     #[prop(default = false)] has_children: bool,
+    #[prop(default = ExpandedState::Collapsed)] expanded: ExpandedState,
+    #[prop(optional_no_strip)] checked: Option<CheckedState>,
     #[prop(default = 0u8)] depth: u8,
     #[prop(into, default = String::new())] class: String,
 ) -> impl IntoView {
     if has_children {
         view! {
-            <TreeItemPrimitive has_children=true depth=depth class=class>
+            <TreeItemPrimitive has_children=true expanded=expanded checked=checked depth=depth class=class>
                 <span data-rs-tree-toggle="">
                     <svg xmlns="http://www.w3.org/2000/svg" width="12" height="12" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2.5" stroke-linecap="round" stroke-linejoin="round">
                         <polyline points="9 18 15 12 9 6"/>
@@ -43,7 +46,7 @@ pub fn TreeItem(
         }.into_any()
     } else {
         view! {
-            <TreeItemPrimitive has_children=false depth=depth class=class>
+            <TreeItemPrimitive has_children=false checked=checked depth=depth class=class>
                 <span data-rs-tree-indent="" />
                 <span data-rs-tree-icon="" data-rs-tree-icon-file="">
                     <svg xmlns="http://www.w3.org/2000/svg" width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="1.5" stroke-linecap="round" stroke-linejoin="round">
@@ -4,10 +4,12 @@
 pub mod style_contract;
 
 pub use style_contract::{
+    Breakpoint, Responsive,
     SpaceScale, Spacing,
     Align, Width, LayoutStyle,
     TextSize, TextWeight, TextAlign, Typography,
     Variant, ColorStyle,
     StyleProps,
+    utility_rules, class_vocabulary, UtilityRule,
     validate_style, style_from_json, StyleValidationError,
 };
@@ -1,5 +1,7 @@
 //! Color/variant contract
 
+use super::responsive::Responsive;
+
 #[derive(Clone, Copy, PartialEq, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub enum Variant {
     #[default] Default,
@@ -7,6 +9,11 @@ pub enum Variant {
 }
 
 impl Variant {
+    pub const ALL: &'static [Self] = &[
+        Self::Default, Self::Primary, Self::Secondary, Self::Success,
+        Self::Warning, Self::Danger, Self::Ghost, Self::Outline,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Default   => "default",
@@ -31,6 +38,19 @@ impl Variant {
             Self::Outline   => "variant-outline",
         }
     }
+    /// Empty for Default — no class, no rule
+    pub fn css(&self) -> &'static str {
+        match self {
+            Self::Default   => "",
+            Self::Primary   => "background: var(--color-primary); color: var(--color-primary-foreground)",
+            Self::Secondary => "background: var(--color-secondary); color: var(--color-secondary-foreground)",
+            Self::Success   => "background: var(--color-success); color: var(--color-success-foreground)",
+            Self::Warning   => "background: var(--color-warning); color: var(--color-warning-foreground)",
+            Self::Danger    => "background: var(--color-destructive); color: var(--color-destructive-foreground)",
+            Self::Ghost     => "background: transparent; color: inherit",
+            Self::Outline   => "background: transparent; border: 1px solid var(--color-border)",
+        }
+    }
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "default"   => Some(Self::Default),
@@ -48,11 +68,17 @@ impl Variant {
 
 #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ColorStyle {
-    pub variant: Option<Variant>,
+    #[serde(default, skip_serializing_if = "Responsive::is_empty")]
+    pub variant: Responsive<Variant>,
 }
 
 impl ColorStyle {
+    /// Base variant class only
     pub fn as_class(&self) -> &'static str {
-        self.variant.as_ref().map(|v| v.as_class()).unwrap_or("")
+        self.variant.base.as_ref().map(|v| v.as_class()).unwrap_or("")
+    }
+
+    pub fn classes(&self) -> Vec<String> {
+        self.variant.classes(Variant::as_class)
     }
 }
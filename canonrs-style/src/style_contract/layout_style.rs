@@ -1,5 +1,7 @@
 //! Layout style contract — align + width
 
+use super::responsive::Responsive;
+
 #[derive(Clone, Copy, PartialEq, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub enum Align {
     #[default]
@@ -11,6 +13,8 @@ pub enum Align {
 }
 
 impl Align {
+    pub const ALL: &'static [Self] = &[Self::Start, Self::Center, Self::End, Self::Between, Self::Stretch];
+
     pub fn as_class(&self) -> &'static str {
         match self {
             Self::Start   => "items-start",
@@ -20,6 +24,15 @@ impl Align {
             Self::Stretch => "items-stretch",
         }
     }
+    pub fn css(&self) -> &'static str {
+        match self {
+            Self::Start   => "align-items: flex-start",
+            Self::Center  => "align-items: center",
+            Self::End     => "align-items: flex-end",
+            Self::Between => "justify-content: space-between",
+            Self::Stretch => "align-items: stretch",
+        }
+    }
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "start"   => Some(Self::Start),
@@ -43,6 +56,8 @@ pub enum Width {
 }
 
 impl Width {
+    pub const ALL: &'static [Self] = &[Self::Auto, Self::Full, Self::Container, Self::Narrow, Self::Wide];
+
     pub fn as_class(&self) -> &'static str {
         match self {
             Self::Auto      => "w-auto",
@@ -52,6 +67,15 @@ impl Width {
             Self::Wide      => "w-wide",
         }
     }
+    pub fn css(&self) -> &'static str {
+        match self {
+            Self::Auto      => "width: auto",
+            Self::Full      => "width: 100%",
+            Self::Container => "width: 100%; max-width: var(--layout-content-max-width); margin-inline: auto",
+            Self::Narrow    => "width: 100%; max-width: var(--layout-width-xl); margin-inline: auto",
+            Self::Wide      => "width: 100%; max-width: var(--breakpoint-2xl); margin-inline: auto",
+        }
+    }
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "auto"      => Some(Self::Auto),
@@ -66,6 +90,16 @@ impl Width {
 
 #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct LayoutStyle {
-    pub align: Option<Align>,
-    pub width: Option<Width>,
+    #[serde(default, skip_serializing_if = "Responsive::is_empty")]
+    pub align: Responsive<Align>,
+    #[serde(default, skip_serializing_if = "Responsive::is_empty")]
+    pub width: Responsive<Width>,
+}
+
+impl LayoutStyle {
+    pub fn classes(&self) -> Vec<String> {
+        let mut classes = self.align.classes(Align::as_class);
+        classes.extend(self.width.classes(Width::as_class));
+        classes
+    }
 }
@@ -2,17 +2,22 @@
 //! Sits between Block/Layout structure and CSS render
 //! Zero Leptos dependency — pure Rust contracts
 
+pub mod responsive;
 pub mod spacing;
 pub mod layout_style;
 pub mod typography;
 pub mod color;
 pub mod props;
 
+pub use responsive::{Breakpoint, Responsive};
 pub use spacing::{SpaceScale, Spacing};
 pub use layout_style::{Align, Width, LayoutStyle};
 pub use typography::{TextSize, TextWeight, TextAlign, Typography};
 pub use color::{Variant, ColorStyle};
 pub use props::StyleProps;
 
+pub mod utilities;
+pub use utilities::{utility_rules, class_vocabulary, UtilityRule};
+
 pub mod validator;
 pub use validator::{validate_style, style_from_json, StyleValidationError};
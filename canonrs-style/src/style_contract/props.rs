@@ -1,4 +1,5 @@
 //! StyleProps — unified contract + class resolver
+//! Every property is Responsive — base class plus breakpoint-prefixed overrides
 
 use super::{
     spacing::Spacing,
@@ -20,56 +21,33 @@ impl StyleProps {
         Self::default()
     }
 
-    pub fn to_class(&self) -> String {
-        let mut classes: Vec<&str> = Vec::new();
-
+    /// All classes, base first then breakpoint-prefixed — e.g. "m-sm md:m-lg p-md"
+    pub fn classes(&self) -> Vec<String> {
+        let mut classes = Vec::new();
         if let Some(spacing) = &self.spacing {
-            if let Some(m) = &spacing.margin {
-                classes.push(m.as_margin_class());
-            }
-            if let Some(p) = &spacing.padding {
-                classes.push(p.as_padding_class());
-            }
+            classes.extend(spacing.classes());
         }
-
         if let Some(layout) = &self.layout {
-            if let Some(align) = &layout.align {
-                classes.push(align.as_class());
-            }
-            if let Some(width) = &layout.width {
-                classes.push(width.as_class());
-            }
+            classes.extend(layout.classes());
         }
-
         if let Some(typography) = &self.typography {
-            if let Some(size) = &typography.size {
-                classes.push(size.as_class());
-            }
-            if let Some(weight) = &typography.weight {
-                classes.push(weight.as_class());
-            }
-            if let Some(align) = &typography.align {
-                classes.push(align.as_class());
-            }
+            classes.extend(typography.classes());
         }
-
         if let Some(color) = &self.color {
-            let c = color.as_class();
-            if !c.is_empty() {
-                classes.push(c);
-            }
+            classes.extend(color.classes());
         }
+        classes
+    }
 
-        classes.into_iter().filter(|c| !c.is_empty()).collect::<Vec<_>>().join(" ")
+    pub fn to_class(&self) -> String {
+        self.classes().join(" ")
     }
 
-    /// Returns classes prefixed with breakpoint, e.g. "md:m-md md:p-sm"
+    /// Base classes re-declared at `bp`, e.g. "md:m-md md:p-sm" — values already keyed to a breakpoint are skipped
     pub fn to_class_bp(&self, bp: &str) -> String {
-        let base = self.to_class();
-        if base.is_empty() {
-            return String::new();
-        }
-        base.split_whitespace()
+        self.classes()
+            .into_iter()
+            .filter(|c| !c.contains(':'))
             .map(|c| format!("{}:{}", bp, c))
             .collect::<Vec<_>>()
             .join(" ")
@@ -94,4 +72,32 @@ impl StyleProps {
             color:      other.color.clone().or_else(|| self.color.clone()),
         }
     }
+
+    /// Like `merge`, but per property and per breakpoint — `other.md` overrides only `md`
+    pub fn merge_responsive(&self, other: &StyleProps) -> StyleProps {
+        fn pick<T: Clone>(a: &Option<T>, b: &Option<T>, f: impl Fn(&T, &T) -> T) -> Option<T> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(f(a, b)),
+                _                  => b.clone().or_else(|| a.clone()),
+            }
+        }
+        StyleProps {
+            spacing: pick(&self.spacing, &other.spacing, |a, b| Spacing {
+                margin:  a.margin.merge(&b.margin),
+                padding: a.padding.merge(&b.padding),
+            }),
+            layout: pick(&self.layout, &other.layout, |a, b| LayoutStyle {
+                align: a.align.merge(&b.align),
+                width: a.width.merge(&b.width),
+            }),
+            typography: pick(&self.typography, &other.typography, |a, b| Typography {
+                size:   a.size.merge(&b.size),
+                weight: a.weight.merge(&b.weight),
+                align:  a.align.merge(&b.align),
+            }),
+            color: pick(&self.color, &other.color, |a, b| ColorStyle {
+                variant: a.variant.merge(&b.variant),
+            }),
+        }
+    }
 }
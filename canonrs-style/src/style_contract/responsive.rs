@@ -0,0 +1,179 @@
+//! Responsive contract — one value per breakpoint (base, sm, md, lg, xl)
+//! Mobile-first: `base` has no prefix, the others resolve to `sm:`, `md:`... (min-width)
+//! JSON accepts either a plain value (`"md"`) or the keyed shape (`{"base": "sm", "lg": "xl"}`)
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Breakpoint {
+    Base,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Breakpoint {
+    pub const ALL: [Breakpoint; 5] = [Self::Base, Self::Sm, Self::Md, Self::Lg, Self::Xl];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Base => "base",
+            Self::Sm   => "sm",
+            Self::Md   => "md",
+            Self::Lg   => "lg",
+            Self::Xl   => "xl",
+        }
+    }
+
+    /// Class prefix — None for base
+    pub fn prefix(&self) -> Option<&'static str> {
+        match self {
+            Self::Base => None,
+            other      => Some(other.as_str()),
+        }
+    }
+
+    /// min-width token in canonrs-tokens FOUNDATION_BREAKPOINTS
+    pub fn token(&self) -> Option<&'static str> {
+        match self {
+            Self::Base => None,
+            Self::Sm   => Some("breakpoint-sm"),
+            Self::Md   => Some("breakpoint-md"),
+            Self::Lg   => Some("breakpoint-lg"),
+            Self::Xl   => Some("breakpoint-xl"),
+        }
+    }
+
+    /// `"md"` → Md — unknown keys are None
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|bp| bp.as_str() == s)
+    }
+
+    /// `md` + `m-lg` → `md:m-lg`; base returns the class untouched
+    pub fn apply(&self, class: &str) -> String {
+        match self.prefix() {
+            Some(prefix) => format!("{}:{}", prefix, class),
+            None         => class.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "ResponsiveShape<T>")]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct Responsive<T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sm:   Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub md:   Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lg:   Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xl:   Option<T>,
+}
+
+/// Both JSON shapes — a bare value is the base
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ResponsiveShape<T> {
+    Single(T),
+    Keyed(ResponsiveKeyed<T>),
+}
+
+/// Keyed shape — a typo'd breakpoint (`"lgg"`) is an error, not a silently dropped value
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ResponsiveKeyed<T> {
+    base: Option<T>,
+    sm:   Option<T>,
+    md:   Option<T>,
+    lg:   Option<T>,
+    xl:   Option<T>,
+}
+
+impl<T> From<ResponsiveShape<T>> for Responsive<T> {
+    fn from(shape: ResponsiveShape<T>) -> Self {
+        match shape {
+            ResponsiveShape::Single(value) => Self::base(value),
+            ResponsiveShape::Keyed(ResponsiveKeyed { base, sm, md, lg, xl }) => Self { base, sm, md, lg, xl },
+        }
+    }
+}
+
+impl<T> Default for Responsive<T> {
+    fn default() -> Self {
+        Self { base: None, sm: None, md: None, lg: None, xl: None }
+    }
+}
+
+impl<T> From<T> for Responsive<T> {
+    fn from(value: T) -> Self {
+        Self::base(value)
+    }
+}
+
+impl<T> Responsive<T> {
+    pub fn base(value: T) -> Self {
+        Self { base: Some(value), ..Self::default() }
+    }
+
+    /// Builder — `Responsive::base(Sm).at(Breakpoint::Lg, Xl)`
+    pub fn at(mut self, bp: Breakpoint, value: T) -> Self {
+        *self.slot_mut(bp) = Some(value);
+        self
+    }
+
+    pub fn get(&self, bp: Breakpoint) -> Option<&T> {
+        match bp {
+            Breakpoint::Base => self.base.as_ref(),
+            Breakpoint::Sm   => self.sm.as_ref(),
+            Breakpoint::Md   => self.md.as_ref(),
+            Breakpoint::Lg   => self.lg.as_ref(),
+            Breakpoint::Xl   => self.xl.as_ref(),
+        }
+    }
+
+    pub fn slot_mut(&mut self, bp: Breakpoint) -> &mut Option<T> {
+        match bp {
+            Breakpoint::Base => &mut self.base,
+            Breakpoint::Sm   => &mut self.sm,
+            Breakpoint::Md   => &mut self.md,
+            Breakpoint::Lg   => &mut self.lg,
+            Breakpoint::Xl   => &mut self.xl,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Defined values, base first
+    pub fn iter(&self) -> impl Iterator<Item = (Breakpoint, &T)> {
+        Breakpoint::ALL.into_iter().filter_map(move |bp| self.get(bp).map(|v| (bp, v)))
+    }
+
+    /// Breakpoint-prefixed classes — empty classes (e.g. Variant::Default) are skipped
+    pub fn classes(&self, class: impl Fn(&T) -> &'static str) -> Vec<String> {
+        self.iter()
+            .map(|(bp, v)| (bp, class(v)))
+            .filter(|(_, c)| !c.is_empty())
+            .map(|(bp, c)| bp.apply(c))
+            .collect()
+    }
+
+    /// Per-breakpoint override — `other` wins where it is defined
+    pub fn merge(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        let mut merged = self.clone();
+        for (bp, value) in other.iter() {
+            *merged.slot_mut(bp) = Some(value.clone());
+        }
+        merged
+    }
+}
@@ -1,5 +1,7 @@
 //! Spacing contract — margin + padding via token scale
 
+use super::responsive::Responsive;
+
 #[derive(Clone, Copy, PartialEq, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub enum SpaceScale {
     None,
@@ -13,6 +15,8 @@ pub enum SpaceScale {
 }
 
 impl SpaceScale {
+    pub const ALL: &'static [Self] = &[Self::None, Self::Xs, Self::Sm, Self::Md, Self::Lg, Self::Xl, Self::Xxl];
+
     pub fn as_margin_class(&self) -> &'static str {
         match self {
             Self::None => "m-0",
@@ -35,6 +39,18 @@ impl SpaceScale {
             Self::Xxl  => "p-2xl",
         }
     }
+    /// Token value — `var(--space-*)` from FOUNDATION_SPACING
+    pub fn css_value(&self) -> &'static str {
+        match self {
+            Self::None => "0",
+            Self::Xs   => "var(--space-xs)",
+            Self::Sm   => "var(--space-sm)",
+            Self::Md   => "var(--space-md)",
+            Self::Lg   => "var(--space-lg)",
+            Self::Xl   => "var(--space-xl)",
+            Self::Xxl  => "var(--space-2xl)",
+        }
+    }
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "none" => Some(Self::None),
@@ -51,6 +67,16 @@ impl SpaceScale {
 
 #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Spacing {
-    pub margin:  Option<SpaceScale>,
-    pub padding: Option<SpaceScale>,
+    #[serde(default, skip_serializing_if = "Responsive::is_empty")]
+    pub margin:  Responsive<SpaceScale>,
+    #[serde(default, skip_serializing_if = "Responsive::is_empty")]
+    pub padding: Responsive<SpaceScale>,
+}
+
+impl Spacing {
+    pub fn classes(&self) -> Vec<String> {
+        let mut classes = self.margin.classes(SpaceScale::as_margin_class);
+        classes.extend(self.padding.classes(SpaceScale::as_padding_class));
+        classes
+    }
 }
@@ -1,11 +1,15 @@
 //! Typography contract — size + weight + align
 
+use super::responsive::Responsive;
+
 #[derive(Clone, Copy, PartialEq, Debug, Default, serde::Serialize, serde::Deserialize)]
 pub enum TextSize {
     Xs, Sm, #[default] Md, Lg, Xl, Xxl, Display,
 }
 
 impl TextSize {
+    pub const ALL: &'static [Self] = &[Self::Xs, Self::Sm, Self::Md, Self::Lg, Self::Xl, Self::Xxl, Self::Display];
+
     pub fn as_class(&self) -> &'static str {
         match self {
             Self::Xs      => "text-xs",
@@ -17,6 +21,17 @@ impl TextSize {
             Self::Display => "text-display",
         }
     }
+    pub fn css(&self) -> &'static str {
+        match self {
+            Self::Xs      => "font-size: var(--font-size-xs)",
+            Self::Sm      => "font-size: var(--font-size-sm)",
+            Self::Md      => "font-size: var(--font-size-md)",
+            Self::Lg      => "font-size: var(--font-size-lg)",
+            Self::Xl      => "font-size: var(--font-size-xl)",
+            Self::Xxl     => "font-size: var(--font-size-2xl)",
+            Self::Display => "font-size: var(--font-size-5xl); line-height: var(--line-height-tight)",
+        }
+    }
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "xs" => Some(Self::Xs), "sm" => Some(Self::Sm),
@@ -33,6 +48,8 @@ pub enum TextWeight {
 }
 
 impl TextWeight {
+    pub const ALL: &'static [Self] = &[Self::Normal, Self::Medium, Self::Semibold, Self::Bold];
+
     pub fn as_class(&self) -> &'static str {
         match self {
             Self::Normal   => "font-normal",
@@ -41,6 +58,14 @@ impl TextWeight {
             Self::Bold     => "font-bold",
         }
     }
+    pub fn css(&self) -> &'static str {
+        match self {
+            Self::Normal   => "font-weight: var(--font-weight-normal)",
+            Self::Medium   => "font-weight: var(--font-weight-medium)",
+            Self::Semibold => "font-weight: var(--font-weight-semibold)",
+            Self::Bold     => "font-weight: var(--font-weight-bold)",
+        }
+    }
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "normal" => Some(Self::Normal), "medium" => Some(Self::Medium),
@@ -56,6 +81,8 @@ pub enum TextAlign {
 }
 
 impl TextAlign {
+    pub const ALL: &'static [Self] = &[Self::Left, Self::Center, Self::Right, Self::Justify];
+
     pub fn as_class(&self) -> &'static str {
         match self {
             Self::Left    => "text-left",
@@ -64,6 +91,14 @@ impl TextAlign {
             Self::Justify => "text-justify",
         }
     }
+    pub fn css(&self) -> &'static str {
+        match self {
+            Self::Left    => "text-align: left",
+            Self::Center  => "text-align: center",
+            Self::Right   => "text-align: right",
+            Self::Justify => "text-align: justify",
+        }
+    }
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "left" => Some(Self::Left), "center" => Some(Self::Center),
@@ -75,7 +110,19 @@ impl TextAlign {
 
 #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Typography {
-    pub size:   Option<TextSize>,
-    pub weight: Option<TextWeight>,
-    pub align:  Option<TextAlign>,
+    #[serde(default, skip_serializing_if = "Responsive::is_empty")]
+    pub size:   Responsive<TextSize>,
+    #[serde(default, skip_serializing_if = "Responsive::is_empty")]
+    pub weight: Responsive<TextWeight>,
+    #[serde(default, skip_serializing_if = "Responsive::is_empty")]
+    pub align:  Responsive<TextAlign>,
+}
+
+impl Typography {
+    pub fn classes(&self) -> Vec<String> {
+        let mut classes = self.size.classes(TextSize::as_class);
+        classes.extend(self.weight.classes(TextWeight::as_class));
+        classes.extend(self.align.classes(TextAlign::as_class));
+        classes
+    }
 }
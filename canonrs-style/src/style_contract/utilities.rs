@@ -0,0 +1,63 @@
+//! Utility rules — every class the contracts can emit, with its declarations
+//! Fonte única para o tokens-engine gerar o CSS (base + breakpoints)
+//! `ALL` de cada enum precisa listar todas as variantes — o check do tokens-engine compara com o vocabulário
+
+use super::spacing::SpaceScale;
+use super::layout_style::{Align, Width};
+use super::typography::{TextSize, TextWeight, TextAlign};
+use super::color::Variant;
+use super::responsive::Breakpoint;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UtilityRule {
+    pub class:        &'static str,
+    pub declarations: String,
+}
+
+impl UtilityRule {
+    fn new(class: &'static str, declarations: impl Into<String>) -> Self {
+        Self { class, declarations: declarations.into() }
+    }
+
+    /// `.md\:m-lg { margin: var(--space-lg); }` — `:` escapado no seletor
+    pub fn to_css(&self, bp: Breakpoint) -> String {
+        format!(".{} {{ {}; }}", bp.apply(self.class).replace(':', "\\:"), self.declarations)
+    }
+}
+
+/// All utility rules, unprefixed — Variant::Default has no class and is skipped
+pub fn utility_rules() -> Vec<UtilityRule> {
+    let mut rules = vec![];
+    for s in SpaceScale::ALL {
+        rules.push(UtilityRule::new(s.as_margin_class(), format!("margin: {}", s.css_value())));
+    }
+    for s in SpaceScale::ALL {
+        rules.push(UtilityRule::new(s.as_padding_class(), format!("padding: {}", s.css_value())));
+    }
+    rules.extend(Align::ALL.iter().map(|a| UtilityRule::new(a.as_class(), a.css())));
+    rules.extend(Width::ALL.iter().map(|w| UtilityRule::new(w.as_class(), w.css())));
+    rules.extend(TextSize::ALL.iter().map(|t| UtilityRule::new(t.as_class(), t.css())));
+    rules.extend(TextWeight::ALL.iter().map(|t| UtilityRule::new(t.as_class(), t.css())));
+    rules.extend(TextAlign::ALL.iter().map(|t| UtilityRule::new(t.as_class(), t.css())));
+    rules.extend(
+        Variant::ALL.iter()
+            .filter(|v| !v.as_class().is_empty())
+            .map(|v| UtilityRule::new(v.as_class(), v.css())),
+    );
+    rules
+}
+
+/// Every class `StyleProps::to_class` can emit (unprefixed) — what the generated CSS must cover
+pub fn class_vocabulary() -> Vec<&'static str> {
+    let mut classes: Vec<&'static str> = vec![];
+    classes.extend(SpaceScale::ALL.iter().map(SpaceScale::as_margin_class));
+    classes.extend(SpaceScale::ALL.iter().map(SpaceScale::as_padding_class));
+    classes.extend(Align::ALL.iter().map(Align::as_class));
+    classes.extend(Width::ALL.iter().map(Width::as_class));
+    classes.extend(TextSize::ALL.iter().map(TextSize::as_class));
+    classes.extend(TextWeight::ALL.iter().map(TextWeight::as_class));
+    classes.extend(TextAlign::ALL.iter().map(TextAlign::as_class));
+    classes.extend(Variant::ALL.iter().map(Variant::as_class));
+    classes.retain(|c| !c.is_empty());
+    classes
+}
@@ -5,6 +5,7 @@ use super::layout_style::{Align, Width};
 use super::typography::{TextSize, TextWeight, TextAlign};
 use super::color::Variant;
 use super::props::StyleProps;
+use super::responsive::{Breakpoint, Responsive};
 
 #[derive(Debug, Clone)]
 pub struct StyleValidationError {
@@ -28,6 +29,7 @@ pub fn validate_style(props: &StyleProps) -> Vec<StyleValidationError> {
 }
 
 /// Parse style from JSON value — returns StyleProps or errors
+/// Each property accepts a plain value (`"md"`) or the responsive shape (`{"base": "sm", "lg": "xl"}`)
 pub fn style_from_json(value: &serde_json::Value) -> Result<StyleProps, Vec<StyleValidationError>> {
     use super::spacing::Spacing;
     use super::layout_style::LayoutStyle;
@@ -38,72 +40,63 @@ pub fn style_from_json(value: &serde_json::Value) -> Result<StyleProps, Vec<Styl
     let mut errors = vec![];
 
     if let Some(spacing) = value.get("spacing") {
-        let mut s = Spacing::default();
-        if let Some(m) = spacing.get("margin").and_then(|v| v.as_str()) {
-            match SpaceScale::from_str(m) {
-                Some(scale) => s.margin = Some(scale),
-                None => errors.push(StyleValidationError { field: "spacing.margin", message: format!("unknown value: {}", m) }),
-            }
-        }
-        if let Some(p) = spacing.get("padding").and_then(|v| v.as_str()) {
-            match SpaceScale::from_str(p) {
-                Some(scale) => s.padding = Some(scale),
-                None => errors.push(StyleValidationError { field: "spacing.padding", message: format!("unknown value: {}", p) }),
-            }
-        }
-        style.spacing = Some(s);
+        style.spacing = Some(Spacing {
+            margin:  responsive(spacing.get("margin"), "spacing.margin", SpaceScale::from_str, &mut errors),
+            padding: responsive(spacing.get("padding"), "spacing.padding", SpaceScale::from_str, &mut errors),
+        });
     }
 
     if let Some(layout) = value.get("layout") {
-        let mut l = LayoutStyle::default();
-        if let Some(a) = layout.get("align").and_then(|v| v.as_str()) {
-            match Align::from_str(a) {
-                Some(align) => l.align = Some(align),
-                None => errors.push(StyleValidationError { field: "layout.align", message: format!("unknown value: {}", a) }),
-            }
-        }
-        if let Some(w) = layout.get("width").and_then(|v| v.as_str()) {
-            match Width::from_str(w) {
-                Some(width) => l.width = Some(width),
-                None => errors.push(StyleValidationError { field: "layout.width", message: format!("unknown value: {}", w) }),
-            }
-        }
-        style.layout = Some(l);
+        style.layout = Some(LayoutStyle {
+            align: responsive(layout.get("align"), "layout.align", Align::from_str, &mut errors),
+            width: responsive(layout.get("width"), "layout.width", Width::from_str, &mut errors),
+        });
     }
 
     if let Some(typography) = value.get("typography") {
-        let mut t = Typography::default();
-        if let Some(s) = typography.get("size").and_then(|v| v.as_str()) {
-            match TextSize::from_str(s) {
-                Some(size) => t.size = Some(size),
-                None => errors.push(StyleValidationError { field: "typography.size", message: format!("unknown value: {}", s) }),
-            }
-        }
-        if let Some(w) = typography.get("weight").and_then(|v| v.as_str()) {
-            match TextWeight::from_str(w) {
-                Some(weight) => t.weight = Some(weight),
-                None => errors.push(StyleValidationError { field: "typography.weight", message: format!("unknown value: {}", w) }),
-            }
-        }
-        if let Some(a) = typography.get("align").and_then(|v| v.as_str()) {
-            match TextAlign::from_str(a) {
-                Some(align) => t.align = Some(align),
-                None => errors.push(StyleValidationError { field: "typography.align", message: format!("unknown value: {}", a) }),
-            }
-        }
-        style.typography = Some(t);
+        style.typography = Some(Typography {
+            size:   responsive(typography.get("size"), "typography.size", TextSize::from_str, &mut errors),
+            weight: responsive(typography.get("weight"), "typography.weight", TextWeight::from_str, &mut errors),
+            align:  responsive(typography.get("align"), "typography.align", TextAlign::from_str, &mut errors),
+        });
     }
 
     if let Some(color) = value.get("color") {
-        let mut c = ColorStyle::default();
-        if let Some(v) = color.get("variant").and_then(|v| v.as_str()) {
-            match Variant::from_str(v) {
-                Some(variant) => c.variant = Some(variant),
-                None => errors.push(StyleValidationError { field: "color.variant", message: format!("unknown value: {}", v) }),
-            }
-        }
-        style.color = Some(c);
+        style.color = Some(ColorStyle {
+            variant: responsive(color.get("variant"), "color.variant", Variant::from_str, &mut errors),
+        });
     }
 
     if errors.is_empty() { Ok(style) } else { Err(errors) }
 }
+
+/// One property — plain string is the base value; object keys must be breakpoints
+fn responsive<T>(
+    value:  Option<&serde_json::Value>,
+    field:  &'static str,
+    parse:  fn(&str) -> Option<T>,
+    errors: &mut Vec<StyleValidationError>,
+) -> Responsive<T> {
+    let mut out = Responsive::default();
+    let mut set = |bp: Breakpoint, raw: &serde_json::Value, errors: &mut Vec<StyleValidationError>| {
+        let at = if bp == Breakpoint::Base { String::new() } else { format!(" at {}", bp.as_str()) };
+        match raw.as_str().map(|s| (s, parse(s))) {
+            Some((_, Some(v))) => *out.slot_mut(bp) = Some(v),
+            Some((s, None))    => errors.push(StyleValidationError { field, message: format!("unknown value{}: {}", at, s) }),
+            None               => errors.push(StyleValidationError { field, message: format!("expected string{}, got {}", at, raw) }),
+        }
+    };
+    match value {
+        None | Some(serde_json::Value::Null) => {}
+        Some(serde_json::Value::Object(map)) => {
+            for (key, raw) in map {
+                match Breakpoint::parse(key) {
+                    Some(bp) => set(bp, raw, errors),
+                    None     => errors.push(StyleValidationError { field, message: format!("unknown breakpoint: {}", key) }),
+                }
+            }
+        }
+        Some(raw) => set(Breakpoint::Base, raw, errors),
+    }
+    out
+}
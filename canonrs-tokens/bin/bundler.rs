@@ -1,28 +1,28 @@
-use std::fs;
+use crate::output::{with_path, Output};
 use std::path::Path;
-use std::io::{self, BufRead, BufReader};
+use std::io;
 
-pub fn generate(styles_dir: &Path, output_dir: &Path) -> std::io::Result<()> {
+pub fn generate(out: &mut Output, styles_dir: &Path, output_dir: &Path) -> std::io::Result<()> {
     // Canonicalize para resolver paths relativos corretamente
-    let styles_abs = styles_dir.canonicalize()?;
+    let styles_abs = styles_dir.canonicalize().map_err(|e| with_path(styles_dir, e))?;
     let entry = styles_abs.join("canonrs.css");
     let output = output_dir.join("canonrs.bundle.css");
 
     let mut bundled = String::new();
-    process_file(&entry, &styles_abs, &mut bundled)?;
+    process_file(out, &entry, &styles_abs, &mut bundled)?;
 
-    fs::write(&output, &bundled)?;
     let lines = bundled.lines().count();
-    println!("  ✓ canonrs.bundle.css ({} lines)", lines);
+    let status = out.write(&output, bundled)?;
+    println!("  {} canonrs.bundle.css ({} lines)", status, lines);
     Ok(())
 }
 
-fn process_file(file: &Path, base_dir: &Path, output: &mut String) -> io::Result<()> {
-    let reader = BufReader::new(fs::File::open(file)?);
+/// Lê via Output — no --check os gerados desta execução não estão no disco
+fn process_file(out: &Output, file: &Path, base_dir: &Path, output: &mut String) -> io::Result<()> {
+    let css = out.read_to_string(file)?;
     let file_dir = file.parent().unwrap_or(base_dir);
 
-    for line in reader.lines() {
-        let line = line?;
+    for line in css.lines() {
         let trimmed = line.trim();
 
         if trimmed.starts_with("@layer") {
@@ -34,15 +34,15 @@ fn process_file(file: &Path, base_dir: &Path, output: &mut String) -> io::Result
                 let clean_path = import_path.strip_prefix("./").unwrap_or(import_path);
                 let resolved = file_dir.join(clean_path);
 
-                if resolved.exists() {
+                if out.exists(&resolved) {
                     output.push_str(&format!("/* Bundled: {} */\n", import_path));
-                    process_file(&resolved, base_dir, output)?;
+                    process_file(out, &resolved, base_dir, output)?;
                 } else {
                     output.push_str(&format!("/* Missing: {} */\n", import_path));
                 }
             }
         } else {
-            output.push_str(&line);
+            output.push_str(line);
             output.push('\n');
         }
     }
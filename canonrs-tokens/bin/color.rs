@@ -0,0 +1,318 @@
+// Color — parser e modelo normalizado para o ingest de temas
+// Aceita: hex (#rgb #rgba #rrggbb #rrggbbaa), rgb()/rgba(), hsl()/hsla() (espaço ou vírgula),
+// oklch(), alpha (`/ a` ou 4º argumento) e canais HSL crus (`220 16% 11%`, formato das primitivas)
+// Modelo canônico em HSL — entrada hsl() é preservada sem arredondamento; o resto converte via sRGB
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HSLColor {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    /// 0.0..=1.0
+    pub alpha: f32,
+}
+
+impl HSLColor {
+    pub const fn new(h: f32, s: f32, l: f32) -> Self {
+        Self { h, s, l, alpha: 1.0 }
+    }
+
+    pub fn to_css(&self) -> String {
+        if self.alpha < 1.0 {
+            format!("hsl({} {}% {}% / {})", self.h, self.s, self.l, round(self.alpha, 4))
+        } else {
+            format!("hsl({} {}% {}%)", self.h, self.s, self.l)
+        }
+    }
+
+    /// sRGB (gamma) 0..1
+    pub fn to_rgb(&self) -> [f32; 3] {
+        let (s, l) = (self.s / 100.0, self.l / 100.0);
+        let a = s * l.min(1.0 - l);
+        let f = |n: f32| {
+            let k = (n + self.h.rem_euclid(360.0) / 30.0) % 12.0;
+            l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        [f(0.0), f(8.0), f(4.0)]
+    }
+
+    /// WCAG 2.x relative luminance
+    pub fn relative_luminance(&self) -> f32 {
+        let [r, g, b] = self.to_rgb().map(to_linear);
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Alpha composto sobre `base` (opaca) — cor efetivamente vista
+    pub fn over(&self, base: &HSLColor) -> HSLColor {
+        if self.alpha >= 1.0 {
+            return self.clone();
+        }
+        let (top, bottom) = (self.to_rgb(), base.to_rgb());
+        let mix = |i: usize| top[i] * self.alpha + bottom[i] * (1.0 - self.alpha);
+        HSLColor::from_rgb([mix(0), mix(1), mix(2)], 1.0)
+    }
+
+    pub fn from_rgb([r, g, b]: [f32; 3], alpha: f32) -> Self {
+        let (r, g, b) = (r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0));
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        // Acromático (ou quase — ruído de conversão): sem hue, sem saturação
+        let (h, s) = if d < 1e-4 {
+            (0.0, 0.0)
+        } else {
+            let s = d / (1.0 - (2.0 * l - 1.0).abs());
+            let h = if max == r {
+                60.0 * ((g - b) / d).rem_euclid(6.0)
+            } else if max == g {
+                60.0 * ((b - r) / d + 2.0)
+            } else {
+                60.0 * ((r - g) / d + 4.0)
+            };
+            (h, s)
+        };
+        Self { h: round(h, 4), s: round(s * 100.0, 4), l: round(l * 100.0, 4), alpha: alpha.clamp(0.0, 1.0) }
+    }
+
+    /// OKLCH — L 0..1, C ~0..0.4, H graus
+    pub fn to_oklch(&self) -> [f32; 3] {
+        let [r, g, b] = self.to_rgb().map(to_linear);
+        let l = (0.412_221_47 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        let ok_l = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+        let ok_a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+        let ok_b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+        let c = (ok_a * ok_a + ok_b * ok_b).sqrt();
+        let h = if c < 1e-4 { 0.0 } else { ok_b.atan2(ok_a).to_degrees().rem_euclid(360.0) };
+        [ok_l, c, h]
+    }
+
+    pub fn from_oklch([ok_l, c, h]: [f32; 3], alpha: f32) -> Self {
+        let (ok_a, ok_b) = (c * h.to_radians().cos(), c * h.to_radians().sin());
+        let l = (ok_l + 0.396_337_78 * ok_a + 0.215_803_76 * ok_b).powi(3);
+        let m = (ok_l - 0.105_561_346 * ok_a - 0.063_854_17 * ok_b).powi(3);
+        let s = (ok_l - 0.089_484_18 * ok_a - 1.291_485_5 * ok_b).powi(3);
+        let rgb = [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ];
+        Self::from_rgb(rgb.map(from_linear), alpha)
+    }
+}
+
+fn to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+fn from_linear(c: f32) -> f32 {
+    if c <= 0.003_130_8 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 }
+}
+
+fn round(v: f32, places: i32) -> f32 {
+    let p = 10f32.powi(places);
+    (v * p).round() / p
+}
+
+/// Parse de qualquer sintaxe suportada — None quando não é uma cor
+pub fn parse_color(value: &str) -> Option<HSLColor> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+    let lower = value.to_ascii_lowercase();
+    if let Some((func, args)) = lower.split_once('(') {
+        let args = args.strip_suffix(')')?;
+        let (channels, alpha) = split_args(args)?;
+        let alpha = match alpha {
+            Some(a) => parse_alpha(a)?,
+            None    => 1.0,
+        };
+        return match (func.trim(), channels.as_slice()) {
+            ("rgb" | "rgba", [r, g, b]) =>
+                Some(HSLColor::from_rgb([parse_rgb_channel(r)?, parse_rgb_channel(g)?, parse_rgb_channel(b)?], alpha)),
+            ("hsl" | "hsla", [h, s, l]) =>
+                Some(HSLColor { h: parse_hue(h)?, s: parse_percent(s)?, l: parse_percent(l)?, alpha }),
+            ("oklch", [l, c, h]) => {
+                let l = match l.strip_suffix('%') {
+                    Some(p) => p.parse::<f32>().ok()? / 100.0,
+                    None    => parse_number(l)?,
+                };
+                let c = match c.strip_suffix('%') {
+                    Some(p) => p.parse::<f32>().ok()? / 100.0 * 0.4,
+                    None    => parse_number(c)?,
+                };
+                Some(HSLColor::from_oklch([l, c, parse_hue(h)?], alpha))
+            }
+            _ => None,
+        };
+    }
+    // Canais crus — formato das primitivas (`220 16% 11%`)
+    let (channels, alpha) = split_args(value)?;
+    match channels.as_slice() {
+        [h, s, l] if s.ends_with('%') && l.ends_with('%') => Some(HSLColor {
+            h: parse_hue(h)?,
+            s: parse_percent(s)?,
+            l: parse_percent(l)?,
+            alpha: match alpha { Some(a) => parse_alpha(a)?, None => 1.0 },
+        }),
+        _ => None,
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<HSLColor> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits: Vec<u8> = match hex.len() {
+        3 | 4 => hex.chars().map(|c| u8::from_str_radix(&format!("{c}{c}"), 16)).collect::<Result<_, _>>().ok()?,
+        6 | 8 => (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16)).collect::<Result<_, _>>().ok()?,
+        _ => return None,
+    };
+    let channel = |i: usize| f32::from(digits[i]) / 255.0;
+    let alpha = if digits.len() == 4 { round(channel(3), 4) } else { 1.0 };
+    Some(HSLColor::from_rgb([channel(0), channel(1), channel(2)], alpha))
+}
+
+/// `a b c / d` ou `a, b, c, d` → (canais, alpha)
+fn split_args(args: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    if args.contains(',') {
+        let mut parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        let alpha = if parts.len() == 4 { parts.pop() } else { None };
+        return Some((parts, alpha));
+    }
+    let (channels, alpha) = match args.split_once('/') {
+        Some((c, a)) => (c, Some(a.trim())),
+        None         => (args, None),
+    };
+    Some((channels.split_whitespace().collect(), alpha))
+}
+
+fn parse_number(v: &str) -> Option<f32> {
+    if v == "none" { return Some(0.0); }
+    v.parse::<f32>().ok().filter(|n| n.is_finite())
+}
+
+fn parse_percent(v: &str) -> Option<f32> {
+    parse_number(v.strip_suffix('%').unwrap_or(v))
+}
+
+fn parse_alpha(v: &str) -> Option<f32> {
+    let a = match v.strip_suffix('%') {
+        Some(p) => parse_number(p)? / 100.0,
+        None    => parse_number(v)?,
+    };
+    Some(a.clamp(0.0, 1.0))
+}
+
+fn parse_hue(v: &str) -> Option<f32> {
+    if let Some(d) = v.strip_suffix("deg") { return parse_number(d); }
+    if let Some(t) = v.strip_suffix("turn") { return Some(parse_number(t)? * 360.0); }
+    if let Some(g) = v.strip_suffix("grad") { return Some(parse_number(g)? * 0.9); }
+    if let Some(r) = v.strip_suffix("rad") { return Some(parse_number(r)?.to_degrees()); }
+    parse_number(v)
+}
+
+/// rgb(): 0..255 ou porcentagem → 0..1
+fn parse_rgb_channel(v: &str) -> Option<f32> {
+    match v.strip_suffix('%') {
+        Some(p) => Some(parse_number(p)? / 100.0),
+        None    => Some(parse_number(v)? / 255.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hsl(value: &str) -> [f32; 4] {
+        let c = parse_color(value).unwrap_or_else(|| panic!("{value} should parse"));
+        [c.h, c.s, c.l, c.alpha]
+    }
+
+    fn assert_near(actual: [f32; 4], expected: [f32; 4], tolerance: f32) {
+        // hue é circular — 359.99 ≈ 0
+        let hue = (actual[0] - expected[0]).rem_euclid(360.0);
+        assert!(hue.min(360.0 - hue) <= tolerance, "{actual:?} != {expected:?}");
+        for (a, e) in actual.iter().zip(expected).skip(1) {
+            assert!((a - e).abs() <= tolerance, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_hex_all_lengths() {
+        assert_eq!(hsl("#fff"), [0.0, 0.0, 100.0, 1.0]);
+        assert_eq!(hsl("#f00"), [0.0, 100.0, 50.0, 1.0]);
+        assert_eq!(hsl("#f008"), [0.0, 100.0, 50.0, 0.5333]);
+        assert_eq!(hsl("#FF0000"), [0.0, 100.0, 50.0, 1.0]);
+        assert_eq!(hsl("#ff000080"), [0.0, 100.0, 50.0, 0.502]);
+        assert_near(hsl("#3b82f6"), [217.2, 91.2, 59.8, 1.0], 0.1);
+    }
+
+    #[test]
+    fn test_rgb_and_rgba() {
+        assert_eq!(hsl("rgb(255 0 0)"), hsl("#f00"));
+        assert_eq!(hsl("rgb(255, 0, 0)"), hsl("#f00"));
+        assert_eq!(hsl("rgba(255, 0, 0, 0.5)"), [0.0, 100.0, 50.0, 0.5]);
+        assert_eq!(hsl("rgb(100% 0% 0% / 50%)"), [0.0, 100.0, 50.0, 0.5]);
+        assert_eq!(hsl("RGB(0 0 0 / 2)")[3], 1.0);
+    }
+
+    #[test]
+    fn test_hsl_both_syntaxes_are_preserved() {
+        assert_eq!(hsl("hsl(220 16% 11%)"), [220.0, 16.0, 11.0, 1.0]);
+        assert_eq!(hsl("hsl(220, 16%, 11%)"), [220.0, 16.0, 11.0, 1.0]);
+        assert_eq!(hsl("hsla(220, 16%, 11%, 0.5)"), [220.0, 16.0, 11.0, 0.5]);
+        assert_eq!(hsl("hsl(220.5 16.25% 11.125% / 25%)"), [220.5, 16.25, 11.125, 0.25]);
+        // canais crus das primitivas
+        assert_eq!(hsl("220 16% 11%"), [220.0, 16.0, 11.0, 1.0]);
+        assert_eq!(hsl("220 16% 11% / 0.5"), [220.0, 16.0, 11.0, 0.5]);
+        assert_eq!(parse_color("hsl(220 16% 11% / 0.5)").unwrap().to_css(), "hsl(220 16% 11% / 0.5)");
+    }
+
+    #[test]
+    fn test_hue_units() {
+        for value in ["hsl(180 50% 50%)", "hsl(180deg 50% 50%)", "hsl(0.5turn 50% 50%)", "hsl(200grad 50% 50%)"] {
+            assert_eq!(hsl(value), [180.0, 50.0, 50.0, 1.0], "{value}");
+        }
+        assert_near(hsl("hsl(3.14159rad 50% 50%)"), [180.0, 50.0, 50.0, 1.0], 0.01);
+        assert_eq!(hsl("hsl(none 0% 50%)")[0], 0.0);
+    }
+
+    #[test]
+    fn test_oklch() {
+        assert_near(hsl("oklch(0.628 0.2577 29.23)"), [0.0, 100.0, 50.0, 1.0], 0.5);
+        assert_near(hsl("oklch(62.8% 0.2577 29.23deg / 0.5)"), [0.0, 100.0, 50.0, 0.5], 0.5);
+        assert_near(hsl("oklch(100% 0 0)"), [0.0, 0.0, 100.0, 1.0], 0.01);
+        assert_near(hsl("oklch(0 0 0)"), [0.0, 0.0, 0.0, 1.0], 0.01);
+        // roundtrip hsl → oklch → hsl
+        let blue = parse_color("#3b82f6").unwrap();
+        let back = HSLColor::from_oklch(blue.to_oklch(), 1.0);
+        assert_near([back.h, back.s, back.l, back.alpha], [blue.h, blue.s, blue.l, 1.0], 0.1);
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        for value in [
+            "", "red", "transparent", "var(--primary)", "color-mix(in srgb, red 50%, blue)",
+            "#", "#ff", "#12345", "#ggg", "#1234567",
+            "rgb(1 2)", "rgb(a b c)", "rgb(nan 0 0)", "rgb(255 0 0", "hsl(220 16% 11% 0.5 1)",
+            "oklch(0.5 0.1)", "lab(50% 40 59)", "220 16 11", "1rem",
+        ] {
+            assert!(parse_color(value).is_none(), "{value:?} should not parse");
+        }
+    }
+
+    #[test]
+    fn test_alpha_composites_over_base() {
+        let white = parse_color("#fff").unwrap();
+        let half_black = parse_color("rgb(0 0 0 / 0.5)").unwrap();
+        let seen = half_black.over(&white);
+        assert_eq!(seen.alpha, 1.0);
+        assert!((seen.l - 50.0).abs() < 0.01);
+        assert_eq!(white.over(&half_black), white);
+        assert!((white.relative_luminance() - 1.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,233 @@
+// Contrast — WCAG 2.x (e APCA opcional) para cada par bg/fg dos temas ingeridos
+// Canon rule 182: papel semântico afeta contraste — aqui ele é medido
+// Pares no vocabulário normalizado: `x-fg` ↔ `x-bg` (ou `x` quando não há `-bg`), light e dark
+
+use crate::color::HSLColor;
+use crate::output::Output;
+use crate::theme_generator::{self, ThemeColors, ThemeOverride};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// WCAG 2.x — texto normal
+pub const AA: f32 = 4.5;
+pub const AAA: f32 = 7.0;
+/// Texto grande (≥ 18pt / 14pt bold)
+pub const AA_LARGE: f32 = 3.0;
+
+#[derive(Debug, Clone)]
+pub struct ContrastResult {
+    pub theme: String,
+    pub mode: &'static str,
+    pub bg: String,
+    pub fg: String,
+    pub ratio: f32,
+    /// APCA Lc — só com `--apca`
+    pub apca: Option<f32>,
+}
+
+impl ContrastResult {
+    pub fn passes_aa(&self) -> bool {
+        self.ratio >= AA
+    }
+
+    pub fn level(&self) -> &'static str {
+        if self.ratio >= AAA { "AAA" }
+        else if self.ratio >= AA { "AA" }
+        else if self.ratio >= AA_LARGE { "AA-large" }
+        else { "fail" }
+    }
+}
+
+/// Pares bg/fg da paleta — `x-fg` com `x-bg`, senão com `x`
+pub fn pairs(palette: &BTreeMap<String, HSLColor>) -> Vec<(&str, &str)> {
+    palette.keys()
+        .filter(|k| k.contains("-fg"))
+        .filter_map(|fg| {
+            let bg = [fg.replacen("-fg", "-bg", 1), fg.replacen("-fg", "", 1)]
+                .into_iter()
+                .find(|bg| palette.contains_key(bg))?;
+            let (bg, _) = palette.get_key_value(&bg)?;
+            Some((bg.as_str(), fg.as_str()))
+        })
+        .collect()
+}
+
+/// Superfície sob fundos translúcidos — `surface-bg` opaco, senão branco (light) / preto (dark)
+pub fn page_surface(palette: &BTreeMap<String, HSLColor>, mode: &str) -> HSLColor {
+    palette.get("surface-bg").filter(|c| c.alpha >= 1.0).cloned()
+        .unwrap_or_else(|| if mode == "dark" { HSLColor::new(0.0, 0.0, 0.0) } else { HSLColor::new(0.0, 0.0, 100.0) })
+}
+
+/// Contraste efetivamente visto — bg composto sobre a página, fg sobre o bg
+pub fn composited_ratio(fg: &HSLColor, bg: &HSLColor, page: &HSLColor) -> f32 {
+    let bg = bg.over(page);
+    wcag_ratio(&fg.over(&bg), &bg)
+}
+
+pub fn check_palette(theme: &str, mode: &'static str, palette: &BTreeMap<String, HSLColor>, apca: bool) -> Vec<ContrastResult> {
+    let page = page_surface(palette, mode);
+
+    pairs(palette).into_iter().map(|(bg_key, fg_key)| {
+        let bg = palette[bg_key].over(&page);
+        let fg = palette[fg_key].over(&bg);
+        ContrastResult {
+            theme: theme.to_string(),
+            mode,
+            bg: bg_key.to_string(),
+            fg: fg_key.to_string(),
+            ratio: wcag_ratio(&fg, &bg),
+            apca: apca.then(|| apca_lc(&fg, &bg)),
+        }
+    }).collect()
+}
+
+/// Todos os temas ingeridos, com os mesmos overrides aplicados em themes.css
+pub fn check_themes(themes: &[(String, ThemeColors)], overrides: &HashMap<String, ThemeOverride>, apca: bool) -> Vec<ContrastResult> {
+    let mut results = vec![];
+    for (name, colors) in themes {
+        let palettes = theme_generator::theme_palettes(colors, overrides.get(name));
+        results.extend(check_palette(name, "light", &palettes.light, apca));
+        results.extend(check_palette(name, "dark", &palettes.dark, apca));
+    }
+    results
+}
+
+pub fn wcag_ratio(a: &HSLColor, b: &HSLColor) -> f32 {
+    let (la, lb) = (a.relative_luminance(), b.relative_luminance());
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// APCA-W3 0.0.98G — Lc com sinal (positivo: texto escuro sobre claro)
+pub fn apca_lc(text: &HSLColor, bg: &HSLColor) -> f32 {
+    fn y(c: &HSLColor) -> f32 {
+        let [r, g, b] = c.to_rgb().map(|v| v.powf(2.4));
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        if y < 0.022 { y + (0.022 - y).powf(1.414) } else { y }
+    }
+    let (yt, yb) = (y(text), y(bg));
+    if (yb - yt).abs() < 0.0005 {
+        return 0.0;
+    }
+    let lc = if yb > yt {
+        let sapc = (yb.powf(0.56) - yt.powf(0.57)) * 1.14;
+        if sapc < 0.1 { 0.0 } else { sapc - 0.027 }
+    } else {
+        let sapc = (yb.powf(0.65) - yt.powf(0.62)) * 1.14;
+        if sapc > -0.1 { 0.0 } else { sapc + 0.027 }
+    };
+    lc * 100.0
+}
+
+pub fn print_summary(results: &[ContrastResult]) {
+    // Ordem de verificação — tema a tema, light antes de dark
+    let mut groups: Vec<((&str, &str), Vec<&ContrastResult>)> = vec![];
+    for r in results {
+        let key = (r.theme.as_str(), r.mode);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, rows)) => rows.push(r),
+            None            => groups.push((key, vec![r])),
+        }
+    }
+    for ((theme, mode), rows) in groups {
+        let failing: Vec<_> = rows.iter().filter(|r| !r.passes_aa()).collect();
+        if failing.is_empty() {
+            println!("  ✓ {} ({}): {} pairs ≥ AA", theme, mode, rows.len());
+            continue;
+        }
+        println!("  ⚠ {} ({}): {}/{} pairs below AA", theme, mode, failing.len(), rows.len());
+        for r in failing {
+            let apca = r.apca.map(|lc| format!(", APCA Lc {:.1}", lc)).unwrap_or_default();
+            println!("      {} on {}: {:.2}:1 [{}]{}", r.fg, r.bg, r.ratio, r.level(), apca);
+        }
+    }
+}
+
+pub fn write_report(out: &mut Output, path: &Path, results: &[ContrastResult]) -> std::io::Result<()> {
+    let rows: Vec<Value> = results.iter().map(|r| json!({
+        "theme": r.theme,
+        "mode": r.mode,
+        "bg": r.bg,
+        "fg": r.fg,
+        "ratio": (r.ratio * 100.0).round() / 100.0,
+        "level": r.level(),
+        "aa": r.passes_aa(),
+        "apca": r.apca.map(|lc| (lc * 10.0).round() / 10.0),
+    })).collect();
+    let report = json!({
+        "thresholds": { "aa": AA, "aaa": AAA, "aa_large": AA_LARGE },
+        "failing": results.iter().filter(|r| !r.passes_aa()).count(),
+        "pairs": rows,
+    });
+    let status = out.write(path, serde_json::to_string_pretty(&report).map_err(std::io::Error::other)? + "\n")?;
+    println!("  {} {}", status, path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::parse_color;
+
+    fn color(value: &str) -> HSLColor {
+        parse_color(value).unwrap()
+    }
+
+    fn palette(entries: &[(&str, &str)]) -> BTreeMap<String, HSLColor> {
+        entries.iter().map(|(k, v)| (k.to_string(), color(v))).collect()
+    }
+
+    #[test]
+    fn test_wcag_ratio() {
+        let (black, white) = (color("#000"), color("#fff"));
+        assert!((wcag_ratio(&black, &white) - 21.0).abs() < 0.01);
+        assert_eq!(wcag_ratio(&black, &white), wcag_ratio(&white, &black));
+        assert!((wcag_ratio(&white, &white) - 1.0).abs() < 1e-4);
+        // #767676 é o cinza mais claro que passa AA sobre branco
+        assert!((wcag_ratio(&color("#767676"), &white) - 4.54).abs() < 0.01);
+        assert!(wcag_ratio(&color("#777"), &white) < AA);
+    }
+
+    #[test]
+    fn test_apca_lc() {
+        let (black, white) = (color("#000"), color("#fff"));
+        assert!((apca_lc(&black, &white) - 106.04).abs() < 0.1);
+        assert!((apca_lc(&white, &black) + 107.88).abs() < 0.1);
+        assert_eq!(apca_lc(&white, &white), 0.0);
+        // texto claro sobre fundo escuro tem sinal negativo
+        assert!(apca_lc(&color("#ddd"), &color("#222")) < 0.0);
+        assert!(apca_lc(&color("#222"), &color("#ddd")) > 0.0);
+    }
+
+    #[test]
+    fn test_pairs_prefer_bg_then_bare_key() {
+        let p = palette(&[
+            ("surface-bg", "#fff"), ("surface-fg", "#000"),
+            ("surface-muted", "#eee"), ("surface-fg-muted", "#555"),
+            ("sidebar", "#fafafa"), ("sidebar-fg", "#111"),
+            ("action-primary-bg", "#00f"), ("action-primary", "#f00"), ("action-primary-fg", "#fff"),
+            ("orphan-fg", "#000"), ("chart-1", "#f0f"),
+        ]);
+        assert_eq!(pairs(&p), vec![
+            ("action-primary-bg", "action-primary-fg"),
+            ("sidebar", "sidebar-fg"),
+            ("surface-bg", "surface-fg"),
+            ("surface-muted", "surface-fg-muted"),
+        ]);
+    }
+
+    #[test]
+    fn test_check_palette_composites_translucent_bg() {
+        let p = palette(&[
+            ("surface-bg", "#000"), ("surface-fg", "#fff"),
+            // branco transparente sobre a superfície preta é visto como preto
+            ("overlay-bg", "rgb(255 255 255 / 0)"), ("overlay-fg", "#fff"),
+        ]);
+        let results = check_palette("t", "light", &p, true);
+        let overlay = results.iter().find(|r| r.bg == "overlay-bg").unwrap();
+        assert!((overlay.ratio - 21.0).abs() < 0.01);
+        assert_eq!(overlay.level(), "AAA");
+        assert!(overlay.apca.is_some());
+        assert!(check_palette("t", "light", &p, false).iter().all(|r| r.apca.is_none()));
+    }
+}
@@ -0,0 +1,134 @@
+// Dark synthesis — paleta dark derivada da light quando o ingest não tem `.dark`
+// OKLCH: lightness invertida, hue preservado; cores saturadas (marca, estados) quase não se movem
+// Depois, cada par bg/fg é corrigido para não ficar com contraste pior que no light (alvo máximo: AA)
+
+use crate::color::HSLColor;
+use crate::contrast::{self, AA};
+use std::collections::BTreeMap;
+
+/// Faixa de lightness do dark — nem preto puro nem branco puro
+const L_MIN: f32 = 0.14;
+const L_MAX: f32 = 0.96;
+/// Chroma: abaixo de NEUTRAL inverte por completo (cinzas, slates); acima de BRAND mantém a lightness
+const CHROMA_NEUTRAL: f32 = 0.05;
+const CHROMA_BRAND: f32 = 0.15;
+const STEP: f32 = 0.02;
+
+pub fn synthesize(light: &BTreeMap<String, HSLColor>) -> BTreeMap<String, HSLColor> {
+    let mut dark: BTreeMap<String, HSLColor> = light.iter()
+        .map(|(key, color)| (key.clone(), invert(color)))
+        .collect();
+
+    let pairs: Vec<(String, String)> = contrast::pairs(light).into_iter()
+        .map(|(bg, fg)| (bg.to_string(), fg.to_string()))
+        .collect();
+    // Mesma composição de alpha do contrast::check_palette — o alvo e o ajuste medem o que o relatório mede
+    let (light_page, dark_page) = (contrast::page_surface(light, "light"), contrast::page_surface(&dark, "dark"));
+    for (bg_key, fg_key) in pairs {
+        let target = contrast::composited_ratio(&light[&fg_key], &light[&bg_key], &light_page).min(AA);
+        let bg = dark[&bg_key].over(&dark_page);
+        if let Some(fg) = dark.get_mut(&fg_key) {
+            *fg = push_apart(fg, &bg, target);
+        }
+    }
+    dark
+}
+
+fn invert(color: &HSLColor) -> HSLColor {
+    let [l, c, h] = color.to_oklch();
+    let inverted = L_MIN + (1.0 - l) * (L_MAX - L_MIN);
+    let keep = ((c - CHROMA_NEUTRAL) / (CHROMA_BRAND - CHROMA_NEUTRAL)).clamp(0.0, 1.0);
+    let l = inverted + (l.clamp(L_MIN, L_MAX) - inverted) * keep;
+    HSLColor::from_oklch([l, c, h], color.alpha)
+}
+
+/// Ajusta a lightness do fg até atingir `target` — o menor deslocamento em qualquer direção;
+/// se nenhum atinge, fica com o de maior contraste
+/// `bg` já composto sobre a página; o fg (e o alpha dele) é composto sobre o bg a cada candidato
+fn push_apart(fg: &HSLColor, bg: &HSLColor, target: f32) -> HSLColor {
+    let ratio_of = |c: &HSLColor| contrast::wcag_ratio(&c.over(bg), bg);
+    if ratio_of(fg) >= target {
+        return fg.clone();
+    }
+    let [l, c, h] = fg.to_oklch();
+    let mut best = (ratio_of(fg), fg.clone());
+    for step in 1..=(1.0 / STEP) as usize {
+        let delta = step as f32 * STEP;
+        for candidate_l in [l + delta, l - delta] {
+            if !(0.0..=1.0).contains(&candidate_l) {
+                continue;
+            }
+            let candidate = HSLColor::from_oklch([candidate_l, c, h], fg.alpha);
+            let ratio = ratio_of(&candidate);
+            if ratio >= target {
+                return candidate;
+            }
+            if ratio > best.0 {
+                best = (ratio, candidate);
+            }
+        }
+    }
+    best.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::parse_color;
+
+    fn palette(entries: &[(&str, &str)]) -> BTreeMap<String, HSLColor> {
+        entries.iter().map(|(k, v)| (k.to_string(), parse_color(v).unwrap())).collect()
+    }
+
+    #[test]
+    fn test_synthesize_reaches_light_contrast_capped_at_aa() {
+        let light = palette(&[
+            ("surface-bg", "#ffffff"), ("surface-fg", "#111111"),
+            ("action-primary-bg", "#3b82f6"), ("action-primary-fg", "#ffffff"),
+            ("state-error-bg", "#fee2e2"), ("state-error-fg", "#b91c1c"),
+            ("overlay-bg", "rgb(0 0 0 / 0.5)"), ("overlay-fg", "#ffffff"),
+            ("state-info-bg", "#e0f2fe"), ("state-info-fg", "hsl(200 98% 39% / 0.8)"),
+        ]);
+        let dark = synthesize(&light);
+        let (light_page, dark_page) = (contrast::page_surface(&light, "light"), contrast::page_surface(&dark, "dark"));
+        for (bg, fg) in contrast::pairs(&light) {
+            let target = contrast::composited_ratio(&light[fg], &light[bg], &light_page).min(AA);
+            let reached = contrast::composited_ratio(&dark[fg], &dark[bg], &dark_page);
+            assert!(reached >= target, "{fg} on {bg}: {reached:.2} < {target:.2}");
+        }
+        // o relatório mede o mesmo que a síntese corrigiu
+        let report = contrast::check_palette("t", "dark", &dark, false);
+        assert!(report.iter().all(|r| r.ratio >= AA.min(contrast::composited_ratio(&light[&r.fg], &light[&r.bg], &light_page)) - 1e-4));
+    }
+
+    #[test]
+    fn test_synthesize_keeps_hue_and_alpha() {
+        let light = palette(&[
+            ("surface-bg", "#ffffff"), ("surface-fg", "#111111"),
+            ("state-error-bg", "#fee2e2"), ("state-error-fg", "#b91c1c"),
+            ("state-info-bg", "#e0f2fe"), ("state-info-fg", "hsl(200 98% 39% / 0.8)"),
+            ("action-primary-bg", "#3b82f6"),
+        ]);
+        let dark = synthesize(&light);
+        for key in ["state-error-bg", "state-error-fg", "state-info-bg", "state-info-fg", "action-primary-bg"] {
+            let ([_, c, before], [_, _, after]) = (light[key].to_oklch(), dark[key].to_oklch());
+            let drift = (after - before).rem_euclid(360.0);
+            assert!(c < 0.02 || drift.min(360.0 - drift) < 2.0, "{key}: hue {before} → {after}");
+            assert_eq!(light[key].alpha, dark[key].alpha, "{key}");
+        }
+        // neutros invertem: superfície escura, texto claro
+        assert!(dark["surface-bg"].l < 20.0 && dark["surface-fg"].l > 70.0);
+    }
+
+    #[test]
+    fn test_push_apart_measures_composited_fg() {
+        let bg = parse_color("#1e1e1e").unwrap();
+        // fg translúcido: cru passaria, composto sobre o bg não
+        let fg = parse_color("rgb(150 150 150 / 0.6)").unwrap();
+        assert!(contrast::wcag_ratio(&fg, &bg) >= AA);
+        assert!(contrast::wcag_ratio(&fg.over(&bg), &bg) < AA);
+        let pushed = push_apart(&fg, &bg, AA);
+        assert_eq!(pushed.alpha, fg.alpha);
+        assert!(contrast::wcag_ratio(&pushed.over(&bg), &bg) >= AA);
+    }
+}
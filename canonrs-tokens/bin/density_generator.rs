@@ -0,0 +1,60 @@
+// Density — `[data-density="<mode>"]` overrides (foundation/density.rs)
+// var() resolve onde a propriedade é declarada: trocar só `--density-*` numa subárvore não muda
+// `--button-height` herdado do <html>. Cada bloco redeclara também os tokens que dependem de density
+// (transitivamente), para que resolvam de novo no elemento com `data-density`
+
+use crate::output::Output;
+use crate::token_graph::var_refs;
+use crate::{COMPONENT_FAMILIES, FOUNDATION_LAYERS, SYSTEM_FAMILIES};
+use canonrs_tokens::design::tokens::foundation::DENSITY_MODES;
+use canonrs_tokens::design::tokens::semantics::{SEMANTICS_ACTIONS, SEMANTICS_STATES, SEMANTICS_SURFACE};
+use canonrs_tokens::design::tokens::FamilyToken;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+
+pub fn generate(out: &mut Output, output_dir: &Path) -> io::Result<()> {
+    let dependents = dependents();
+
+    let mut css = String::from("/* AUTO-GENERATED - DENSITY - [data-density] overrides, scoped to any subtree */\n");
+    for (mode, tokens) in DENSITY_MODES {
+        css.push_str(&format!("\n[data-density=\"{}\"] {{\n", mode));
+        for token in *tokens {
+            css.push_str(&format!("  --{}: {};\n", token.name, token.value));
+        }
+        css.push_str("  /* re-resolve */\n");
+        for token in &dependents {
+            css.push_str(&format!("  --{}: {};\n", token.name, token.value));
+        }
+        css.push_str("}\n");
+    }
+
+    let status = out.write(&output_dir.join("density.css"), css)?;
+    println!("  {} density.css ({} modes, {} dependent tokens)", status, DENSITY_MODES.len(), dependents.len());
+    Ok(())
+}
+
+/// Tokens fora de density cujo valor alcança `--density-*`, na ordem de emissão do engine
+pub fn dependents() -> Vec<&'static FamilyToken> {
+    let candidates: Vec<&'static FamilyToken> = FOUNDATION_LAYERS.iter().flat_map(|l| l.iter())
+        .chain(SEMANTICS_SURFACE.iter().chain(SEMANTICS_ACTIONS).chain(SEMANTICS_STATES))
+        .chain(COMPONENT_FAMILIES.iter().chain(SYSTEM_FAMILIES).flat_map(|(_, f)| f.iter()))
+        .filter(|t| !t.name.starts_with("density-"))
+        .collect();
+
+    let mut found: HashSet<&str> = HashSet::new();
+    loop {
+        let before = found.len();
+        for token in &candidates {
+            if !found.contains(token.name)
+                && var_refs(token.value).iter().any(|r| r.name.starts_with("density-") || found.contains(r.name.as_str()))
+            {
+                found.insert(token.name);
+            }
+        }
+        if found.len() == before {
+            break;
+        }
+    }
+    candidates.into_iter().filter(|t| found.contains(t.name)).collect()
+}
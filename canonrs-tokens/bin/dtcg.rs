@@ -0,0 +1,244 @@
+// DTCG — W3C Design Tokens (Figma / Tokens Studio) export + import
+// Formato multi-set do Tokens Studio: cada chave do topo é um token set, na ordem de $metadata.tokenSetOrder
+// Path do token = nome da CSS custom property → alias `var(--x)` vira `{x}` e continua referência
+// Grupos aninhados no import (`primitive.neutral.950`) são achatados com `-`
+
+use crate::color::{parse_color, HSLColor};
+use crate::theme_generator::{self, ThemeColors, ThemeOverride};
+use crate::{COMPONENT_FAMILIES, FOUNDATION_LAYERS, SYSTEM_FAMILIES};
+use canonrs_tokens::design::tokens::foundation::DENSITY_MODES;
+use canonrs_tokens::design::tokens::primitives::PRIMITIVE_VALUES;
+use canonrs_tokens::design::tokens::semantics::{SEMANTICS_ACTIONS, SEMANTICS_STATES, SEMANTICS_SURFACE};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Primitivas em canais HSL crus (`220 16% 11%`) — exportadas como `hsl(...)` com esta marca
+const HSL_CHANNELS: &str = "hsl-channels";
+
+#[derive(Debug, Default)]
+pub struct DtcgOverrides {
+    pub primitives: HashMap<String, String>,
+    pub themes: HashMap<String, ThemeOverride>,
+}
+
+impl DtcgOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.primitives.is_empty() && self.themes.is_empty()
+    }
+}
+
+// ============================================================
+// EXPORT
+// ============================================================
+
+pub fn export(path: &Path, themes: &[(String, ThemeColors)]) -> std::io::Result<()> {
+    let mut root = Map::new();
+    let mut order = vec![];
+    let mut push_set = |name: String, set: Map<String, Value>| {
+        order.push(Value::String(name.clone()));
+        root.insert(name, Value::Object(set));
+    };
+
+    push_set("primitive".into(), PRIMITIVE_VALUES.iter().map(|t| (t.name.to_string(), token(t.name, t.value))).collect());
+    push_set("foundation".into(), family_set(FOUNDATION_LAYERS.iter().flat_map(|l| l.iter())));
+    for (mode, tokens) in DENSITY_MODES {
+        push_set(format!("density/{}", mode), family_set(tokens.iter()));
+    }
+    push_set("system".into(), family_set(SYSTEM_FAMILIES.iter().flat_map(|(_, f)| f.iter())));
+
+    for (name, colors) in themes {
+        push_set(format!("theme/{}/light", name), theme_set(&colors.light));
+        if !colors.dark.is_empty() {
+            push_set(format!("theme/{}/dark", name), theme_set(&colors.dark));
+        }
+    }
+
+    push_set("semantic".into(), family_set(SEMANTICS_SURFACE.iter().chain(SEMANTICS_ACTIONS).chain(SEMANTICS_STATES)));
+    for (name, family) in COMPONENT_FAMILIES {
+        push_set(format!("component/{}", name.trim_start_matches("components-")), family_set(family.iter()));
+    }
+
+    let count = order.len();
+    root.insert("$metadata".into(), json!({ "tokenSetOrder": order }));
+
+    let json = serde_json::to_string_pretty(&Value::Object(root)).map_err(std::io::Error::other)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, json + "\n")?;
+    println!("  ✓ {} ({} token sets)", path.display(), count);
+    Ok(())
+}
+
+fn family_set<'a>(tokens: impl Iterator<Item = &'a canonrs_tokens::design::tokens::FamilyToken>) -> Map<String, Value> {
+    tokens.map(|t| (t.name.to_string(), token(t.name, t.value))).collect()
+}
+
+fn theme_set(colors: &HashMap<String, HSLColor>) -> Map<String, Value> {
+    theme_generator::normalized_colors(colors)
+        .into_iter()
+        .map(|(key, color)| (format!("theme-{}", key), json!({ "$type": "color", "$value": color.to_css() })))
+        .collect()
+}
+
+fn token(name: &str, value: &str) -> Value {
+    if let Some(target) = alias_target(value) {
+        return json!({ "$value": format!("{{{}}}", target) });
+    }
+    if is_hsl_channels(value) {
+        return json!({
+            "$type": "color",
+            "$value": format!("hsl({})", value),
+            "$extensions": { "canonrs": { "format": HSL_CHANNELS } },
+        });
+    }
+    match infer_type(name, value) {
+        Some(kind) => json!({ "$type": kind, "$value": value }),
+        None       => json!({ "$value": value }),
+    }
+}
+
+/// `var(--x)` puro → `x`; expressões (calc, fallback, hsl(var())) ficam como valor CSS
+fn alias_target(value: &str) -> Option<&str> {
+    let inner = value.trim().strip_prefix("var(--")?.strip_suffix(')')?;
+    inner.chars().all(|c| c.is_ascii_alphanumeric() || c == '-').then_some(inner)
+}
+
+fn is_hsl_channels(value: &str) -> bool {
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    parts.len() == 3
+        && parts[0].parse::<f32>().is_ok()
+        && parts[1..].iter().all(|p| p.strip_suffix('%').is_some_and(|n| n.parse::<f32>().is_ok()))
+}
+
+fn infer_type(name: &str, value: &str) -> Option<&'static str> {
+    let v = value.trim();
+    let number = |s: &str| s.parse::<f32>().is_ok();
+    if name.contains("font-sans") || name.contains("font-serif") || name.contains("font-mono") || name.contains("font-family") {
+        return Some("fontFamily");
+    }
+    if v.starts_with('#') || ["hsl(", "hsla(", "rgb(", "rgba(", "oklch("].iter().any(|p| v.starts_with(p)) {
+        return Some("color");
+    }
+    if ["px", "rem", "em"].iter().any(|u| v.strip_suffix(u).is_some_and(number)) {
+        return Some("dimension");
+    }
+    if v.strip_suffix("ms").is_some_and(number) || v.strip_suffix('s').is_some_and(number) {
+        return Some("duration");
+    }
+    if name.contains("font-weight") && number(v) {
+        return Some("fontWeight");
+    }
+    if number(v) {
+        return Some("number");
+    }
+    None
+}
+
+// ============================================================
+// IMPORT — só primitivas e temas; o resto do grafo é contrato
+// ============================================================
+
+pub fn import(path: &Path) -> Result<DtcgOverrides, String> {
+    let raw = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let root: Value = serde_json::from_str(&raw).map_err(|e| format!("{}: invalid JSON: {}", path.display(), e))?;
+    let Value::Object(sets) = root else {
+        return Err(format!("{}: top level must be an object of token sets", path.display()));
+    };
+
+    // Todos os tokens literais do arquivo — resolvem aliases entre sets
+    let mut flat = HashMap::new();
+    for (name, set) in &sets {
+        if !name.starts_with('$') {
+            flatten(set, "", &mut flat);
+        }
+    }
+
+    let mut overrides = DtcgOverrides::default();
+    let mut errors = vec![];
+
+    for (set_name, set) in &sets {
+        let mut tokens = HashMap::new();
+        flatten(set, "", &mut tokens);
+
+        if set_name == "primitive" {
+            for (name, value) in tokens {
+                let Some(original) = PRIMITIVE_VALUES.iter().find(|t| t.name == name) else {
+                    errors.push(format!("primitive: unknown token '{}'", name));
+                    continue;
+                };
+                match resolve(&value, &flat, 0) {
+                    Some(v) if is_hsl_channels(original.value) => match channels(&v) {
+                        Some(c) => { overrides.primitives.insert(name, c); }
+                        None    => errors.push(format!("primitive: '{}' expects an opaque color, got '{}'", name, v)),
+                    },
+                    Some(v) => { overrides.primitives.insert(name, v); }
+                    None    => errors.push(format!("primitive: '{}' has unresolved alias '{}'", name, value)),
+                }
+            }
+        } else if let Some(rest) = set_name.strip_prefix("theme/") {
+            let Some((theme, mode)) = rest.rsplit_once('/') else {
+                errors.push(format!("{}: expected theme/<name>/<light|dark>", set_name));
+                continue;
+            };
+            let entry = overrides.themes.entry(theme.to_string()).or_default();
+            let target = match mode {
+                "light" => &mut entry.light,
+                "dark"  => &mut entry.dark,
+                other   => { errors.push(format!("{}: unknown mode '{}'", set_name, other)); continue; }
+            };
+            for (name, value) in tokens {
+                let key = name.trim_start_matches("theme-").to_string();
+                match resolve(&value, &flat, 0) {
+                    Some(v) => match parse_color(&v) {
+                        Some(color) => { target.insert(key, color); }
+                        None        => errors.push(format!("{}: '{}' is not a color: '{}'", set_name, name, v)),
+                    },
+                    None => errors.push(format!("{}: '{}' has unresolved alias '{}'", set_name, name, value)),
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() { Ok(overrides) } else { Err(errors.join("\n")) }
+}
+
+/// Group → tokens achatados (`neutral` + `950` → `neutral-950`); `$value` marca um token
+fn flatten(node: &Value, prefix: &str, out: &mut HashMap<String, String>) {
+    let Value::Object(map) = node else { return };
+    if let Some(value) = map.get("$value") {
+        let value = match value {
+            Value::String(s) => s.clone(),
+            other            => other.to_string(),
+        };
+        out.insert(prefix.to_string(), value);
+        return;
+    }
+    for (key, child) in map {
+        if key.starts_with('$') { continue; }
+        let path = if prefix.is_empty() { key.clone() } else { format!("{}-{}", prefix, key) };
+        flatten(child, &path, out);
+    }
+}
+
+/// `{a.b}` → valor literal (aliases encadeados, limite contra ciclos); tokens da lib como fallback
+fn resolve(value: &str, flat: &HashMap<String, String>, depth: usize) -> Option<String> {
+    let Some(target) = value.trim().strip_prefix('{').and_then(|v| v.strip_suffix('}')) else {
+        return Some(value.to_string());
+    };
+    if depth > 16 { return None; }
+    let target = target.replace('.', "-");
+    match flat.get(&target) {
+        Some(next) => resolve(next, flat, depth + 1),
+        None => PRIMITIVE_VALUES.iter().find(|t| t.name == target).map(|t| t.value.to_string()),
+    }
+}
+
+/// Qualquer cor opaca → canais crus `220 16% 11%` (formato das primitivas)
+fn channels(value: &str) -> Option<String> {
+    parse_color(value)
+        .filter(|c| c.alpha >= 1.0)
+        .map(|c| format!("{} {}% {}%", c.h, c.s, c.l))
+}
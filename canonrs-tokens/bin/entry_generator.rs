@@ -1,7 +1,8 @@
+use crate::output::{with_path, Output};
 use std::fs;
 use std::path::Path;
 
-pub fn generate(_generated_dir: &Path, styles_dir: &Path) -> std::io::Result<()> {
+pub fn generate(out: &mut Output, _generated_dir: &Path, styles_dir: &Path) -> std::io::Result<()> {
     let output = styles_dir.join("canonrs.css");
 
     let mut content = String::from(
@@ -39,18 +40,25 @@ pub fn generate(_generated_dir: &Path, styles_dir: &Path) -> std::io::Result<()>
     }
 
     content.push_str("\n/* 6. ROOT */\n@import \"./.generated/root.css\";\n");
+    content.push_str("\n/* 6b. DENSITY (depois dos componentes: redeclara os dependentes) */\n@import \"./.generated/density.css\";\n");
 
     content.push_str("\n/* 7. VARIANTS */\n");
     let variants_dir = styles_dir.join("variants");
     if variants_dir.exists() {
-        for entry in fs::read_dir(variants_dir)? {
-            let entry = entry?;
+        // Ordenado — read_dir não garante ordem e o hash/--check precisa de saída estável
+        let mut variants = vec![];
+        for entry in fs::read_dir(&variants_dir).map_err(|e| with_path(&variants_dir, e))? {
+            let entry = entry.map_err(|e| with_path(&variants_dir, e))?;
             if let Some(name) = entry.file_name().to_str() {
                 if name.ends_with(".css") {
-                    content.push_str(&format!("@import \"./variants/{}\";\n", name));
+                    variants.push(name.to_string());
                 }
             }
         }
+        variants.sort();
+        for name in variants {
+            content.push_str(&format!("@import \"./variants/{}\";\n", name));
+        }
     }
 
     content.push_str("\n/* 8. UI COMPONENTS */\n@import \"./ui/ui.css\";\n");
@@ -58,8 +66,9 @@ pub fn generate(_generated_dir: &Path, styles_dir: &Path) -> std::io::Result<()>
     content.push_str("\n/* 9.1 LAYOUT PRIMITIVES CSS */\n@import \"./layout_primitives/layout_primitives.css\";\n");
     content.push_str("\n/* 10. LAYOUTS */\n@import \"./layouts/layouts.css\";\n");
     content.push_str("\n/* 11. GLOBALS (FINAL) */\n@import \"./tokens/base/globals.css\";\n");
+    content.push_str("\n/* 12. UTILITIES (StyleProps, base → breakpoints) */\n@import \"./.generated/utilities.css\";\n@import \"./.generated/utilities-responsive.css\";\n");
 
-    fs::write(&output, content)?;
-    println!("  ✓ canonrs.css (ordem canônica corrigida)");
+    let status = out.write(&output, content)?;
+    println!("  {} canonrs.css (ordem canônica corrigida)", status);
     Ok(())
 }
@@ -1,4 +1,6 @@
+use crate::output::{with_path, Output};
 use std::fs;
+use std::io;
 use std::path::Path;
 
 struct FontFace {
@@ -16,20 +18,12 @@ const FONTS: &[FontFace] = &[
     FontFace { family: "IBM Plex Sans",  weight: 700, style: "normal", subsets: &["latin", "latin-ext"], npm_package: "ibm-plex-sans" },
 ];
 
-// Fonte npm base path (relativo ao cwd do tokens-engine, que roda de canonrs-tokens/)
-const FONTS_NPM_BASE: &str = "fonts/node_modules/@fontsource";
-
-// Onde as fontes serao copiadas para ser servidas pelo canonrs-server
-const FONTS_OUT_DIR: &str = "../canonrs-server/styles/fonts";
+// Fonte npm base path (`--fonts-dir`) e destino (`<styles>/fonts`) vêm do tokens-engine
 
 // URL base que o browser vai usar para requisitar as fontes
 const FONTS_URL_BASE: &str = "/fonts";
 
-pub fn generate(output_dir: &Path) {
-    // Garante diretorio de saida das fontes no server
-    let fonts_out = Path::new(FONTS_OUT_DIR);
-    fs::create_dir_all(fonts_out).expect("Failed to create fonts output dir");
-
+pub fn generate(out: &mut Output, output_dir: &Path, fonts_src: &Path, fonts_out: &Path) -> io::Result<()> {
     let mut css = String::from("/* AUTO-GENERATED - FONTS - @font-face declarations */\n\n");
 
     for font in FONTS {
@@ -43,26 +37,22 @@ pub fn generate(output_dir: &Path) {
                 font.npm_package, subset, font.weight, font.style
             );
 
-            let src_woff2 = format!("{}/{}/files/{}", FONTS_NPM_BASE, font.npm_package, filename);
-            let src_woff  = format!("{}/{}/files/{}", FONTS_NPM_BASE, font.npm_package, filename_woff);
-
-            let src_path_woff2 = Path::new(&src_woff2);
-            let src_path_woff  = Path::new(&src_woff);
+            let files = fonts_src.join(font.npm_package).join("files");
+            let src_path_woff2 = files.join(&filename);
+            let src_path_woff  = files.join(&filename_woff);
 
             // Copia woff2
             if src_path_woff2.exists() {
-                fs::copy(src_path_woff2, fonts_out.join(&filename))
-                    .expect("Failed to copy woff2");
+                copy(out, &src_path_woff2, &fonts_out.join(&filename))?;
             } else {
-                println!("  ⚠ Missing: {}", src_woff2);
+                println!("  ⚠ Missing: {}", src_path_woff2.display());
                 continue;
             }
 
             // Copia woff (fallback)
             let has_woff = src_path_woff.exists();
             if has_woff {
-                fs::copy(src_path_woff, fonts_out.join(&filename_woff))
-                    .expect("Failed to copy woff");
+                copy(out, &src_path_woff, &fonts_out.join(&filename_woff))?;
             }
 
             // Gera @font-face
@@ -91,6 +81,14 @@ pub fn generate(output_dir: &Path) {
         }
     }
 
-    fs::write(output_dir.join("fonts.css"), &css).expect("Failed to write fonts.css");
-    println!("  ✓ fonts.css ({} @font-face declarations)", FONTS.len() * 2);
+    let status = out.write(&output_dir.join("fonts.css"), css)?;
+    println!("  {} fonts.css ({} @font-face declarations)", status, FONTS.len() * 2);
+    Ok(())
+}
+
+/// Cópia via Output — binário igual ao do disco não é regravado
+fn copy(out: &mut Output, src: &Path, dst: &Path) -> io::Result<()> {
+    let bytes = fs::read(src).map_err(|e| with_path(src, e))?;
+    out.write(dst, bytes)?;
+    Ok(())
 }
@@ -0,0 +1,152 @@
+// Output — toda escrita do engine passa por aqui
+// Write: grava só o que mudou (hash do conteúdo vs. disco) | Check: não grava nada, só acumula o drift
+// Conteúdo emitido fica em memória: bundler, token graph e utility check leem a versão desta execução
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Write,
+    Check,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Written,
+    Unchanged,
+    /// Check: conteúdo no disco difere (ou não existe)
+    Drift,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Written   => "✓",
+            Status::Unchanged => "=",
+            Status::Drift     => "✗",
+        })
+    }
+}
+
+pub struct Output {
+    mode: Mode,
+    emitted: BTreeMap<PathBuf, Vec<u8>>,
+    written: usize,
+    unchanged: usize,
+    drift: Vec<String>,
+}
+
+impl Output {
+    pub fn new(mode: Mode) -> Self {
+        Self { mode, emitted: BTreeMap::new(), written: 0, unchanged: 0, drift: vec![] }
+    }
+
+    pub fn write(&mut self, path: &Path, content: impl Into<Vec<u8>>) -> io::Result<Status> {
+        let content = content.into();
+        let on_disk = match fs::read(path) {
+            Ok(bytes) => Some(bytes),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(with_path(path, e)),
+        };
+        let new_hash = content_hash(&content);
+        let old_hash = on_disk.as_deref().map(content_hash);
+
+        let status = if old_hash == Some(new_hash) {
+            self.unchanged += 1;
+            Status::Unchanged
+        } else if self.mode == Mode::Check {
+            self.drift.push(match old_hash {
+                Some(old) => format!("{} differs (committed {:016x}, generated {:016x})", path.display(), old, new_hash),
+                None      => format!("{} is missing", path.display()),
+            });
+            Status::Drift
+        } else {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| with_path(parent, e))?;
+            }
+            fs::write(path, &content).map_err(|e| with_path(path, e))?;
+            self.written += 1;
+            Status::Written
+        };
+        self.emitted.insert(key(path), content);
+        Ok(status)
+    }
+
+    /// Versão emitida nesta execução, senão o disco
+    pub fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        match self.emitted.get(&key(path)) {
+            Some(bytes) => String::from_utf8(bytes.clone()).map_err(|e| with_path(path, io::Error::other(e))),
+            None        => fs::read_to_string(path).map_err(|e| with_path(path, e)),
+        }
+    }
+
+    pub fn exists(&self, path: &Path) -> bool {
+        self.emitted.contains_key(&key(path)) || path.exists()
+    }
+
+    /// Arquivos emitidos diretamente em `dir`
+    pub fn emitted_in<'a>(&'a self, dir: &Path) -> impl Iterator<Item = (&'a Path, &'a [u8])> {
+        let dir = dir.canonicalize().unwrap_or_else(|_| key(dir));
+        self.emitted.iter()
+            .filter(move |(path, _)| path.parent() == Some(dir.as_path()))
+            .map(|(path, bytes)| (path.as_path(), bytes.as_slice()))
+    }
+
+    /// `.css` em `dir` que o engine não gera mais — aviso, não drift (nada importa esses arquivos)
+    pub fn stale(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut stale = vec![];
+        if !dir.exists() {
+            return Ok(stale);
+        }
+        for entry in fs::read_dir(dir).map_err(|e| with_path(dir, e))? {
+            let path = entry.map_err(|e| with_path(dir, e))?.path();
+            if path.extension().and_then(|e| e.to_str()) == Some("css") && !self.emitted.contains_key(&key(&path)) {
+                stale.push(path);
+            }
+        }
+        stale.sort();
+        Ok(stale)
+    }
+
+    pub fn drift(&self) -> &[String] {
+        &self.drift
+    }
+
+    pub fn print_summary(&self) {
+        match self.mode {
+            Mode::Write => println!("  {} written, {} unchanged", self.written, self.unchanged),
+            Mode::Check => println!("  {} up to date, {} drifted", self.unchanged, self.drift.len()),
+        }
+    }
+}
+
+/// Erro de IO com o caminho — o `io::Error` puro não diz qual arquivo
+pub fn with_path(path: &Path, e: io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+}
+
+/// FNV-1a 64 — estável entre execuções e versões do compilador
+fn content_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| (hash ^ u64::from(*b)).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Chave normalizada: diretório canônico quando existe (bundler usa canonicalize), senão absoluto léxico
+fn key(path: &Path) -> PathBuf {
+    let absolute = std::env::current_dir().map(|cwd| cwd.join(path)).unwrap_or_else(|_| path.to_path_buf());
+    let mut lexical = PathBuf::new();
+    for part in absolute.components() {
+        match part {
+            Component::CurDir    => {}
+            Component::ParentDir => { lexical.pop(); }
+            other                => lexical.push(other),
+        }
+    }
+    match (lexical.parent().and_then(|p| p.canonicalize().ok()), lexical.file_name()) {
+        (Some(dir), Some(name)) => dir.join(name),
+        _ => lexical,
+    }
+}
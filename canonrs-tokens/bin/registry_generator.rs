@@ -0,0 +1,77 @@
+// Theme registry — canonrs-core/src/generated/theme_presets.rs a partir de themes/ingest
+// id = nome do arquivo (= `data-theme`), label/description/default do cabeçalho `/* @theme */`
+// Swatches das paletas finais (overrides e dark sintetizado incluídos) — os mesmos valores de themes.css
+
+use crate::color::HSLColor;
+use crate::output::Output;
+use crate::theme_generator::{self, ThemeColors, ThemeOverride};
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::Path;
+
+/// (campo de ThemeSwatches, chave normalizada da paleta)
+const SWATCHES: &[(&str, &str)] = &[
+    ("background", "surface-bg"),
+    ("foreground", "surface-fg"),
+    ("primary",    "action-primary-bg"),
+    ("secondary",  "action-secondary-bg"),
+    ("accent",     "action-accent-bg"),
+];
+
+pub fn generate(
+    out: &mut Output,
+    path: &Path,
+    themes: &[(String, ThemeColors)],
+    overrides: &HashMap<String, ThemeOverride>,
+) -> io::Result<()> {
+    let defaults: Vec<&str> = themes.iter().filter(|(_, c)| c.meta.default).map(|(id, _)| id.as_str()).collect();
+    if defaults.len() > 1 {
+        println!("  ⚠ More than one theme marked `default: true` ({}), using '{}'", defaults.join(", "), defaults[0]);
+    }
+    let default = defaults.first().copied()
+        .or_else(|| themes.first().map(|(id, _)| id.as_str()))
+        .unwrap_or_default();
+
+    let mut rs = String::from("// AUTO-GENERATED by tokens-engine from canonrs-tokens/themes/ingest — DO NOT EDIT\n");
+    rs.push_str("use crate::infra::theme::theme_registry::{ThemePreset, ThemeSwatches};\n\n");
+    rs.push_str(&format!("pub const DEFAULT_THEME_PRESET: &str = {:?};\n\n", default));
+    rs.push_str("pub static THEME_PRESETS: &[ThemePreset] = &[\n");
+    for (id, colors) in themes {
+        let palettes = theme_generator::theme_palettes(colors, overrides.get(id));
+        let label = colors.meta.label.clone().unwrap_or_else(|| title_case(id));
+        rs.push_str("    ThemePreset {\n");
+        rs.push_str(&format!("        id: {:?},\n", id));
+        rs.push_str(&format!("        label: {:?},\n", label));
+        rs.push_str(&format!("        description: {:?},\n", colors.meta.description.as_deref().unwrap_or_default()));
+        rs.push_str(&format!("        has_light: {},\n", !colors.light.is_empty()));
+        rs.push_str(&format!("        has_dark: {},\n", !colors.light.is_empty() || !colors.dark.is_empty()));
+        rs.push_str(&format!("        dark_synthesized: {},\n", palettes.dark_generated));
+        rs.push_str(&format!("        light: {},\n", swatches(&palettes.light)));
+        rs.push_str(&format!("        dark: {},\n", swatches(&palettes.dark)));
+        rs.push_str("    },\n");
+    }
+    rs.push_str("];\n");
+
+    let status = out.write(path, rs)?;
+    println!("  {} {} ({} presets, default '{}')", status, path.display(), themes.len(), default);
+    Ok(())
+}
+
+fn swatches(palette: &BTreeMap<String, HSLColor>) -> String {
+    let fields: Vec<String> = SWATCHES.iter()
+        .map(|(field, key)| format!("{}: {:?}", field, palette.get(*key).map(HSLColor::to_css).unwrap_or_default()))
+        .collect();
+    format!("ThemeSwatches {{ {} }}", fields.join(", "))
+}
+
+/// `clean-slate` → `Clean Slate` — quando o ingest não tem `label:`
+fn title_case(id: &str) -> String {
+    id.split(['-', '_'])
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
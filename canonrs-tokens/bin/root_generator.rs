@@ -1,8 +1,8 @@
-use std::fs;
+use crate::output::Output;
+use std::io;
 use std::path::Path;
 
-pub fn generate(output_dir: &Path) {
-    let css = r#"/* ======================================================================
+pub const ROOT_CSS: &str = r#"/* ======================================================================
    ROOT TOKENS — Final visual contract
    Consumes semantic layer, never theme directly
    ====================================================================== */
@@ -14,6 +14,8 @@ pub fn generate(output_dir: &Path) {
 }
 "#;
 
-    fs::write(output_dir.join("root.css"), css).ok();
-    println!("  ✓ root.css (via semantic)");
+pub fn generate(out: &mut Output, output_dir: &Path) -> io::Result<()> {
+    let status = out.write(&output_dir.join("root.css"), ROOT_CSS)?;
+    println!("  {} root.css (via semantic)", status);
+    Ok(())
 }
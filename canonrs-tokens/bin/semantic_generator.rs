@@ -1,8 +1,9 @@
-use std::fs;
+use crate::output::Output;
+use std::io;
 use std::path::Path;
 use canonrs_tokens::design::tokens::semantics::{SEMANTICS_SURFACE, SEMANTICS_ACTIONS, SEMANTICS_STATES};
 
-pub fn generate(output_dir: &Path) {
+pub fn generate(out: &mut Output, output_dir: &Path) -> io::Result<()> {
     let mut css = String::from("/* SEMANTIC LAYER — Bridge to Theme */\n[data-theme] {\n");
 
     for token in SEMANTICS_SURFACE {
@@ -17,6 +18,7 @@ pub fn generate(output_dir: &Path) {
 
     css.push_str("}\n");
 
-    fs::write(output_dir.join("semantic.css"), css).ok();
-    println!("  ✓ semantic.css (scoped to [data-theme])");
+    let status = out.write(&output_dir.join("semantic.css"), css)?;
+    println!("  {} semantic.css (scoped to [data-theme])", status);
+    Ok(())
 }
@@ -1,18 +1,40 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::io;
 use std::path::Path;
-
-#[derive(Debug, Clone)]
-pub struct HSLColor {
-    pub h: f32,
-    pub s: f32,
-    pub l: f32,
-}
+use crate::color::{parse_color, HSLColor};
+use crate::dark_generator;
+use crate::output::{with_path, Output};
 
 pub struct ThemeColors {
     pub light: HashMap<String, HSLColor>,
     pub dark: HashMap<String, HSLColor>,
     pub props: HashMap<String, String>,  // non-HSL properties (fonts, radius, etc)
+    /// Vars de cor que o parser não reconheceu — `(.dark) --key: value`, para o warning
+    pub unparsed: Vec<String>,
+    pub meta: ThemeMeta,
+}
+
+/// Cabeçalho `/* @theme ... */` do ingest — alimenta o ThemeRegistry do canonrs-core
+/// ```css
+/// /* @theme
+///    label: CanonRS
+///    description: Official CanonRS brand theme
+///    default: true
+/// */
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ThemeMeta {
+    pub label: Option<String>,
+    pub description: Option<String>,
+    pub default: bool,
+}
+
+/// Overrides vindos de fora (DTCG import) — chave normalizada, sem o prefixo `theme-`
+#[derive(Debug, Default)]
+pub struct ThemeOverride {
+    pub light: HashMap<String, HSLColor>,
+    pub dark: HashMap<String, HSLColor>,
 }
 
 fn normalize_theme_key(css_key: &str) -> String {
@@ -83,16 +105,11 @@ fn normalize_theme_key(css_key: &str) -> String {
         .unwrap_or_else(|| css_key.to_string())
 }
 
-pub fn parse_hsl(value: &str) -> Option<HSLColor> {
-    let value = value.trim().trim_start_matches("hsl(").trim_end_matches(')');
-    let parts: Vec<&str> = value.split_whitespace().collect();
-    if parts.len() != 3 { return None; }
-
-    let h = parts[0].parse::<f32>().ok()?;
-    let s = parts[1].trim_end_matches('%').parse::<f32>().ok()?;
-    let l = parts[2].trim_end_matches('%').parse::<f32>().ok()?;
+/// Vars do ingest que não são cor (fontes, raio, sombra composta, espaçamento)
+const NON_COLOR_PREFIXES: &[&str] = &["font-", "radius", "shadow", "spacing", "tracking-", "letter-spacing"];
 
-    Some(HSLColor { h, s, l })
+fn is_color_var(key: &str) -> bool {
+    key == "shadow-color" || !NON_COLOR_PREFIXES.iter().any(|p| key.starts_with(p))
 }
 
 // Font properties to bridge from theme to CanonRS tokens
@@ -107,6 +124,7 @@ pub fn parse_css_theme(content: &str) -> ThemeColors {
     let mut light = HashMap::new();
     let mut dark = HashMap::new();
     let mut props = HashMap::new();
+    let mut unparsed = vec![];
     let mut in_root = false;
     let mut in_dark = false;
 
@@ -119,10 +137,13 @@ pub fn parse_css_theme(content: &str) -> ThemeColors {
             if let Some((key, value)) = line.split_once(':') {
                 let key = key.trim().trim_start_matches("--").to_string();
                 let value = value.trim().trim_end_matches(';').trim().to_string();
-                if let Some(color) = parse_hsl(&value) {
+                if let Some(color) = parse_color(&value) {
                     if in_dark { dark.insert(key.clone(), color); }
                     else { light.insert(key.clone(), color); }
                 } else {
+                    if is_color_var(&key) {
+                        unparsed.push(format!("{}--{}: {}", if in_dark { ".dark " } else { "" }, key, value));
+                    }
                     // capture non-HSL props from :root and .dark
                     for (theme_key, _) in FONT_PROPS {
                         if key == *theme_key {
@@ -134,7 +155,25 @@ pub fn parse_css_theme(content: &str) -> ThemeColors {
         }
     }
 
-    ThemeColors { light, dark, props }
+    ThemeColors { light, dark, props, unparsed, meta: parse_theme_meta(content) }
+}
+
+fn parse_theme_meta(content: &str) -> ThemeMeta {
+    let mut meta = ThemeMeta::default();
+    let Some(start) = content.find("/* @theme") else { return meta };
+    let block = &content[start + "/* @theme".len()..];
+    let block = &block[..block.find("*/").unwrap_or(block.len())];
+    for line in block.lines() {
+        let Some((key, value)) = line.trim().trim_start_matches('*').split_once(':') else { continue };
+        let value = value.trim().to_string();
+        match key.trim() {
+            "label"       => meta.label = Some(value),
+            "description" => meta.description = Some(value),
+            "default"     => meta.default = value == "true",
+            _ => {}
+        }
+    }
+    meta
 }
 
 // ============================================================
@@ -145,39 +184,83 @@ pub fn parse_css_theme(content: &str) -> ThemeColors {
 fn ensure_required_states(colors: &mut HashMap<String, HSLColor>) {
     // Success: CanonRS standard green (enterprise contract)
     if !colors.contains_key("success") {
-        colors.insert("success".to_string(), HSLColor { h: 142.0, s: 76.0, l: 36.0 });
+        colors.insert("success".to_string(), HSLColor::new(142.0, 76.0, 36.0));
     }
     if !colors.contains_key("success-foreground") {
-        colors.insert("success-foreground".to_string(), HSLColor { h: 0.0, s: 0.0, l: 100.0 });
+        colors.insert("success-foreground".to_string(), HSLColor::new(0.0, 0.0, 100.0));
     }
 
     // Warning: CanonRS standard orange (enterprise contract)
     if !colors.contains_key("warning") {
-        colors.insert("warning".to_string(), HSLColor { h: 38.0, s: 92.0, l: 50.0 });
+        colors.insert("warning".to_string(), HSLColor::new(38.0, 92.0, 50.0));
     }
     if !colors.contains_key("warning-foreground") {
-        colors.insert("warning-foreground".to_string(), HSLColor { h: 0.0, s: 0.0, l: 0.0 });
+        colors.insert("warning-foreground".to_string(), HSLColor::new(0.0, 0.0, 0.0));
     }
 
     // Info: CanonRS standard blue (enterprise contract)
     if !colors.contains_key("info") {
-        colors.insert("info".to_string(), HSLColor { h: 221.0, s: 83.0, l: 53.0 });
+        colors.insert("info".to_string(), HSLColor::new(221.0, 83.0, 53.0));
     }
     if !colors.contains_key("info-foreground") {
-        colors.insert("info-foreground".to_string(), HSLColor { h: 0.0, s: 0.0, l: 100.0 });
+        colors.insert("info-foreground".to_string(), HSLColor::new(0.0, 0.0, 100.0));
     }
 }
 
-pub fn generate_css_theme(theme_name: &str, colors: &ThemeColors) -> String {
-    let mut css = format!("/* {} - Normalized vocabulary */\n[data-theme=\"{}\"] {{\n", theme_name, theme_name);
+/// Vocabulário normalizado (chave sem `theme-`), ordenado — required states incluídos
+/// Chaves ingest que normalizam para o mesmo nome (border/input) viram uma entrada
+pub fn normalized_colors(colors: &HashMap<String, HSLColor>) -> BTreeMap<String, HSLColor> {
+    let mut colors = colors.clone();
+    ensure_required_states(&mut colors);
+    colors.into_iter().map(|(key, color)| (normalize_theme_key(&key), color)).collect()
+}
+
+fn palette(colors: &HashMap<String, HSLColor>, overrides: Option<&HashMap<String, HSLColor>>) -> BTreeMap<String, HSLColor> {
+    let mut normalized = normalized_colors(colors);
+    if let Some(overrides) = overrides {
+        normalized.extend(overrides.iter().map(|(k, c)| (k.clone(), c.clone())));
+    }
+    normalized
+}
 
-    let mut light_colors = colors.light.clone();
-    ensure_required_states(&mut light_colors);
+/// Paletas finais do tema (o que vai para themes.css)
+pub struct ThemePalettes {
+    pub light: BTreeMap<String, HSLColor>,
+    pub dark: BTreeMap<String, HSLColor>,
+    /// Ingest sem `.dark` — dark sintetizado a partir do light (ver dark_generator)
+    pub dark_generated: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ThemeOptions {
+    /// Marca o dark sintetizado: comentário + `--theme-dark-generated: 1`
+    pub mark_generated_dark: bool,
+}
 
-    for (key, color) in &light_colors {
-        let normalized = normalize_theme_key(key);
-        css.push_str(&format!("  --theme-{}: hsl({} {}% {}%);\n", normalized, color.h, color.s, color.l));
+pub fn theme_palettes(colors: &ThemeColors, overrides: Option<&ThemeOverride>) -> ThemePalettes {
+    let light = palette(&colors.light, overrides.map(|o| &o.light));
+    let dark_overrides = overrides.map(|o| &o.dark);
+    if !colors.dark.is_empty() {
+        return ThemePalettes { dark: palette(&colors.dark, dark_overrides), light, dark_generated: false };
+    }
+    let mut dark = dark_generator::synthesize(&light);
+    if let Some(overrides) = dark_overrides {
+        dark.extend(overrides.iter().map(|(k, c)| (k.clone(), c.clone())));
     }
+    ThemePalettes { light, dark, dark_generated: true }
+}
+
+fn push_colors(css: &mut String, palette: &BTreeMap<String, HSLColor>) {
+    for (key, color) in palette {
+        css.push_str(&format!("  --theme-{}: {};\n", key, color.to_css()));
+    }
+}
+
+pub fn generate_css_theme(theme_name: &str, colors: &ThemeColors, overrides: Option<&ThemeOverride>, options: ThemeOptions) -> String {
+    let palettes = theme_palettes(colors, overrides);
+    let mut css = format!("/* {} - Normalized vocabulary */\n[data-theme=\"{}\"] {{\n", theme_name, theme_name);
+
+    push_colors(&mut css, &palettes.light);
 
     // Bridge font and other non-HSL tokens from theme to CanonRS primitives
     for (theme_key, primitive_key) in FONT_PROPS {
@@ -188,42 +271,98 @@ pub fn generate_css_theme(theme_name: &str, colors: &ThemeColors) -> String {
 
     css.push_str("}\n\n");
 
-    if !colors.dark.is_empty() {
-        css.push_str(&format!("[data-theme=\"{}\"].dark {{\n", theme_name));
-        
-        let mut dark_colors = colors.dark.clone();
-        ensure_required_states(&mut dark_colors);
-
-        for (key, color) in &dark_colors {
-            let normalized = normalize_theme_key(key);
-            css.push_str(&format!("  --theme-{}: hsl({} {}% {}%);\n", normalized, color.h, color.s, color.l));
-        }
-        css.push_str("}\n\n");
+    let marked = palettes.dark_generated && options.mark_generated_dark;
+    if marked {
+        css.push_str(&format!("/* {} - dark synthesized from light (no .dark in ingest) */\n", theme_name));
+    }
+    css.push_str(&format!("[data-theme=\"{}\"].dark {{\n", theme_name));
+    push_colors(&mut css, &palettes.dark);
+    if marked {
+        css.push_str("  --theme-dark-generated: 1;\n");
     }
+    css.push_str("}\n\n");
 
     css
 }
 
-pub fn generate_themes(output_dir: &Path) {
-    let themes_dir = Path::new("themes/ingest");
-    if !themes_dir.exists() {
-        println!("  ⚠ No themes directory found, skipping");
-        return;
+/// Temas de `themes_dir` (themes/ingest), ordenados por nome — (nome, cores parseadas)
+pub fn load_themes(themes_dir: &Path) -> io::Result<Vec<(String, ThemeColors)>> {
+    let mut themes = vec![];
+    for entry in fs::read_dir(themes_dir).map_err(|e| with_path(themes_dir, e))? {
+        let path = entry.map_err(|e| with_path(themes_dir, e))?.path();
+        if path.extension().and_then(|s| s.to_str()) != Some("css") { continue; }
+
+        let Some(theme_name) = path.file_stem().and_then(|s| s.to_str()).map(str::to_string) else {
+            return Err(with_path(&path, io::Error::new(io::ErrorKind::InvalidData, "theme file name is not UTF-8")));
+        };
+        let content = fs::read_to_string(&path).map_err(|e| with_path(&path, e))?;
+        themes.push((theme_name, parse_css_theme(&content)));
+    }
+    themes.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(themes)
+}
+
+pub fn generate_themes(
+    out: &mut Output,
+    output_dir: &Path,
+    themes: &[(String, ThemeColors)],
+    overrides: &HashMap<String, ThemeOverride>,
+    options: ThemeOptions,
+) -> io::Result<()> {
+    if themes.is_empty() {
+        println!("  ⚠ No themes found, skipping");
+        return Ok(());
     }
 
     let mut themes_css = String::from("/* AUTO-GENERATED - THEME LAYER (normalized vocabulary) */\n\n");
 
-    for entry in fs::read_dir(themes_dir).expect("Failed to read themes") {
-        let path = entry.expect("Entry error").path();
-        if path.extension().and_then(|s| s.to_str()) != Some("css") { continue; }
+    for (theme_name, colors) in themes {
+        if !colors.unparsed.is_empty() {
+            println!("  ⚠ {}: {} color var(s) not parsed, skipped:", theme_name, colors.unparsed.len());
+            for var in &colors.unparsed {
+                println!("      {}", var);
+            }
+        }
+        if colors.dark.is_empty() {
+            println!("  ⚠ {}: no .dark block, dark variant synthesized", theme_name);
+        }
+        themes_css.push_str(&generate_css_theme(theme_name, colors, overrides.get(theme_name), options));
+    }
+    for name in overrides.keys().filter(|name| !themes.iter().any(|(t, _)| t == *name)) {
+        println!("  ⚠ Override for unknown theme '{}' ignored", name);
+    }
 
-        let theme_name = path.file_stem().and_then(|s| s.to_str()).expect("Invalid name");
-        let content = fs::read_to_string(&path).expect("Read failed");
-        let colors = parse_css_theme(&content);
+    let status = out.write(&output_dir.join("themes.css"), themes_css)?;
+    println!("  {} themes.css (normalized)", status);
+    Ok(())
+}
 
-        themes_css.push_str(&generate_css_theme(theme_name, &colors));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_css_theme_lists_unparsed_color_vars() {
+        let theme = parse_css_theme(":root {
+  --background: #ffffff;
+  --primary: color-mix(in srgb, red 50%, blue);
+  --font-sans: Inter, sans-serif;
+  --radius: 0.5rem;
+  --shadow-2xl: 0 1px 2px black;
+  --shadow-color: hsl(0 0% 0%);
+}
+.dark {
+  --background: oklch(0.145 0 0);
+  --accent: var(--muted);
+  --spacing: 0.25rem;
+}");
+        assert_eq!(theme.unparsed, vec![
+            "--primary: color-mix(in srgb, red 50%, blue)".to_string(),
+            ".dark --accent: var(--muted)".to_string(),
+        ]);
+        assert!(theme.light.contains_key("background") && theme.light.contains_key("shadow-color"));
+        assert!(theme.dark.contains_key("background"));
+        assert_eq!(theme.props.get("font-sans").map(String::as_str), Some("Inter, sans-serif"));
+        assert_eq!(theme.props.get("radius").map(String::as_str), Some("0.5rem"));
     }
-
-    fs::write(output_dir.join("themes.css"), themes_css).expect("Write failed");
-    println!("  ✓ themes.css (normalized)");
 }
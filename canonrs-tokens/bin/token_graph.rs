@@ -0,0 +1,443 @@
+// Token graph — toda definição `--name` e toda referência `var(--...)`
+// Fontes: primitives, foundation, themes (paletas geradas), semantic, system, families, root, utilities
+// e as folhas de estilo alcançáveis a partir de canonrs.css (fora de .generated, que vem do Rust)
+// Canon rule 171 (phantom variables) + 181 (cascata): undefined, unused, ciclos e referência para camada acima
+
+use crate::output::Output;
+use crate::theme_generator::{self, ThemeColors, ThemeOverride};
+use crate::{COMPONENT_FAMILIES, FOUNDATION_LAYERS, SYSTEM_FAMILIES};
+use canonrs_tokens::design::tokens::foundation::DENSITY_MODES;
+use canonrs_tokens::design::tokens::primitives::PRIMITIVE_VALUES;
+use canonrs_tokens::design::tokens::semantics::{SEMANTICS_ACTIONS, SEMANTICS_STATES, SEMANTICS_SURFACE};
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Ordem da cascata — uma camada só referencia a própria ou as de baixo
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum Layer {
+    Primitive,
+    Foundation,
+    Theme,
+    Semantic,
+    System,
+    Component,
+    Root,
+    /// CSS escrito à mão (ui, blocks, layouts...) e utilities — só consome
+    Stylesheet,
+}
+
+impl Layer {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Primitive  => "primitive",
+            Self::Foundation => "foundation",
+            Self::Theme      => "theme",
+            Self::Semantic   => "semantic",
+            Self::System     => "system",
+            Self::Component  => "component",
+            Self::Root       => "root",
+            Self::Stylesheet => "stylesheet",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub name: String,
+    /// `var(--x, fallback)`
+    pub fallback: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Definition {
+    pub name: String,
+    pub layer: Layer,
+    pub source: String,
+    pub refs: Vec<Reference>,
+}
+
+#[derive(Default)]
+pub struct TokenGraph {
+    defs: Vec<Definition>,
+    /// Referências fora de definições (propriedades CSS comuns) — (source, ref)
+    uses: Vec<(String, Reference)>,
+}
+
+#[derive(Debug)]
+pub struct Undefined {
+    pub source: String,
+    /// Token cuja definição referencia — vazio para uso direto em propriedade
+    pub from: String,
+    pub name: String,
+    pub fallback: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct GraphReport {
+    pub undefined: Vec<Undefined>,
+    pub unused: Vec<(Layer, String)>,
+    pub cycles: Vec<Vec<String>>,
+    /// (token, camada, referência, camada da referência)
+    pub layer_violations: Vec<(String, Layer, String, Layer)>,
+}
+
+impl TokenGraph {
+    pub fn build(out: &Output, styles_dir: &Path, themes: &[(String, ThemeColors)], overrides: &HashMap<String, ThemeOverride>) -> Self {
+        let mut graph = Self::default();
+
+        for t in PRIMITIVE_VALUES {
+            graph.define(Layer::Primitive, "primitives", t.name, t.value);
+        }
+        for t in FOUNDATION_LAYERS.iter().flat_map(|l| l.iter()) {
+            graph.define(Layer::Foundation, "core", t.name, t.value);
+        }
+        for (mode, tokens) in DENSITY_MODES {
+            for t in *tokens {
+                graph.define(Layer::Foundation, &format!("density/{}", mode), t.name, t.value);
+            }
+        }
+        for (name, colors) in themes {
+            let palettes = theme_generator::theme_palettes(colors, overrides.get(name));
+            for (key, color) in palettes.light.iter().chain(&palettes.dark) {
+                graph.define(Layer::Theme, name, &format!("theme-{}", key), &color.to_css());
+            }
+        }
+        for t in SEMANTICS_SURFACE.iter().chain(SEMANTICS_ACTIONS).chain(SEMANTICS_STATES) {
+            graph.define(Layer::Semantic, "semantic", t.name, t.value);
+        }
+        for (family, tokens) in SYSTEM_FAMILIES {
+            for t in *tokens {
+                graph.define(Layer::System, family, t.name, t.value);
+            }
+        }
+        for (family, tokens) in COMPONENT_FAMILIES {
+            for t in *tokens {
+                graph.define(Layer::Component, family, t.name, t.value);
+            }
+        }
+        for (name, value) in declarations(crate::root_generator::ROOT_CSS) {
+            graph.define(Layer::Root, "root", &name, &value);
+        }
+        for rule in canonrs_style::utility_rules() {
+            for r in var_refs(&rule.declarations) {
+                graph.uses.push(("utilities".into(), r));
+            }
+        }
+        for path in stylesheets(out, styles_dir) {
+            let Ok(css) = out.read_to_string(&path) else { continue };
+            let css = strip_comments(&css);
+            let source = path.strip_prefix(styles_dir).unwrap_or(&path).display().to_string();
+            let decls = declarations(&css);
+            for (name, value) in &decls {
+                graph.define(Layer::Stylesheet, &source, name, value);
+            }
+            // Referências fora de definições `--x:` são uso direto (multiset: total − as das definições)
+            let mut in_defs: HashMap<String, usize> = HashMap::new();
+            for r in decls.iter().flat_map(|(_, v)| var_refs(v)) {
+                *in_defs.entry(r.name).or_default() += 1;
+            }
+            for r in var_refs(&css) {
+                match in_defs.get_mut(&r.name) {
+                    Some(n) if *n > 0 => *n -= 1,
+                    _ => graph.uses.push((source.clone(), r)),
+                }
+            }
+        }
+        graph
+    }
+
+    fn define(&mut self, layer: Layer, source: &str, name: &str, value: &str) {
+        self.defs.push(Definition { name: name.to_string(), layer, source: source.to_string(), refs: var_refs(value) });
+    }
+
+    pub fn analyze(&self) -> GraphReport {
+        let mut report = GraphReport::default();
+
+        // Camada do token = a mais baixa que o define (tema redefinindo primitiva continua primitiva)
+        let mut layer_of: HashMap<&str, Layer> = HashMap::new();
+        let mut edges: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for d in &self.defs {
+            let layer = layer_of.entry(&d.name).or_insert(d.layer);
+            *layer = (*layer).min(d.layer);
+            edges.entry(&d.name).or_default().extend(d.refs.iter().map(|r| r.name.as_str()));
+        }
+
+        let mut referenced: HashSet<&str> = HashSet::new();
+        let mut seen_undefined = HashSet::new();
+        let refs = self.defs.iter()
+            .flat_map(|d| d.refs.iter().map(move |r| (d.source.as_str(), d.name.as_str(), Some(d.layer), r)))
+            .chain(self.uses.iter().map(|(s, r)| (s.as_str(), "", None, r)));
+        for (source, from, from_layer, r) in refs {
+            referenced.insert(&r.name);
+            match layer_of.get(r.name.as_str()) {
+                None => {
+                    if seen_undefined.insert((source, r.name.as_str())) {
+                        report.undefined.push(Undefined {
+                            source: source.to_string(),
+                            from: from.to_string(),
+                            name: r.name.clone(),
+                            fallback: r.fallback,
+                        });
+                    }
+                }
+                Some(&to_layer) => {
+                    if let Some(from_layer) = from_layer {
+                        if to_layer > from_layer && from_layer != Layer::Stylesheet {
+                            report.layer_violations.push((from.to_string(), from_layer, r.name.clone(), to_layer));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut unused: BTreeSet<(Layer, &str)> = BTreeSet::new();
+        for d in &self.defs {
+            if d.layer < Layer::Stylesheet && !referenced.contains(d.name.as_str()) {
+                unused.insert((layer_of[d.name.as_str()], &d.name));
+            }
+        }
+        report.unused = unused.into_iter().map(|(l, n)| (l, n.to_string())).collect();
+        report.layer_violations.sort();
+        report.layer_violations.dedup();
+        report.cycles = cycles(&edges);
+        report
+    }
+}
+
+impl GraphReport {
+    /// Unused é só aviso — o resto quebra a cascata
+    pub fn has_errors(&self) -> bool {
+        !self.undefined.is_empty() || !self.cycles.is_empty() || !self.layer_violations.is_empty()
+    }
+
+    pub fn print_summary(&self) {
+        const SHOW: usize = 10;
+        let mark = |n: usize| if n == 0 { "✓" } else { "⚠" };
+        println!("  {} {} undefined reference(s)", mark(self.undefined.len()), self.undefined.len());
+        for u in self.undefined.iter().take(SHOW) {
+            let from = if u.from.is_empty() { String::new() } else { format!(" in --{}", u.from) };
+            let fallback = if u.fallback { " [has fallback]" } else { "" };
+            println!("      {}: var(--{}){}{}", u.source, u.name, from, fallback);
+        }
+        println!("  {} {} reference cycle(s)", mark(self.cycles.len()), self.cycles.len());
+        for cycle in self.cycles.iter().take(SHOW) {
+            println!("      --{}", cycle.join(" → --"));
+        }
+        println!("  {} {} layer-order violation(s)", mark(self.layer_violations.len()), self.layer_violations.len());
+        for (from, from_layer, to, to_layer) in self.layer_violations.iter().take(SHOW) {
+            println!("      --{} ({}) → --{} ({})", from, from_layer.as_str(), to, to_layer.as_str());
+        }
+        println!("  {} {} unused token(s)", mark(self.unused.len()), self.unused.len());
+        for (layer, name) in self.unused.iter().take(SHOW) {
+            println!("      --{} ({})", name, layer.as_str());
+        }
+    }
+
+    pub fn write(&self, out: &mut Output, path: &Path) -> std::io::Result<()> {
+        let report = json!({
+            "undefined": self.undefined.iter().map(|u| json!({
+                "source": u.source, "from": u.from, "name": u.name, "fallback": u.fallback,
+            })).collect::<Vec<_>>(),
+            "cycles": self.cycles,
+            "layer_violations": self.layer_violations.iter().map(|(from, fl, to, tl)| json!({
+                "from": from, "from_layer": fl.as_str(), "to": to, "to_layer": tl.as_str(),
+            })).collect::<Vec<_>>(),
+            "unused": self.unused.iter().map(|(layer, name)| json!({ "name": name, "layer": layer.as_str() })).collect::<Vec<_>>(),
+        });
+        let status = out.write(path, serde_json::to_string_pretty(&report).map_err(std::io::Error::other)? + "\n")?;
+        println!("  {} {}", status, path.display());
+        Ok(())
+    }
+}
+
+/// Ciclos via DFS (branco/cinza/preto) — cada ciclo reportado uma vez, a partir do menor nome
+fn cycles<'a>(edges: &BTreeMap<&'a str, BTreeSet<&'a str>>) -> Vec<Vec<String>> {
+    fn visit<'a>(
+        node: &'a str,
+        edges: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+        state: &mut HashMap<&'a str, u8>,
+        stack: &mut Vec<&'a str>,
+        found: &mut BTreeSet<Vec<String>>,
+    ) {
+        state.insert(node, 1);
+        stack.push(node);
+        for &next in edges.get(node).into_iter().flatten() {
+            match state.get(next).copied().unwrap_or(0) {
+                0 if edges.contains_key(next) => visit(next, edges, state, stack, found),
+                1 => {
+                    let start = stack.iter().position(|n| *n == next).unwrap_or(0);
+                    let mut cycle: Vec<String> = stack[start..].iter().map(|s| s.to_string()).collect();
+                    let min = cycle.iter().enumerate().min_by(|a, b| a.1.cmp(b.1)).map(|(i, _)| i).unwrap_or(0);
+                    cycle.rotate_left(min);
+                    cycle.push(cycle[0].clone());
+                    found.insert(cycle);
+                }
+                _ => {}
+            }
+        }
+        stack.pop();
+        state.insert(node, 2);
+    }
+
+    let mut state = HashMap::new();
+    let mut found = BTreeSet::new();
+    for &node in edges.keys() {
+        if !state.contains_key(node) {
+            visit(node, edges, &mut state, &mut vec![], &mut found);
+        }
+    }
+    found.into_iter().collect()
+}
+
+/// Folhas alcançáveis por @import a partir de canonrs.css — .generated fica de fora (modelado do Rust)
+fn stylesheets(output: &Output, styles_dir: &Path) -> Vec<PathBuf> {
+    fn walk(output: &Output, file: &Path, out: &mut Vec<PathBuf>) {
+        if out.iter().any(|p| p == file) {
+            return;
+        }
+        let Ok(css) = output.read_to_string(file) else { return };
+        out.push(file.to_path_buf());
+        let dir = file.parent().unwrap_or(Path::new("."));
+        for line in strip_comments(&css).lines() {
+            let Some(rest) = line.trim().strip_prefix("@import") else { continue };
+            let Some(import) = rest.trim().trim_start_matches(['"', '\'']).split(['"', '\'']).next() else { continue };
+            if import.contains(".generated/") {
+                continue;
+            }
+            walk(output, &dir.join(import.trim_start_matches("./")), out);
+        }
+    }
+    let mut files = vec![];
+    walk(output, &styles_dir.join("canonrs.css"), &mut files);
+    files.retain(|f| f.file_name().and_then(|n| n.to_str()) != Some("canonrs.css") || f.parent() != Some(styles_dir));
+    files
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None      => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+/// `--name: value;` — definições de custom property
+pub fn declarations(css: &str) -> Vec<(String, String)> {
+    let mut out = vec![];
+    for (i, _) in css.match_indices("--") {
+        // `--` no meio de um nome (`a--b`) ou dentro de var( não é definição
+        if css[..i].chars().next_back().is_some_and(|c| is_name_char(c) || c == '(') {
+            continue;
+        }
+        let name: String = css[i + 2..].chars().take_while(|c| is_name_char(*c)).collect();
+        let after = css[i + 2 + name.len()..].trim_start();
+        let (false, Some(value)) = (name.is_empty(), after.strip_prefix(':')) else { continue };
+        let end = value.find([';', '}']).unwrap_or(value.len());
+        out.push((name, value[..end].trim().to_string()));
+    }
+    out
+}
+
+pub fn var_refs(text: &str) -> Vec<Reference> {
+    text.match_indices("var(--").map(|(i, _)| {
+        let rest = &text[i + 6..];
+        let name: String = rest.chars().take_while(|c| is_name_char(*c)).collect();
+        let fallback = rest[name.len()..].trim_start().starts_with(',');
+        Reference { name, fallback }
+    }).filter(|r| !r.name.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(defs: &[(Layer, &str, &str)]) -> TokenGraph {
+        let mut graph = TokenGraph::default();
+        for (layer, name, value) in defs {
+            graph.define(*layer, layer.as_str(), name, value);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_var_refs_detect_fallback() {
+        let refs: Vec<(String, bool)> = var_refs("calc(var(--a) * 2) var(--b, 1px) var(--c , var(--d)) var(--)")
+            .into_iter()
+            .map(|r| (r.name, r.fallback))
+            .collect();
+        assert_eq!(refs, vec![
+            ("a".to_string(), false),
+            ("b".to_string(), true),
+            ("c".to_string(), true),
+            ("d".to_string(), false),
+        ]);
+    }
+
+    #[test]
+    fn test_declarations_skip_refs_and_inner_dashes() {
+        let decls = declarations(":root { --a: 1px; --b:var(--a) } .x { color: var(--c); } .a--b { --c_2: red }");
+        assert_eq!(decls, vec![
+            ("a".to_string(), "1px".to_string()),
+            ("b".to_string(), "var(--a)".to_string()),
+            ("c_2".to_string(), "red".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_cycles_reported_once_from_smallest_name() {
+        let report = graph(&[
+            (Layer::Semantic, "c", "var(--a)"),
+            (Layer::Semantic, "a", "var(--b)"),
+            (Layer::Semantic, "b", "var(--c)"),
+            (Layer::Semantic, "self", "var(--self)"),
+            (Layer::Semantic, "tail", "var(--a)"),
+        ]).analyze();
+        assert_eq!(report.cycles, vec![
+            vec!["a".to_string(), "b".into(), "c".into(), "a".into()],
+            vec!["self".to_string(), "self".into()],
+        ]);
+        assert!(report.has_errors());
+        assert!(!graph(&[(Layer::Semantic, "a", "var(--b)"), (Layer::Semantic, "b", "1px")]).analyze().has_errors());
+    }
+
+    #[test]
+    fn test_layer_order_violations() {
+        let report = graph(&[
+            (Layer::Primitive, "space", "4px"),
+            (Layer::Semantic, "gap", "var(--space)"),
+            // primitiva apontando para cima na cascata
+            (Layer::Primitive, "radius", "var(--gap)"),
+            // redefinida no tema continua primitiva — tema → primitiva é permitido
+            (Layer::Theme, "space", "var(--radius)"),
+            // folha de estilo consome qualquer camada
+            (Layer::Stylesheet, "local", "var(--gap)"),
+        ]).analyze();
+        assert_eq!(report.layer_violations, vec![("radius".to_string(), Layer::Primitive, "gap".to_string(), Layer::Semantic)]);
+        assert!(report.undefined.is_empty());
+    }
+
+    #[test]
+    fn test_undefined_and_unused() {
+        let mut g = graph(&[
+            (Layer::Primitive, "used", "1px"),
+            (Layer::Primitive, "orphan", "2px"),
+            (Layer::Semantic, "alias", "var(--missing, var(--used))"),
+        ]);
+        g.uses.push(("ui/button.css".into(), Reference { name: "ghost".into(), fallback: false }));
+        let report = g.analyze();
+        let undefined: Vec<_> = report.undefined.iter().map(|u| (u.source.as_str(), u.from.as_str(), u.name.as_str(), u.fallback)).collect();
+        assert_eq!(undefined, vec![("semantic", "alias", "missing", true), ("ui/button.css", "", "ghost", false)]);
+        assert_eq!(report.unused, vec![(Layer::Primitive, "orphan".to_string()), (Layer::Semantic, "alias".to_string())]);
+    }
+}
@@ -1,106 +1,237 @@
+mod color;
+mod contrast;
+mod dark_generator;
+mod token_graph;
 mod theme_generator;
 mod entry_generator;
 mod bundler;
 mod semantic_generator;
 mod root_generator;
 mod font_generator;
+mod utility_generator;
+mod dtcg;
+mod density_generator;
+mod registry_generator;
+mod output;
 
 use canonrs_tokens::design::tokens::components::*;
 use canonrs_tokens::design::tokens::system::{LAYERS_TOKENS, SYSTEM_OPACITY, SYSTEM_FOCUS, SYSTEM_TRANSFORM, SYSTEM_BLUR};
 use canonrs_tokens::design::tokens::foundation::*;
 use canonrs_tokens::design::tokens::foundation::breakpoints::FOUNDATION_BREAKPOINTS;
 use canonrs_tokens::design::tokens::primitives::PRIMITIVE_VALUES;
-use std::fs;
-use std::path::Path;
+use canonrs_tokens::design::tokens::FamilyToken;
+use output::{Mode, Output};
+use std::collections::HashMap;
+use std::error::Error;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// core.css — ordem de emissão
+pub const FOUNDATION_LAYERS: &[&[FamilyToken]] = &[
+    FOUNDATION_SPACING, FOUNDATION_RADIUS, FOUNDATION_MOTION, FOUNDATION_TYPOGRAPHY,
+    FOUNDATION_COLOR, FOUNDATION_SHADOW, FOUNDATION_BORDER, FOUNDATION_BREAKPOINTS,
+    FOUNDATION_DENSITY,
+];
+
+/// Um arquivo por família — `<name>.css`
+pub const COMPONENT_FAMILIES: &[(&str, &[FamilyToken])] = &[
+    ("components-overlay",    OVERLAY_TOKENS),
+    ("components-selection",  SELECTION_TOKENS),
+    ("components-forms",      FORMS_TOKENS),
+    ("components-navigation", NAVIGATION_TOKENS),
+    ("components-feedback",   FEEDBACK_TOKENS),
+    ("components-data",       DATA_TOKENS),
+    ("components-composite",  COMPOSITE_TOKENS),
+    ("components-layout",     LAYOUT_TOKENS),
+    ("components-animation",  ANIMATION_TOKENS),
+    ("components-blocks",     BLOCKS_TOKENS),
+];
+
+pub const SYSTEM_FAMILIES: &[(&str, &[FamilyToken])] = &[
+    ("system-opacity",    SYSTEM_OPACITY),
+    ("system-focus",      SYSTEM_FOCUS),
+    ("system-transform",  SYSTEM_TRANSFORM),
+    ("system-blur",       SYSTEM_BLUR),
+    ("foundation-layers", LAYERS_TOKENS),
+];
+
+/// `--styles-dir <dir>`: saída (canonrs.css, bundle, .generated/, fonts/) — padrão ../canonrs-server/styles
+/// `--themes-dir <dir>`: temas ingeridos — padrão themes/ingest
+/// `--fonts-dir <dir>`: pacotes @fontsource — padrão fonts/node_modules/@fontsource
+/// `--registry-out <file>`: ThemeRegistry gerado — padrão ../canonrs-core/src/generated/theme_presets.rs
+/// `--check`: não grava nada (nem relatórios); falha se algum arquivo gerado difere do commitado
+/// `--export-dtcg <file>`: grava o grafo em DTCG JSON e sai
+/// `--import-dtcg <file>`: aplica overrides de primitivas e temas na geração
+/// `--contrast-report <file>`: relatório JSON de contraste | `--apca`: inclui APCA Lc
+/// `--strict-contrast`: falha quando algum par bg/fg fica abaixo de WCAG AA
+/// `--mark-generated-dark`: marca os dark sintetizados (temas sem `.dark`) em themes.css
+/// `--graph-report <file>`: grafo de variáveis em JSON | `--strict-graph`: falha em undefined, ciclo ou cascata violada
+fn arg_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1)).cloned()
+}
 
 fn main() {
-    let generated_path = Path::new("../canonrs-server/styles/.generated");
-    let styles_path = Path::new("../canonrs-server/styles");
-    let bundle_output_path = Path::new("../canonrs-server/styles");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Err(e) = run(&args) {
+        eprintln!("\n❌ {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let styles_path = PathBuf::from(arg_value(args, "--styles-dir").unwrap_or_else(|| "../canonrs-server/styles".into()));
+    let themes_path = PathBuf::from(arg_value(args, "--themes-dir").unwrap_or_else(|| "themes/ingest".into()));
+    let fonts_path = PathBuf::from(arg_value(args, "--fonts-dir").unwrap_or_else(|| "fonts/node_modules/@fontsource".into()));
+    let registry_path = PathBuf::from(arg_value(args, "--registry-out").unwrap_or_else(|| "../canonrs-core/src/generated/theme_presets.rs".into()));
+    let generated_path = styles_path.join(".generated");
+    let bundle_output_path = styles_path.as_path();
+
+    let themes = theme_generator::load_themes(&themes_path)?;
+
+    if let Some(path) = arg_value(args, "--export-dtcg") {
+        println!("🔧 Exporting DTCG token graph...");
+        dtcg::export(Path::new(&path), &themes)?;
+        return Ok(());
+    }
+
+    let overrides = match arg_value(args, "--import-dtcg") {
+        Some(path) => dtcg::import(Path::new(&path)).map_err(|errors| format!("DTCG import failed:\n{}", errors))?,
+        None => dtcg::DtcgOverrides::default(),
+    };
+    if !overrides.is_empty() {
+        println!("🔧 DTCG overrides: {} primitive(s), {} theme(s)", overrides.primitives.len(), overrides.themes.len());
+    }
 
-    fs::create_dir_all(generated_path).expect("Failed to create dir");
+    let mode = if args.iter().any(|a| a == "--check") { Mode::Check } else { Mode::Write };
+    let mut out = Output::new(mode);
+    if mode == Mode::Check {
+        println!("🔧 Check mode: nothing is written, drift fails the run\n");
+    }
 
     println!("🔧 Step 1: Generating primitives...");
-    generate_primitives(generated_path);
+    generate_primitives(&mut out, &generated_path, &overrides.primitives)?;
 
     println!("\n🔧 Step 1b: Generating fonts (@font-face)...");
-    font_generator::generate(generated_path);
+    font_generator::generate(&mut out, &generated_path, &fonts_path, &styles_path.join("fonts"))?;
 
     println!("\n🔧 Step 2: Generating foundation...");
-    generate_core(generated_path);
+    generate_core(&mut out, &generated_path)?;
 
     println!("\n🔧 Step 3: Generating components...");
-    generate_family("components-overlay",   OVERLAY_TOKENS, generated_path);
-    generate_family("components-selection", SELECTION_TOKENS, generated_path);
-    generate_family("components-forms", FORMS_TOKENS, generated_path);
-    generate_family("components-navigation", NAVIGATION_TOKENS, generated_path);
-    generate_family("components-feedback", FEEDBACK_TOKENS, generated_path);
-    generate_family("components-data", DATA_TOKENS, generated_path);
-    generate_family("components-composite", COMPOSITE_TOKENS, generated_path);
-    generate_family("components-layout", LAYOUT_TOKENS, generated_path);
-    generate_family("components-animation", ANIMATION_TOKENS, generated_path);
-    generate_family("components-blocks", BLOCKS_TOKENS, generated_path);
-    generate_family("system-opacity",    SYSTEM_OPACITY,    generated_path);
-    generate_family("system-focus",      SYSTEM_FOCUS,      generated_path);
-    generate_family("system-transform",  SYSTEM_TRANSFORM,  generated_path);
-    generate_family("system-blur",       SYSTEM_BLUR,       generated_path);
-    generate_family("foundation-layers", LAYERS_TOKENS, generated_path);
+    for (name, tokens) in COMPONENT_FAMILIES.iter().chain(SYSTEM_FAMILIES) {
+        generate_family(&mut out, name, tokens, &generated_path)?;
+    }
 
     println!("\n🔧 Step 4: Generating semantic...");
-    semantic_generator::generate(generated_path);
+    semantic_generator::generate(&mut out, &generated_path)?;
 
     println!("\n🔧 Step 5: Generating themes...");
-    theme_generator::generate_themes(generated_path);
+    let theme_options = theme_generator::ThemeOptions {
+        mark_generated_dark: args.iter().any(|a| a == "--mark-generated-dark"),
+    };
+    theme_generator::generate_themes(&mut out, &generated_path, &themes, &overrides.themes, theme_options)?;
+
+    println!("\n🔧 Step 5a: Generating theme registry (canonrs-core)...");
+    registry_generator::generate(&mut out, &registry_path, &themes, &overrides.themes)?;
+
+    println!("\n🔧 Step 5b: Checking theme contrast (WCAG 2.x)...");
+    let contrast = contrast::check_themes(&themes, &overrides.themes, args.iter().any(|a| a == "--apca"));
+    contrast::print_summary(&contrast);
+    if let Some(path) = arg_value(args, "--contrast-report") {
+        contrast::write_report(&mut out, Path::new(&path), &contrast)?;
+    }
+    let below_aa = contrast.iter().filter(|r| !r.passes_aa()).count();
+    if below_aa > 0 && args.iter().any(|a| a == "--strict-contrast") {
+        return Err(format!("Contrast check failed: {} pair(s) below WCAG AA ({}:1)", below_aa, contrast::AA).into());
+    }
 
     println!("\n🔧 Step 6: Generating root...");
-    root_generator::generate(generated_path);
+    root_generator::generate(&mut out, &generated_path)?;
+
+    println!("\n🔧 Step 6a: Generating density ([data-density] overrides)...");
+    density_generator::generate(&mut out, &generated_path)?;
+
+    println!("\n🔧 Step 6b: Generating utilities (StyleProps classes)...");
+    utility_generator::generate(&mut out, &generated_path)?;
+    if let Err(problems) = utility_generator::check(&out, &generated_path) {
+        for problem in &problems {
+            println!("  ✗ {}", problem);
+        }
+        return Err(format!("Utility check failed: {} problem(s)", problems.len()).into());
+    }
+    println!("  ✓ utility check");
 
     println!("\n🔧 Step 7: Generating canonrs.css entry...");
-    entry_generator::generate(generated_path, styles_path)
-        .expect("Failed to generate entry");
+    entry_generator::generate(&mut out, &generated_path, &styles_path)?;
+
+    println!("\n🔧 Step 7b: Checking token graph (phantom vars, cycles, cascade)...");
+    let graph = token_graph::TokenGraph::build(&out, &styles_path, &themes, &overrides.themes).analyze();
+    graph.print_summary();
+    if let Some(path) = arg_value(args, "--graph-report") {
+        graph.write(&mut out, Path::new(&path))?;
+    }
+    if graph.has_errors() && args.iter().any(|a| a == "--strict-graph") {
+        return Err("Token graph check failed".into());
+    }
 
     println!("\n🔧 Step 8: Bundling canonrs.bundle.css...");
-    bundler::generate(styles_path, bundle_output_path)
-        .expect("Failed to bundle");
+    bundler::generate(&mut out, &styles_path, bundle_output_path)?;
 
+    for path in out.stale(&generated_path)? {
+        println!("  ⚠ {} is no longer generated", path.display());
+    }
+
+    if mode == Mode::Check {
+        println!("\n🔧 Check:");
+        out.print_summary();
+        if !out.drift().is_empty() {
+            for drift in out.drift() {
+                println!("  ✗ {}", drift);
+            }
+            return Err(format!("Generated CSS is out of date: {} file(s) — run tokens-engine and commit", out.drift().len()).into());
+        }
+        println!("\n✅ Generated CSS is up to date.");
+        return Ok(());
+    }
+
+    out.print_summary();
     println!("\n✅ Complete! All CSS generated.");
+    Ok(())
 }
 
-fn generate_primitives(output_dir: &Path) {
+fn generate_primitives(out: &mut Output, output_dir: &Path, overrides: &HashMap<String, String>) -> io::Result<()> {
     let mut css = String::from("/* PRIMITIVES - Atomic values */\n:root {\n");
     for token in PRIMITIVE_VALUES {
-        css.push_str(&format!("  --{}: {};\n", token.name, token.value));
+        let value = overrides.get(token.name).map(String::as_str).unwrap_or(token.value);
+        css.push_str(&format!("  --{}: {};\n", token.name, value));
     }
     css.push_str("}\n");
 
-    fs::write(output_dir.join("primitives.css"), css).ok();
-    println!("  ✓ primitives.css");
+    let status = out.write(&output_dir.join("primitives.css"), css)?;
+    println!("  {} primitives.css", status);
+    Ok(())
 }
 
-fn generate_core(output_dir: &Path) {
+fn generate_core(out: &mut Output, output_dir: &Path) -> io::Result<()> {
     let mut css = String::from("/* FOUNDATION - Core tokens */\n:root {\n");
-    // Foundation layers
-    for token in FOUNDATION_SPACING   { css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
-    for token in FOUNDATION_RADIUS    { css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
-    for token in FOUNDATION_MOTION    { css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
-    for token in FOUNDATION_TYPOGRAPHY{ css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
-    for token in FOUNDATION_COLOR     { css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
-    for token in FOUNDATION_SHADOW    { css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
-    for token in FOUNDATION_BORDER    { css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
-    for token in FOUNDATION_BREAKPOINTS  { css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
+    for layer in FOUNDATION_LAYERS {
+        for token in *layer { css.push_str(&format!("  --{}: {};\n", token.name, token.value)); }
+    }
     css.push_str("}\n");
 
-    fs::write(output_dir.join("core.css"), css).ok();
-    println!("  ✓ core.css");
+    let status = out.write(&output_dir.join("core.css"), css)?;
+    println!("  {} core.css", status);
+    Ok(())
 }
 
-fn generate_family(name: &str, tokens: &[canonrs_tokens::design::tokens::FamilyToken], output_dir: &Path) {
+fn generate_family(out: &mut Output, name: &str, tokens: &[FamilyToken], output_dir: &Path) -> io::Result<()> {
     let mut css = format!("/* {} */\n[data-theme] {{\n", name);
     for token in tokens {
         css.push_str(&format!("  --{}: {};\n", token.name, token.value));
     }
     css.push_str("}\n");
 
-    fs::write(output_dir.join(format!("{}.css", name)), css).ok();
-    println!("  ✓ {}", name);
+    let status = out.write(&output_dir.join(format!("{}.css", name)), css)?;
+    println!("  {} {}", status, name);
+    Ok(())
 }
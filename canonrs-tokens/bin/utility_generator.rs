@@ -0,0 +1,112 @@
+// Utilities — StyleProps class vocabulary from canonrs_style contracts
+// utilities.css: base classes | utilities-responsive.css: um @media (min-width) por breakpoint (FOUNDATION_BREAKPOINTS)
+// check(): toda classe do vocabulário tem regra gerada e todo var(--*) aponta para token definido
+
+use canonrs_style::{class_vocabulary, utility_rules, Breakpoint};
+use canonrs_tokens::design::tokens::foundation::breakpoints::FOUNDATION_BREAKPOINTS;
+use crate::output::Output;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+
+const BASE_FILE: &str = "utilities.css";
+const RESPONSIVE_FILE: &str = "utilities-responsive.css";
+
+pub fn generate(out: &mut Output, output_dir: &Path) -> io::Result<()> {
+    let rules = utility_rules();
+
+    let mut css = String::from("/* AUTO-GENERATED - UTILITIES - StyleProps classes */\n");
+    for rule in &rules {
+        css.push_str(&format!("{}\n", rule.to_css(Breakpoint::Base)));
+    }
+    let status = out.write(&output_dir.join(BASE_FILE), css)?;
+    println!("  {} {} ({} rules)", status, BASE_FILE, rules.len());
+
+    let mut css = String::from("/* AUTO-GENERATED - UTILITIES - responsive (sm: md: lg: xl:) */\n");
+    for bp in Breakpoint::ALL {
+        let Some(token) = bp.token() else { continue };
+        let Some(min_width) = FOUNDATION_BREAKPOINTS.iter().find(|t| t.name == token) else {
+            println!("  ⚠ Missing breakpoint token: {}", token);
+            continue;
+        };
+        css.push_str(&format!("\n@media (min-width: {}) {{\n", min_width.value));
+        for rule in &rules {
+            css.push_str(&format!("  {}\n", rule.to_css(bp)));
+        }
+        css.push_str("}\n");
+    }
+    let status = out.write(&output_dir.join(RESPONSIVE_FILE), css)?;
+    println!("  {} {} ({} rules × {} breakpoints)", status, RESPONSIVE_FILE, rules.len(), Breakpoint::ALL.len() - 1);
+    Ok(())
+}
+
+/// Lê o que foi emitido — roda depois de themes/semantic/root para enxergar todos os tokens
+pub fn check(out: &Output, output_dir: &Path) -> Result<(), Vec<String>> {
+    let mut problems = vec![];
+    let base = out.read_to_string(&output_dir.join(BASE_FILE)).unwrap_or_default();
+    let responsive = out.read_to_string(&output_dir.join(RESPONSIVE_FILE)).unwrap_or_default();
+
+    let defined = defined_tokens(out, output_dir);
+    let base_rules = rules_by_selector(&base);
+    let responsive_rules = rules_by_selector(&responsive);
+
+    for class in class_vocabulary() {
+        for bp in Breakpoint::ALL {
+            let (rules, file) = if bp == Breakpoint::Base { (&base_rules, BASE_FILE) } else { (&responsive_rules, RESPONSIVE_FILE) };
+            let selector = format!(".{}", bp.apply(class).replace(':', "\\:"));
+            match rules.iter().find(|(s, _)| *s == selector) {
+                None => problems.push(format!("{}: no rule for '{}'", file, selector)),
+                Some((_, body)) if body.trim().trim_end_matches(';').is_empty() =>
+                    problems.push(format!("{}: empty rule for '{}'", file, selector)),
+                Some(_) => {}
+            }
+        }
+    }
+
+    let mut unresolved = HashSet::new();
+    for (selector, body) in &base_rules {
+        for token in var_refs(body) {
+            if !defined.contains(token) && unresolved.insert(token.to_string()) {
+                problems.push(format!("{}: '{}' uses undefined token --{}", BASE_FILE, selector, token));
+            }
+        }
+    }
+
+    if problems.is_empty() { Ok(()) } else { Err(problems) }
+}
+
+/// `.sel { body }` em uma linha — formato que o generate emite
+fn rules_by_selector(css: &str) -> Vec<(&str, &str)> {
+    css.lines()
+        .map(str::trim)
+        .filter(|l| l.starts_with('.'))
+        .filter_map(|l| {
+            let (selector, rest) = l.split_once(" {")?;
+            Some((selector, rest.trim_end_matches('}')))
+        })
+        .collect()
+}
+
+/// `--name:` declarado em qualquer .css emitido em `output_dir`
+fn defined_tokens(out: &Output, output_dir: &Path) -> HashSet<String> {
+    let mut tokens = HashSet::new();
+    for (path, bytes) in out.emitted_in(output_dir) {
+        if path.extension().and_then(|e| e.to_str()) != Some("css") { continue; }
+        let Ok(css) = std::str::from_utf8(bytes) else { continue };
+        for (i, _) in css.match_indices("--") {
+            let name: String = css[i + 2..].chars().take_while(|c| c.is_ascii_alphanumeric() || *c == '-').collect();
+            if !name.is_empty() && css[i + 2 + name.len()..].trim_start().starts_with(':') {
+                tokens.insert(name);
+            }
+        }
+    }
+    tokens
+}
+
+fn var_refs(body: &str) -> impl Iterator<Item = &str> {
+    body.match_indices("var(--").map(move |(i, _)| {
+        let rest = &body[i + 6..];
+        let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-')).unwrap_or(rest.len());
+        &rest[..end]
+    })
+}
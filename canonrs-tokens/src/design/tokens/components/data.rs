@@ -38,7 +38,7 @@ pub const DATA_TOKENS: &[FamilyToken] = &[
     // ── Table ─────────────────────────────────────────────────────────────────
     FamilyToken::new("table-border-color",         "var(--theme-surface-border)"),
     FamilyToken::new("table-border-width",         "1px"),
-    FamilyToken::new("table-row-height",           "var(--space-2xl)"),
+    FamilyToken::new("table-row-height",           "var(--density-row-height)"),
     FamilyToken::new("table-row-bg-striped",       "color-mix(in srgb, var(--theme-surface-fg) 3%, transparent)"),
     FamilyToken::new("table-row-bg-hover",         "var(--theme-surface-muted)"),
     FamilyToken::new("table-row-bg-selected",      "var(--theme-action-accent-bg)"),
@@ -50,12 +50,12 @@ pub const DATA_TOKENS: &[FamilyToken] = &[
     FamilyToken::new("table-row-transition-duration", "var(--motion-duration-fast)"),
     FamilyToken::new("table-row-transition-ease",  "var(--motion-ease-standard)"),
     FamilyToken::new("table-cell-fg",              "var(--theme-surface-fg)"),
-    FamilyToken::new("table-cell-font-size",       "var(--font-size-sm)"),
-    FamilyToken::new("table-cell-padding-x",       "var(--space-md)"),
-    FamilyToken::new("table-cell-padding-y",       "var(--space-sm)"),
+    FamilyToken::new("table-cell-font-size",       "var(--density-font-size)"),
+    FamilyToken::new("table-cell-padding-x",       "var(--density-cell-padding-x)"),
+    FamilyToken::new("table-cell-padding-y",       "var(--density-cell-padding-y)"),
     FamilyToken::new("table-header-bg",            "var(--theme-surface-muted)"),
     FamilyToken::new("table-header-fg",            "var(--theme-surface-fg)"),
-    FamilyToken::new("table-header-font-size",     "var(--font-size-sm)"),
+    FamilyToken::new("table-header-font-size",     "var(--density-font-size)"),
     FamilyToken::new("table-header-font-weight",   "var(--font-weight-semibold)"),
     FamilyToken::new("table-header-height",        "var(--space-xl)"),
     FamilyToken::new("table-caption-fg",           "var(--theme-surface-fg-muted)"),
@@ -69,12 +69,6 @@ pub const DATA_TOKENS: &[FamilyToken] = &[
     FamilyToken::new("datatable-toolbar-padding-y",      "var(--space-sm)"),
     FamilyToken::new("datatable-toolbar-padding-x",      "var(--space-sm)"),
     FamilyToken::new("datatable-filter-max-width",       "300px"),
-    FamilyToken::new("datatable-density-compact-padding-y",     "var(--space-xs)"),
-    FamilyToken::new("datatable-density-compact-padding-x",     "var(--space-sm)"),
-    FamilyToken::new("datatable-density-comfortable-padding-y", "var(--space-sm)"),
-    FamilyToken::new("datatable-density-comfortable-padding-x", "var(--space-md)"),
-    FamilyToken::new("datatable-density-spacious-padding-y",    "var(--space-md)"),
-    FamilyToken::new("datatable-density-spacious-padding-x",    "var(--space-lg)"),
     // ── Virtual List ─────────────────────────────────────────────
     FamilyToken::new("virtual-list-bg",               "var(--theme-surface-bg)"),
     FamilyToken::new("virtual-list-border-color",     "var(--theme-surface-border)"),
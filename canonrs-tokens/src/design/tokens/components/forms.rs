@@ -21,9 +21,9 @@ pub const FORMS_TOKENS: &[FamilyToken] = &[
 
     // Button
     // Button sizes — enterprise scale (xs=24px, sm=32px, md=36px, lg=40px, xl=48px)
-    FamilyToken::new("button-height",           "var(--size-button-md)"),   // md = 36px
-    FamilyToken::new("button-padding-x",        "var(--space-md)"),
-    FamilyToken::new("button-padding-y",        "0.375rem"),
+    FamilyToken::new("button-height",           "var(--density-control-height)"),   // md = 36px
+    FamilyToken::new("button-padding-x",        "var(--density-control-padding-x)"),
+    FamilyToken::new("button-padding-y",        "var(--density-control-padding-y)"),
 
     FamilyToken::new("button-xs-height",        "var(--size-button-xs)"),    // 24px
     FamilyToken::new("button-xs-padding-x",     "var(--space-sm)"),
@@ -125,12 +125,12 @@ pub const FORMS_TOKENS: &[FamilyToken] = &[
     FamilyToken::new("input-fg", "var(--theme-surface-fg)"),
     FamilyToken::new("input-border-color", "var(--theme-surface-border)"),
     FamilyToken::new("input-border-width", "1px"),
-    FamilyToken::new("input-height", "var(--space-2xl)"),
-    FamilyToken::new("input-padding-x", "var(--space-sm)"),
-    FamilyToken::new("input-padding-y", "var(--space-sm)"),
+    FamilyToken::new("input-height", "var(--density-field-height)"),
+    FamilyToken::new("input-padding-x", "var(--density-field-padding-x)"),
+    FamilyToken::new("input-padding-y", "var(--density-field-padding-y)"),
     FamilyToken::new("input-radius", "var(--radius-sm)"),
     FamilyToken::new("input-font-family", "inherit"),
-    FamilyToken::new("input-font-size", "var(--font-size-sm)"),
+    FamilyToken::new("input-font-size", "var(--density-font-size)"),
     FamilyToken::new("input-font-weight", "var(--font-weight-normal)"),
     FamilyToken::new("input-line-height", "var(--line-height-normal)"),
     FamilyToken::new("input-placeholder", "var(--theme-surface-fg-muted)"),
@@ -144,7 +144,7 @@ pub const FORMS_TOKENS: &[FamilyToken] = &[
     FamilyToken::new("input-success-border", "var(--color-success)"),
     FamilyToken::new("input-sm-height", "var(--size-input-sm)"),
     FamilyToken::new("input-sm-font-size", "var(--font-size-xs)"),
-    FamilyToken::new("input-md-height", "var(--space-2xl)"),
+    FamilyToken::new("input-md-height", "var(--density-field-height)"),
     FamilyToken::new("input-lg-height", "var(--size-icon-lg)"),
     FamilyToken::new("input-lg-font-size", "var(--font-size-base)"),
     FamilyToken::new("input-group-gap", "0"),
@@ -24,13 +24,13 @@ pub const NAVIGATION_TOKENS: &[FamilyToken] = &[
     FamilyToken::new("sidebar-border-color", "var(--theme-surface-border)"),
     FamilyToken::new("sidebar-border-width", "1px"),
     FamilyToken::new("sidebar-padding", "var(--space-md)"),
-    FamilyToken::new("sidebar-menu-gap", "var(--space-xs)"),
-    FamilyToken::new("sidebar-menu-item-height", "var(--space-2xl)"),
-    FamilyToken::new("sidebar-menu-item-padding-x", "var(--space-sm)"),
-    FamilyToken::new("sidebar-menu-item-padding-y", "var(--space-sm)"),
+    FamilyToken::new("sidebar-menu-gap", "var(--density-gap)"),
+    FamilyToken::new("sidebar-menu-item-height", "var(--density-item-height)"),
+    FamilyToken::new("sidebar-menu-item-padding-x", "var(--density-item-padding-x)"),
+    FamilyToken::new("sidebar-menu-item-padding-y", "var(--density-item-padding-y)"),
     FamilyToken::new("sidebar-menu-item-radius", "var(--radius-sm)"),
     FamilyToken::new("sidebar-menu-item-fg", "var(--theme-surface-fg)"),
-    FamilyToken::new("sidebar-menu-item-font-size", "var(--font-size-sm)"),
+    FamilyToken::new("sidebar-menu-item-font-size", "var(--density-font-size)"),
     FamilyToken::new("sidebar-header-padding", "var(--space-md)"),
     FamilyToken::new("sidebar-content-padding", "var(--space-md)"),
     FamilyToken::new("sidebar-footer-padding", "var(--space-md)"),
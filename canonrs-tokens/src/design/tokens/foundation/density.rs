@@ -0,0 +1,69 @@
+// Foundation — Density
+// Control heights, paddings and font sizes that change with `[data-density]`.
+// FOUNDATION_DENSITY = comfortable (core.css); DENSITY_MODES = overrides emitted by tokens-engine (density.css)
+
+use crate::design::tokens::FamilyToken;
+
+pub const FOUNDATION_DENSITY: &[FamilyToken] = DENSITY_COMFORTABLE;
+
+pub const DENSITY_MODES: &[(&str, &[FamilyToken])] = &[
+    ("compact",     DENSITY_COMPACT),
+    ("comfortable", DENSITY_COMFORTABLE),
+    ("spacious",    DENSITY_SPACIOUS),
+];
+
+pub const DENSITY_COMPACT: &[FamilyToken] = &[
+    // Controls — button
+    FamilyToken::new("density-control-height",    "var(--size-button-sm)"),   // 32px
+    FamilyToken::new("density-control-padding-x", "var(--space-sm)"),
+    FamilyToken::new("density-control-padding-y", "var(--space-xs)"),
+    // Fields — input
+    FamilyToken::new("density-field-height",      "var(--size-input-sm)"),    // 28px
+    FamilyToken::new("density-field-padding-x",   "var(--space-xs)"),
+    FamilyToken::new("density-field-padding-y",   "var(--space-xs)"),
+    // Rows — table, datatable
+    FamilyToken::new("density-row-height",        "var(--size-sm)"),          // 28px
+    FamilyToken::new("density-cell-padding-x",    "var(--space-sm)"),
+    FamilyToken::new("density-cell-padding-y",    "var(--space-xs)"),
+    // Items — sidebar menu
+    FamilyToken::new("density-item-height",       "var(--size-sm)"),
+    FamilyToken::new("density-item-padding-x",    "var(--space-sm)"),
+    FamilyToken::new("density-item-padding-y",    "var(--space-xs)"),
+    FamilyToken::new("density-gap",               "var(--space-2xs)"),
+    // Text inside dense surfaces
+    FamilyToken::new("density-font-size",         "var(--font-size-xs)"),
+];
+
+pub const DENSITY_COMFORTABLE: &[FamilyToken] = &[
+    FamilyToken::new("density-control-height",    "var(--size-button-md)"),   // 36px
+    FamilyToken::new("density-control-padding-x", "var(--space-md)"),
+    FamilyToken::new("density-control-padding-y", "0.375rem"),
+    FamilyToken::new("density-field-height",      "var(--space-2xl)"),        // 32px
+    FamilyToken::new("density-field-padding-x",   "var(--space-sm)"),
+    FamilyToken::new("density-field-padding-y",   "var(--space-sm)"),
+    FamilyToken::new("density-row-height",        "var(--space-2xl)"),
+    FamilyToken::new("density-cell-padding-x",    "var(--space-md)"),
+    FamilyToken::new("density-cell-padding-y",    "var(--space-sm)"),
+    FamilyToken::new("density-item-height",       "var(--space-2xl)"),
+    FamilyToken::new("density-item-padding-x",    "var(--space-sm)"),
+    FamilyToken::new("density-item-padding-y",    "var(--space-sm)"),
+    FamilyToken::new("density-gap",               "var(--space-xs)"),
+    FamilyToken::new("density-font-size",         "var(--font-size-sm)"),
+];
+
+pub const DENSITY_SPACIOUS: &[FamilyToken] = &[
+    FamilyToken::new("density-control-height",    "var(--size-button-lg)"),   // 44px
+    FamilyToken::new("density-control-padding-x", "var(--space-lg)"),
+    FamilyToken::new("density-control-padding-y", "var(--space-sm)"),
+    FamilyToken::new("density-field-height",      "var(--size-lg)"),          // 40px
+    FamilyToken::new("density-field-padding-x",   "var(--space-md)"),
+    FamilyToken::new("density-field-padding-y",   "var(--space-md)"),
+    FamilyToken::new("density-row-height",        "var(--size-lg)"),
+    FamilyToken::new("density-cell-padding-x",    "var(--space-lg)"),
+    FamilyToken::new("density-cell-padding-y",    "var(--space-md)"),
+    FamilyToken::new("density-item-height",       "var(--size-lg)"),
+    FamilyToken::new("density-item-padding-x",    "var(--space-md)"),
+    FamilyToken::new("density-item-padding-y",    "var(--space-sm)"),
+    FamilyToken::new("density-gap",               "var(--space-sm)"),
+    FamilyToken::new("density-font-size",         "var(--font-size-md)"),
+];
@@ -15,3 +15,5 @@ pub use shadow::FOUNDATION_SHADOW;
 pub use border::FOUNDATION_BORDER;
 pub mod breakpoints;
 pub use breakpoints::FOUNDATION_BREAKPOINTS;
+pub mod density;
+pub use density::{FOUNDATION_DENSITY, DENSITY_MODES};
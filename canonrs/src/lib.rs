@@ -16,6 +16,7 @@ pub mod shared {
 
 pub mod providers {
     pub use canonrs_core::infra::theme::*;
+    pub use canonrs_core::infra::density::*;
     pub mod prelude {
         pub use canonrs_core::prelude::*;
     }
@@ -107,6 +108,8 @@ pub mod runtime {
 // ── Dev ───────────────────────────────────────────────────────────────────────
 #[cfg(all(feature = "ssr", debug_assertions))]
 pub use canonrs_server::dev::reload::with_dev_reload;
+#[cfg(all(feature = "ssr", debug_assertions))]
+pub use canonrs_server::dev::verify::with_dom_verifier;
 
 // ── CSS ───────────────────────────────────────────────────────────────────────
 #[cfg(feature = "ssr")]
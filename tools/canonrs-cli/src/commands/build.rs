@@ -10,7 +10,7 @@ pub fn execute() -> Result<()> {
     println!("{}", "🎨 Running tokens-engine...".cyan());
     let tokens_dir = super::canonrs_root()?.join("canonrs-tokens");
     let tokens_status = Command::new("cargo")
-        .args(["run", "--release", "--bin", "tokens-engine"])
+        .args(["run", "--release", "--features", "engine", "--bin", "tokens-engine"])
         .current_dir(&tokens_dir)
         .status()
         .context("Failed to run tokens-engine")?;
@@ -11,6 +11,8 @@ pub fn execute() -> Result<()> {
     let tokens_dir = super::canonrs_root()?.join("canonrs-tokens");
     let tokens_status = Command::new("cargo")
         .arg("run")
+        .arg("--features")
+        .arg("engine")
         .arg("--bin")
         .arg("tokens-engine")
         .current_dir(&tokens_dir)
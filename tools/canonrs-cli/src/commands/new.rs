@@ -155,6 +155,7 @@ pub fn shell(options: leptos::config::LeptosOptions) -> impl IntoView {{
             <head>
                 <meta charset="utf-8"/>
                 <meta name="viewport" content="width=device-width, initial-scale=1"/>
+                <script inner_html=canonrs::providers::canonrs_theme_script("canonrs-theme")></script>
                 <leptos_meta::AutoReload options=options.clone()/>
                 <leptos_axum::HydrationScripts options/>
                 <MetaTags/>
@@ -169,11 +170,18 @@ pub fn shell(options: leptos::config::LeptosOptions) -> impl IntoView {{
 #[component]
 pub fn {component_name}App() -> impl IntoView {{
     provide_meta_context();
+    // Canon rule 32: no SSR o tema vem dos cookies do request; na hidratação o ThemeProvider lê document.cookie
+    #[cfg(feature = "ssr")]
+    let (mode, theme) = use_context::<axum::http::request::Parts>()
+        .and_then(|parts| parts.headers.get(axum::http::header::COOKIE)?.to_str().ok().map(canonrs::providers::theme_from_cookies))
+        .unwrap_or_default();
+    #[cfg(not(feature = "ssr"))]
+    let (mode, theme): (Option<ThemeMode>, Option<String>) = (None, None);
     view! {{
         <Stylesheet id="canonrs" href="/canonrs.css"/>
         <Stylesheet id="app" href="/style/output.css"/>
         <Title text="{name}"/>
-        <CanonRSRoot>
+        <CanonRSRoot mode=mode theme=theme>
             <Router>
                 <Routes fallback=|| view! {{ "Not found" }}>
                     <Route path=StaticSegment("") view=pages::home::HomePage/>
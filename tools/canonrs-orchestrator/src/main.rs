@@ -129,7 +129,7 @@ fn spawn_tokens(root: &PathBuf, state: &Arc<Mutex<SystemState>>) {
     let t = Instant::now();
     println!("[canon][tokens] building...");
     Command::new("cargo")
-        .args(["run", "--bin", "tokens-engine"])
+        .args(["run", "--features", "engine", "--bin", "tokens-engine"])
         .current_dir(&tokens)
         .env("CARGO_TARGET_DIR", "/tmp/tokens-build")
         .status().ok();